@@ -3,8 +3,17 @@ use std::env::var;
 fn main() {
     let link_type = env_or_default("CARGO_LAPACK_TYPE", "dylib");
     let link_name = env_or_default("CARGO_LAPACK", "lapack");
+    let abi = env_or_default("CARGO_LAPACK_ABI", "gfortran");
+
+    match &abi[..] {
+        "gfortran" | "f2c" => {},
+        _ => panic!("CARGO_LAPACK_ABI must be `gfortran` or `f2c`, not `{}`", abi),
+    }
 
     println!("cargo:rustc-link-lib={}={}", link_type, link_name);
+    println!("cargo:rustc-cfg=lapack_abi=\"{}\"", abi);
+    println!("cargo:rustc-check-cfg=cfg(lapack_abi, values(\"gfortran\", \"f2c\"))");
+    println!("cargo:rerun-if-env-changed=CARGO_LAPACK_ABI");
 }
 
 fn env_or_default(var_name: &str, default: &str) -> String {
@@ -0,0 +1,345 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::cmp;
+use libc::c_int;
+use num::Zero;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::Matrix;
+use refinement::{ExpertSolution, Gbrfs, Refinement};
+use scalar::Scalar;
+use types::{
+    Equilibration,
+    Order,
+};
+use util::ColMem;
+
+/// An `m × n` band matrix with `kl` subdiagonals and `ku` superdiagonals in
+/// LAPACK band storage.
+///
+/// Storage is column major with leading dimension `2 * kl + ku + 1`. The
+/// matrix occupies the last `kl + ku + 1` rows, so element `(i, j)` is kept
+/// at row `kl + ku + i - j` of column `j`. The first `kl` rows are left for
+/// the fill-in of an `LU` factorization, so the same storage can be passed to
+/// both the factoring and non-factoring routines.
+#[derive(Clone, Debug)]
+pub struct BandStorage<T> {
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    data: Vec<T>,
+}
+
+impl<T: Copy + Zero> BandStorage<T> {
+    /// A zero band matrix.
+    pub fn new(m: c_int, n: c_int, kl: c_int, ku: c_int) -> BandStorage<T> {
+        let ldab = 2 * kl + ku + 1;
+
+        BandStorage {
+            m: m,
+            n: n,
+            kl: kl,
+            ku: ku,
+            data: vec![T::zero(); (ldab * n) as usize],
+        }
+    }
+
+    /// Copies the band of a dense matrix. Elements outside the band are
+    /// ignored.
+    pub fn from_dense(a: &Matrix<T>, kl: c_int, ku: c_int) -> BandStorage<T> {
+        let m = a.rows();
+        let n = a.cols();
+        let mut band = BandStorage::new(m, n, kl, ku);
+        let ptr = a.as_ptr();
+
+        for j in 0..n {
+            for i in cmp::max(0, j - ku)..cmp::min(m, j + kl + 1) {
+                let offset = match a.order() {
+                    Order::ColMajor => i + j * m,
+                    Order::RowMajor => i * n + j,
+                };
+
+                band.set(i, j, unsafe { *ptr.offset(offset as isize) });
+            }
+        }
+
+        band
+    }
+
+    /// The matrix as a dense column major vector.
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut dense = vec![T::zero(); (self.m * self.n) as usize];
+
+        for j in 0..self.n {
+            for i in cmp::max(0, j - self.ku)..cmp::min(self.m, j + self.kl + 1) {
+                dense[(i + j * self.m) as usize] = self.get(i, j);
+            }
+        }
+
+        dense
+    }
+
+    /// Element `(i, j)`, which is zero outside the band.
+    pub fn get(&self, i: c_int, j: c_int) -> T {
+        assert!(i >= 0 && i < self.m && j >= 0 && j < self.n, "index out of bounds");
+
+        if i - j > self.kl || j - i > self.ku {
+            return T::zero();
+        }
+
+        self.data[self.index(i, j)]
+    }
+
+    /// Sets element `(i, j)`, which must lie inside the band.
+    pub fn set(&mut self, i: c_int, j: c_int, value: T) {
+        assert!(i >= 0 && i < self.m && j >= 0 && j < self.n, "index out of bounds");
+        assert!(i - j <= self.kl && j - i <= self.ku, "element outside of band");
+
+        let index = self.index(i, j);
+        self.data[index] = value;
+    }
+
+    fn index(&self, i: c_int, j: c_int) -> usize {
+        (self.kl + self.ku + i - j + j * self.lead()) as usize
+    }
+}
+
+impl<T> BandStorage<T> {
+    pub fn rows(&self) -> c_int { self.m }
+    pub fn cols(&self) -> c_int { self.n }
+    pub fn sub_diagonals(&self) -> c_int { self.kl }
+    pub fn sup_diagonals(&self) -> c_int { self.ku }
+    /// The leading dimension, `2 * kl + ku + 1`.
+    pub fn lead(&self) -> c_int { 2 * self.kl + self.ku + 1 }
+
+    /// Pointer to the full storage, as the factoring routines expect.
+    pub fn as_ptr(&self) -> *const T { self.data.as_ptr() }
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() }
+
+    /// Pointer to the `kl + ku + 1` rows holding the matrix, as routines
+    /// that do not factor it expect. Use with `lead()`.
+    pub fn as_band_ptr(&self) -> *const T {
+        self.data[self.kl as usize..].as_ptr()
+    }
+
+    pub fn as_mut_band_ptr(&mut self) -> *mut T {
+        let kl = self.kl as usize;
+        self.data[kl..].as_mut_ptr()
+    }
+
+    /// Refines a solution `x` of `A X = B` and bounds its error, where this
+    /// holds the `LU` factors of `a` from `Gbtrf` and `ipiv` their pivots.
+    pub fn refine<Real>(&self, a: &BandStorage<T>, ipiv: &[c_int], b: &mut Matrix<T>, x: &mut Matrix<T>)
+        -> Result<Refinement<Real>, Error> where T: Gbrfs<Real> {
+
+        T::gbrfs(a, self, ipiv, b, x)
+    }
+}
+
+/// `LU` factorization of a band matrix with partial pivoting, in place.
+/// Returns the pivot indices.
+pub trait Gbtrf: Sized {
+    fn gbtrf(a: &mut BandStorage<Self>) -> Result<Vec<c_int>, Error>;
+}
+
+/// Solves `A X = B` with the factorization from `Gbtrf`, overwriting `b`.
+pub trait Gbtrs: Sized {
+    fn gbtrs(lu: &BandStorage<Self>, ipiv: &[c_int], b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Expert band solver. Equilibrates `a` if needed, factors it, solves
+/// `A X = B` into `x`, and estimates the condition number and error bounds.
+///
+/// If `a` is equilibrated it and `b` are overwritten by their scaled forms.
+/// A condition number below machine precision is reported through `rcond`
+/// rather than as an error.
+pub trait Gbsvx<Real>: Sized {
+    fn gbsvx(a: &mut BandStorage<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+        -> Result<ExpertSolution<Real>, Error>;
+}
+
+macro_rules! band_impl(($($t: ident: $r: ident, $zero: expr, $work: expr, $extra: ty, $extra_zero: expr), +) => ($(
+    impl Gbtrf for $t {
+        fn gbtrf(a: &mut BandStorage<Self>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
+            let mut ipiv: Vec<c_int> = vec![0; cmp::min(a.m, a.n) as usize];
+
+            unsafe {
+                prefix!($t, gbtrf_)(a.m.as_mut(), a.n.as_mut(),
+                    a.kl.as_mut(), a.ku.as_mut(),
+                    a.as_mut_ptr(), a.lead().as_mut(),
+                    ipiv.as_mut_ptr(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(ipiv),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl Gbtrs for $t {
+        fn gbtrs(lu: &BandStorage<Self>, ipiv: &[c_int], b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = lu.n;
+            if lu.m != n || b.rows() != n || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, gbtrs_)((b'N' as i8).as_mut(),
+                    n.as_mut(), lu.kl.as_mut(), lu.ku.as_mut(),
+                    nrhs.as_mut(),
+                    lu.as_ptr() as *mut _, lu.lead().as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Gbsvx<$r> for $t {
+        fn gbsvx(a: &mut BandStorage<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+            -> Result<ExpertSolution<$r>, Error> {
+
+            let n = a.n;
+            let nrhs = b.cols();
+            if a.m != n || b.rows() != n || x.rows() != n || x.cols() != nrhs {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut afb: Vec<$t> = vec![$zero; a.data.len()];
+            let mut ipiv: Vec<c_int> = vec![0; n as usize];
+            let mut equed: i8 = 0;
+            let mut r: Vec<$r> = vec![0.0; n as usize];
+            let mut c: Vec<$r> = vec![0.0; n as usize];
+            let mut rcond: $r = 0.0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, gbsvx_)((b'E' as i8).as_mut(), (b'N' as i8).as_mut(),
+                    n.as_mut(), a.kl.as_mut(), a.ku.as_mut(), nrhs.as_mut(),
+                    a.as_mut_band_ptr(), a.lead().as_mut(),
+                    afb.as_mut_ptr(), a.lead().as_mut(),
+                    ipiv.as_mut_ptr(), &mut equed,
+                    r.as_mut_ptr(), c.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    &mut rcond, ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            let solution = ExpertSolution {
+                rcond: rcond,
+                bounds: Refinement { ferr: ferr, berr: berr },
+                equilibration: Equilibration::from_i8(equed),
+            };
+
+            match info {
+                0 => Ok(solution),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x if x > n => Ok(solution),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+)+));
+
+band_impl!(
+    f32: f32, 0.0, 3, c_int, 0,
+    f64: f64, 0.0, 3, c_int, 0,
+    Complex32: f32, Complex32::new(0.0, 0.0), 2, f32, 0.0,
+    Complex64: f64, Complex64::new(0.0, 0.0), 2, f64, 0.0
+);
+
+#[cfg(test)]
+mod band_tests {
+    use band::{BandStorage, Gbsvx, Gbtrf, Gbtrs};
+    use matrix::tests::M;
+    use types::Order::*;
+
+    fn tridiagonal() -> M<f64> {
+        M(RowMajor, 3i32, 3i32, vec![
+            2.0f64, -1.0, 0.0,
+            -1.0, 2.0, -1.0,
+            0.0, -1.0, 2.0,
+        ])
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let a = BandStorage::from_dense(&tridiagonal(), 1, 1);
+
+        assert_eq!(a.lead(), 4);
+        assert_eq!(a.get(0, 2), 0.0);
+        assert_eq!(a.get(2, 1), -1.0);
+        assert_eq!(a.to_dense(), vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    }
+
+    #[test]
+    fn factor_solve() {
+        let mut a = BandStorage::from_dense(&tridiagonal(), 1, 1);
+        let mut b = M(ColMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+
+        let ipiv = Gbtrf::gbtrf(&mut a).unwrap();
+        Gbtrs::gbtrs(&a, &ipiv, &mut b).unwrap();
+
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn refine() {
+        let a = BandStorage::from_dense(&tridiagonal(), 1, 1);
+        let mut lu = a.clone();
+        let mut b = M(RowMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+        let mut x = M(RowMajor, 3i32, 1i32, vec![1.0f64, 1.0 - 1e-6, 1.0]);
+
+        let ipiv = Gbtrf::gbtrf(&mut lu).unwrap();
+        let r = lu.refine::<f64>(&a, &ipiv, &mut b, &mut x).unwrap();
+
+        assert_eq!(r.berr.len(), 1);
+        for x in x.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn expert() {
+        let mut a = BandStorage::from_dense(&tridiagonal(), 1, 1);
+        let mut b = M(RowMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+        let mut x = M(RowMajor, 3i32, 1i32, vec![0.0f64; 3]);
+
+        let s = Gbsvx::<f64>::gbsvx(&mut a, &mut b, &mut x).unwrap();
+
+        assert!(s.rcond > 0.0 && s.rcond <= 1.0);
+        for x in x.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+}
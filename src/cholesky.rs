@@ -0,0 +1,183 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::SymmetricMatrix;
+use scalar::Scalar;
+use types::Symmetry;
+use util::col_vec;
+
+/// Cholesky factorization with complete pivoting of a symmetric (Hermitian)
+/// positive semidefinite matrix, `Pᵀ A P = Uᴴ U` or `Pᵀ A P = L Lᴴ`, stopped
+/// after `rank` steps.
+#[derive(Clone, Debug)]
+pub struct PivotedCholesky<T> {
+    n: c_int,
+    uplo: Symmetry,
+    factors: Vec<T>,
+    piv: Vec<c_int>,
+    rank: usize,
+}
+
+impl<T> PivotedCholesky<T> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+
+    /// The numerical rank found for the tolerance.
+    pub fn rank(&self) -> usize { self.rank }
+
+    /// The permutation `P`, where column `k` of `P` is column `piv[k]` of
+    /// the identity, counting from one.
+    pub fn pivots(&self) -> &[c_int] { &self.piv[..] }
+
+    /// The raw output of `pstrf`, column major, in the `symmetry()`
+    /// triangle. Only the first `rank` rows of `U` (columns of `L`) are
+    /// meaningful.
+    pub fn factors(&self) -> &[T] { &self.factors[..] }
+}
+
+/// Pivoted Cholesky factorization of a symmetric (Hermitian) positive
+/// semidefinite matrix. `a` is not changed.
+///
+/// Pivots smaller than `tol` end the factorization; `None` uses LAPACK's
+/// default of `n ε max(diag(A))`. An indefinite matrix also stops it, at
+/// the first nonpositive pivot, so `rank` is then only a lower bound.
+pub trait Pstrf<Real>: Sized {
+    fn pstrf(a: &SymmetricMatrix<Self>, tol: Option<Real>) -> Result<PivotedCholesky<Self>, Error>;
+}
+
+macro_rules! pivoted_cholesky_impl(($($t: ident: $r: ident, $zero: expr, $conj: expr), +) => ($(
+    impl Pstrf<$r> for $t {
+        fn pstrf(a: &SymmetricMatrix<Self>, tol: Option<$r>) -> Result<PivotedCholesky<Self>, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let uplo = a.symmetry();
+            let lda = if n > 0 { n } else { 1 };
+            let mut factors = col_vec(a);
+            let mut piv: Vec<c_int> = vec![0; n as usize];
+            let mut rank: c_int = 0;
+            let mut tol: $r = tol.unwrap_or(-1.0);
+            let mut work: Vec<$r> = vec![0.0; 2 * n as usize];
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, pstrf_)(uplo.as_i8().as_mut(), n.as_mut(),
+                    factors.as_mut_ptr(), lda.as_mut(),
+                    piv.as_mut_ptr(), &mut rank,
+                    &mut tol, work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                _ => Ok(PivotedCholesky {
+                    n: n,
+                    uplo: uplo,
+                    factors: factors,
+                    piv: piv,
+                    rank: rank as usize,
+                }),
+            }
+        }
+    }
+
+    impl PivotedCholesky<$t> {
+        /// The `n × rank` factor `F = P L` (or `P Uᴴ`) with `A ≈ F Fᴴ`,
+        /// column major.
+        pub fn factor(&self) -> Vec<$t> {
+            let n = self.n as usize;
+            let conj = $conj;
+            let mut f = vec![$zero; n * self.rank];
+
+            for j in 0..self.rank {
+                for k in j..n {
+                    let x = match self.uplo {
+                        Symmetry::Upper => conj(self.factors[j + k * n]),
+                        Symmetry::Lower => self.factors[k + j * n],
+                    };
+                    f[(self.piv[k] - 1) as usize + j * n] = x;
+                }
+            }
+
+            f
+        }
+    }
+)+));
+
+pivoted_cholesky_impl!(
+    f32: f32, 0.0, |x: f32| x,
+    f64: f64, 0.0, |x: f64| x,
+    Complex32: f32, Complex32::new(0.0, 0.0), |x: Complex32| x.conj(),
+    Complex64: f64, Complex64::new(0.0, 0.0), |x: Complex64| x.conj()
+);
+
+#[cfg(test)]
+mod cholesky_tests {
+    use num::complex::Complex64;
+    use cholesky::Pstrf;
+    use matrix::tests::{M, S};
+    use types::Order::*;
+    use types::Symmetry;
+
+    // v vᵀ + w wᵀ for v = (1, 2, 0), w = (0, 1, 1).
+    fn rank_two() -> Vec<f64> {
+        vec![
+            1.0, 2.0, 0.0,
+            2.0, 5.0, 1.0,
+            0.0, 1.0, 1.0,
+        ]
+    }
+
+    #[test]
+    fn low_rank() {
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                let a = S(uplo, M(order, 3i32, 3i32, rank_two()));
+                let c = Pstrf::pstrf(&a, None).unwrap();
+
+                assert_eq!(c.rank(), 2);
+                // The largest diagonal element is pivoted first.
+                assert_eq!(c.pivots()[0], 2);
+
+                let f = c.factor();
+                let expected = rank_two();
+                for i in 0..3 {
+                    for j in 0..3 {
+                        let x = f[i] * f[j] + f[i + 3] * f[j + 3];
+                        assert!((x - expected[i + j * 3]).abs() < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tolerance() {
+        let a = S(Symmetry::Lower, M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1e-3]));
+
+        assert_eq!(Pstrf::pstrf(&a, None).unwrap().rank(), 2);
+        assert_eq!(Pstrf::pstrf(&a, Some(1e-2)).unwrap().rank(), 1);
+    }
+
+    #[test]
+    fn hermitian() {
+        let c = |re, im| Complex64::new(re, im);
+        // v vᴴ for v = (1, i).
+        let a = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 1.0), c(0.0, -1.0), c(1.0, 0.0)]));
+        let p = Pstrf::pstrf(&a, None).unwrap();
+
+        assert_eq!(p.rank(), 1);
+        let f = p.factor();
+        assert!((f[0] * f[1].conj() - c(0.0, -1.0)).norm() < 1e-12);
+        assert!((f[1] * f[0].conj() - c(0.0, 1.0)).norm() < 1e-12);
+    }
+}
@@ -0,0 +1,576 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use band::BandStorage;
+use error::Error;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+    TriangularMatrix,
+};
+use packed::PackedSymmetric;
+use scalar::Scalar;
+use tridiagonal::TridiagonalLU;
+use types::Norm;
+use util::{col_norm, col_uplo, col_vec};
+
+// Condition estimators return the reciprocal condition number in the norm
+// they are given. Only `Norm::One` and `Norm::Infinity` are supported, and
+// `anorm` must be that same norm of the original matrix. The symmetric
+// estimators always use the 1-norm, which equals the infinity-norm.
+
+/// Reciprocal condition number of a general matrix from its `LU`
+/// factorization, as left in `a` by `Gesv`.
+pub trait Gecon<Real>: Sized {
+    fn gecon(norm: Norm, lu: &Matrix<Self>, anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a symmetric (Hermitian) positive definite
+/// matrix from its Cholesky factorization, as left in `a` by `Posv`.
+pub trait Pocon<Real>: Sized {
+    fn pocon(a: &SymmetricMatrix<Self>, anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a packed symmetric (Hermitian) positive
+/// definite matrix from its Cholesky factorization (`Pptrf`).
+pub trait Ppcon<Real>: Sized {
+    fn ppcon(factor: &PackedSymmetric<Self>, anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a band matrix from its `LU`
+/// factorization (`Gbtrf`).
+pub trait Gbcon<Real>: Sized {
+    fn gbcon(norm: Norm, lu: &BandStorage<Self>, ipiv: &[c_int], anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a tridiagonal matrix from its `LU`
+/// factorization (`gttrf`).
+pub trait Gtcon<Real>: Sized {
+    fn gtcon(norm: Norm, lu: &TridiagonalLU<Self>, anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a symmetric indefinite matrix from its
+/// Bunch-Kaufman factorization (`sytrf`).
+pub trait Sycon<Real>: Sized {
+    fn sycon(a: &SymmetricMatrix<Self>, ipiv: &[c_int], anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a Hermitian indefinite matrix from its
+/// Bunch-Kaufman factorization (`hetrf`).
+pub trait Hecon<Real>: Sized {
+    fn hecon(a: &SymmetricMatrix<Self>, ipiv: &[c_int], anorm: Real) -> Result<Real, Error>;
+}
+
+/// Reciprocal condition number of a triangular matrix. No norm of the
+/// original matrix is needed.
+pub trait Trcon<Real>: Sized {
+    fn trcon(norm: Norm, a: &TriangularMatrix<Self>) -> Result<Real, Error>;
+}
+
+fn cond_norm(norm: Norm) -> Result<Norm, Error> {
+    match norm {
+        Norm::One | Norm::Infinity => Ok(norm),
+        _ => Err(Error::IllegalParameter(1)),
+    }
+}
+
+fn cond_result<T>(rcond: T, info: c_int) -> Result<T, Error> {
+    match info {
+        0 => Ok(rcond),
+        x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        x => Err(Error::DiagonalElementZero(x as usize)),
+    }
+}
+
+macro_rules! real_cond_impl(($($t: ident), +) => ($(
+    impl Gecon<$t> for $t {
+        fn gecon(norm: Norm, lu: &Matrix<Self>, anorm: $t) -> Result<$t, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.rows();
+            if n != lu.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 4 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(lu);
+
+            unsafe {
+                prefix!($t, gecon_)(norm.as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Pocon<$t> for $t {
+        fn pocon(a: &SymmetricMatrix<Self>, anorm: $t) -> Result<$t, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(a);
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 3 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                prefix!($t, pocon_)(a.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Ppcon<$t> for $t {
+        fn ppcon(factor: &PackedSymmetric<Self>, anorm: $t) -> Result<$t, Error> {
+            let n = factor.dim();
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 3 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                prefix!($t, ppcon_)(factor.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factor.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Gbcon<$t> for $t {
+        fn gbcon(norm: Norm, lu: &BandStorage<Self>, ipiv: &[c_int], anorm: $t) -> Result<$t, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.cols();
+            if lu.rows() != n || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 3 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                prefix!($t, gbcon_)(norm.as_i8().as_mut(), n.as_mut(),
+                    lu.sub_diagonals().as_mut(), lu.sup_diagonals().as_mut(),
+                    lu.as_ptr() as *mut _, lu.lead().as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Gtcon<$t> for $t {
+        fn gtcon(norm: Norm, lu: &TridiagonalLU<Self>, anorm: $t) -> Result<$t, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.dim() as usize;
+            let (dl, d, du) = lu.factors().as_ptrs();
+
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 2 * n];
+            let mut iwork: Vec<c_int> = vec![0; n];
+
+            unsafe {
+                prefix!($t, gtcon_)(norm.as_i8().as_mut(), (n as c_int).as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    lu.du2().as_ptr() as *mut _, lu.pivots().as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Sycon<$t> for $t {
+        fn sycon(a: &SymmetricMatrix<Self>, ipiv: &[c_int], anorm: $t) -> Result<$t, Error> {
+            let n = a.rows();
+            if n != a.cols() || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(a);
+
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 2 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                prefix!($t, sycon_)(a.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Trcon<$t> for $t {
+        fn trcon(norm: Norm, a: &TriangularMatrix<Self>) -> Result<$t, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let norm = col_norm(a.order(), try!(cond_norm(norm)));
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut info: c_int = 0;
+            let mut rcond: $t = 0.0;
+            let mut work: Vec<$t> = vec![0.0; 3 * n as usize];
+            let mut iwork: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                prefix!($t, trcon_)(norm.as_i8().as_mut(),
+                    uplo.as_i8().as_mut(), a.diagonal().as_i8().as_mut(),
+                    n.as_mut(),
+                    a.as_ptr() as *mut _, n.as_mut(),
+                    &mut rcond,
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+)+));
+
+macro_rules! complex_cond_impl(($($t: ident: $r: ident), +) => ($(
+    impl Gecon<$r> for $t {
+        fn gecon(norm: Norm, lu: &Matrix<Self>, anorm: $r) -> Result<$r, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.rows();
+            if n != lu.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; 2 * n as usize];
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(lu);
+
+            unsafe {
+                prefix!($t, gecon_)(norm.as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Pocon<$r> for $t {
+        fn pocon(a: &SymmetricMatrix<Self>, anorm: $r) -> Result<$r, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(a);
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, pocon_)(a.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Ppcon<$r> for $t {
+        fn ppcon(factor: &PackedSymmetric<Self>, anorm: $r) -> Result<$r, Error> {
+            let n = factor.dim();
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, ppcon_)(factor.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factor.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Gbcon<$r> for $t {
+        fn gbcon(norm: Norm, lu: &BandStorage<Self>, ipiv: &[c_int], anorm: $r) -> Result<$r, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.cols();
+            if lu.rows() != n || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, gbcon_)(norm.as_i8().as_mut(), n.as_mut(),
+                    lu.sub_diagonals().as_mut(), lu.sup_diagonals().as_mut(),
+                    lu.as_ptr() as *mut _, lu.lead().as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Gtcon<$r> for $t {
+        fn gtcon(norm: Norm, lu: &TridiagonalLU<Self>, anorm: $r) -> Result<$r, Error> {
+            let norm = try!(cond_norm(norm));
+            let n = lu.dim() as usize;
+            let (dl, d, du) = lu.factors().as_ptrs();
+
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n];
+
+            unsafe {
+                prefix!($t, gtcon_)(norm.as_i8().as_mut(), (n as c_int).as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    lu.du2().as_ptr() as *mut _, lu.pivots().as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Sycon<$r> for $t {
+        fn sycon(a: &SymmetricMatrix<Self>, ipiv: &[c_int], anorm: $r) -> Result<$r, Error> {
+            let n = a.rows();
+            if n != a.cols() || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(a);
+
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+
+            unsafe {
+                prefix!($t, sycon_)(a.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Hecon<$r> for $t {
+        fn hecon(a: &SymmetricMatrix<Self>, ipiv: &[c_int], anorm: $r) -> Result<$r, Error> {
+            let n = a.rows();
+            if n != a.cols() || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let factors = col_vec(a);
+
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+
+            unsafe {
+                prefix!($t, hecon_)(a.symmetry().as_i8().as_mut(), n.as_mut(),
+                    factors.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+
+    impl Trcon<$r> for $t {
+        fn trcon(norm: Norm, a: &TriangularMatrix<Self>) -> Result<$r, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            // The transpose of a complex matrix has the same condition
+            // number as its conjugate transpose.
+            let norm = col_norm(a.order(), try!(cond_norm(norm)));
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, trcon_)(norm.as_i8().as_mut(),
+                    uplo.as_i8().as_mut(), a.diagonal().as_i8().as_mut(),
+                    n.as_mut(),
+                    a.as_ptr() as *mut _, n.as_mut(),
+                    &mut rcond,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            cond_result(rcond, info)
+        }
+    }
+)+));
+
+real_cond_impl!(f32, f64);
+complex_cond_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod cond_tests {
+    use num::complex::Complex64;
+    use condition::{Gecon, Hecon, Pocon, Sycon, Trcon};
+    use linear_equations::{Gesv, Hesv, Sysv};
+    use matrix::tests::{M, S, Tr};
+    use norms::Lange;
+    use types::{Diagonal, Norm, Symmetry};
+    use types::Order::*;
+
+    #[test]
+    fn general() {
+        let mut a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 4.0, 1.0, 2.0]);
+        let mut b = M(ColMajor, 2i32, 1i32, vec![-2.0f64, 2.0]);
+        let anorm: f64 = Lange::lange(Norm::One, &a);
+
+        Gesv::gesv(&mut a, &mut b).unwrap();
+        let rcond = Gecon::gecon(Norm::One, &a, anorm).unwrap();
+
+        assert!((rcond - 1.0 / 15.0).abs() < 1e-12);
+        assert!(Gecon::gecon(Norm::Frobenius, &a, anorm).is_err());
+    }
+
+    #[test]
+    fn general_row_major() {
+        let mut a = M(RowMajor, 2i32, 2i32, vec![1.0f64, 1.0, 4.0, 2.0]);
+        let mut b = M(RowMajor, 2i32, 1i32, vec![-2.0f64, 2.0]);
+        let anorm: f64 = Lange::lange(Norm::One, &a);
+
+        Gesv::gesv(&mut a, &mut b).unwrap();
+        let rcond = Gecon::gecon(Norm::One, &a, anorm).unwrap();
+
+        assert!((rcond - 1.0 / 15.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangular() {
+        let a = Tr(Symmetry::Upper, Diagonal::NonUnit, M(RowMajor, 2i32, 2i32, vec![2.0f64, 1.0, 0.0, 4.0]));
+        let rcond: f64 = Trcon::trcon(Norm::One, &a).unwrap();
+
+        assert!((rcond - 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn positive_definite() {
+        // A = [4 2; 2 5] = Uᵀ U with U = [2 1; 0 2].
+        let factors = |order| match order {
+            ColMajor => vec![2.0f64, 0.0, 1.0, 2.0],
+            RowMajor => vec![2.0f64, 1.0, 0.0, 2.0],
+        };
+
+        for &order in &[ColMajor, RowMajor] {
+            let a = S(Symmetry::Upper, M(order, 2i32, 2i32, factors(order)));
+            let rcond = Pocon::pocon(&a, 7.0).unwrap();
+
+            assert!((rcond - 16.0 / 49.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn indefinite() {
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                let mut a = S(uplo, M(order, 2i32, 2i32, vec![1.0f64, 2.0, 2.0, 1.0]));
+                let mut b = M(order, 2i32, 1i32, vec![3.0f64, 3.0]);
+
+                let ipiv = Sysv::sysv(&mut a, &mut b).unwrap();
+                let rcond = Sycon::sycon(&a, &ipiv, 3.0).unwrap();
+
+                assert!((rcond - 1.0 / 3.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn hermitian_indefinite() {
+        let c = |re, im| Complex64::new(re, im);
+
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                // Eigenvalues 3 and -1, so the 1-norm condition number is 3.
+                let mut a = S(uplo, M(order, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 2.0), c(0.0, -2.0), c(1.0, 0.0)]));
+                let mut b = M(order, 2i32, 1i32, vec![c(1.0, 0.0), c(1.0, 0.0)]);
+
+                let ipiv = Hesv::hesv(&mut a, &mut b).unwrap();
+                let rcond: f64 = Hecon::hecon(&a, &ipiv, 3.0).unwrap();
+
+                assert!((rcond - 1.0 / 3.0).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -1,19 +1,22 @@
 // Copyright 2014 Michael Yang. All rights reserved.
 // Use of this source code is governed by a MIT-style
 // license that can be found in the LICENSE file.
+use std::cmp;
 use std::mem;
 use std::ptr;
 use libc::c_int;
 use num::complex::{
     Complex,
+    Complex32,
+    Complex64,
 };
 use error::Error;
 use ll::*;
 use Matrix;
 use Vector;
 use scalar::Scalar;
-use types::Compute;
-use util::ColMem;
+use types::{Balance, Compute, Sense};
+use util::{ColMem, mem_job, square_mem};
 
 pub trait Geev<Eigenvalues>: Sized {
     fn geev(a: &mut Matrix<Self>, left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>) -> Result<Vec<Eigenvalues>, Error> {
@@ -77,7 +80,7 @@ macro_rules! real_eigen_impl(($($t: ident), +) => ($(
                     ptr_l, lead_l.as_mut(),
                     ptr_r, lead_r.as_mut(),
                     work.as_mut_ptr(), (work.len() as c_int).as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1, 1);
             };
 
             let values = real.into_iter().zip(imag.into_iter())
@@ -110,7 +113,7 @@ macro_rules! real_eigen_impl(($($t: ident), +) => ($(
                     ptr::null::<$t>() as *mut _, n.as_mut(),
                     ptr::null::<$t>() as *mut _, n.as_mut(),
                     len_ptr, (-1 as c_int).as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1, 1);
             };
 
             match info {
@@ -124,11 +127,196 @@ macro_rules! real_eigen_impl(($($t: ident), +) => ($(
 
 real_eigen_impl!(f32, f64);
 
+/// Balancing and condition estimates from `Geevx`.
+#[derive(Clone, Debug)]
+pub struct EigenExpert<Real> {
+    pub values: Vec<Complex<Real>>,
+    /// Rows and columns outside `ilo..ihi + 1`, counting from one, were
+    /// isolated by balancing.
+    pub ilo: c_int,
+    pub ihi: c_int,
+    /// Permutations and scale factors applied in balancing, as in `Gebal`.
+    pub scale: Vec<Real>,
+    /// One-norm of the balanced matrix.
+    pub abnrm: Real,
+    /// Reciprocal condition numbers of the eigenvalues and right
+    /// eigenvectors, empty unless requested by `Sense`.
+    pub rconde: Vec<Real>,
+    pub rcondv: Vec<Real>,
+}
+
+/// `Geev` with balancing and condition estimates. `a` is destroyed, and the
+/// eigenvectors are those of the original matrix.
+pub trait Geevx<Real>: Sized {
+    fn geevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>,
+        left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+        -> Result<EigenExpert<Real>, Error>;
+}
+
+// Condition numbers of the eigenvalues need both sets of eigenvectors, so
+// those not asked for go to `scratch`.
+fn geevx_vectors<'a, T: Copy>(v: &mut Option<ColMem<'a, T>>, sense: Sense, n: c_int, zero: T,
+    scratch: &mut Vec<T>) -> (i8, *mut T, c_int) {
+
+    match (v.is_none(), sense) {
+        (true, Sense::Eigenvalues) | (true, Sense::Both) => {
+            *scratch = vec![zero; (n * n) as usize];
+            (b'V' as i8, scratch.as_mut_ptr(), cmp::max(n, 1))
+        },
+        _ => mem_job(v),
+    }
+}
+
+fn geevx_error(info: c_int) -> Error {
+    match info {
+        x if x < 0 => Error::IllegalParameter(-x as usize),
+        x => Error::NoConvergence(x as usize),
+    }
+}
+
+// Workspaces are sized for `geevx` with `Sense::Both`.
+macro_rules! real_geevx_impl(($($t: ident), +) => ($(
+    impl Geevx<$t> for $t {
+        fn geevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<EigenExpert<$t>, Error> {
+
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let mut scratch_l = Vec::new();
+            let mut scratch_r = Vec::new();
+            let mut l_mem = try!(square_mem(n, left));
+            let mut r_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = geevx_vectors(&mut l_mem, sense, n, 0.0, &mut scratch_l);
+            let (jobvr, vr, ldvr) = geevx_vectors(&mut r_mem, sense, n, 0.0, &mut scratch_r);
+            let mut a_mem = ColMem::new(a.order(), a);
+
+            let mut info: c_int = 0;
+            let mut ilo: c_int = 0;
+            let mut ihi: c_int = 0;
+            let mut abnrm: $t = 0.0;
+            let mut wr: Vec<$t> = vec![0.0; len];
+            let mut wi: Vec<$t> = vec![0.0; len];
+            let mut scale: Vec<$t> = vec![0.0; len];
+            let mut rconde: Vec<$t> = vec![0.0; len];
+            let mut rcondv: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; cmp::max(1, len * (len + 6))];
+            let mut iwork: Vec<c_int> = vec![0; cmp::max(1, 2 * len)];
+
+            unsafe {
+                prefix!($t, geevx_)(balance.as_i8().as_mut(),
+                    jobvl.as_mut(), jobvr.as_mut(), sense.as_i8().as_mut(),
+                    n.as_mut(), a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    vl, ldvl.as_mut(),
+                    vr, ldvr.as_mut(),
+                    &mut ilo, &mut ihi, scale.as_mut_ptr(), &mut abnrm,
+                    rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1, 1);
+            }
+
+            let (e_len, v_len) = sense.condition_lens(len);
+            rconde.truncate(e_len);
+            rcondv.truncate(v_len);
+
+            match info {
+                0 => Ok(EigenExpert {
+                    values: wr.into_iter().zip(wi.into_iter()).map(|(r, i)| Complex::new(r, i)).collect(),
+                    ilo: ilo,
+                    ihi: ihi,
+                    scale: scale,
+                    abnrm: abnrm,
+                    rconde: rconde,
+                    rcondv: rcondv,
+                }),
+                x => Err(geevx_error(x)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_geevx_impl(($($t: ident: $r: ident), +) => ($(
+    impl Geevx<$r> for $t {
+        fn geevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<EigenExpert<$r>, Error> {
+
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let zero = $t::new(0.0, 0.0);
+            let mut scratch_l = Vec::new();
+            let mut scratch_r = Vec::new();
+            let mut l_mem = try!(square_mem(n, left));
+            let mut r_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = geevx_vectors(&mut l_mem, sense, n, zero, &mut scratch_l);
+            let (jobvr, vr, ldvr) = geevx_vectors(&mut r_mem, sense, n, zero, &mut scratch_r);
+            let mut a_mem = ColMem::new(a.order(), a);
+
+            let mut info: c_int = 0;
+            let mut ilo: c_int = 0;
+            let mut ihi: c_int = 0;
+            let mut abnrm: $r = 0.0;
+            let mut w: Vec<$t> = vec![zero; len];
+            let mut scale: Vec<$r> = vec![0.0; len];
+            let mut rconde: Vec<$r> = vec![0.0; len];
+            let mut rcondv: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![zero; cmp::max(1, len * (len + 2))];
+            let mut rwork: Vec<$r> = vec![0.0; cmp::max(1, 2 * len)];
+
+            unsafe {
+                prefix!($t, geevx_)(balance.as_i8().as_mut(),
+                    jobvl.as_mut(), jobvr.as_mut(), sense.as_i8().as_mut(),
+                    n.as_mut(), a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    w.as_mut_ptr(),
+                    vl, ldvl.as_mut(),
+                    vr, ldvr.as_mut(),
+                    &mut ilo, &mut ihi, scale.as_mut_ptr(), &mut abnrm,
+                    rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1, 1);
+            }
+
+            let (e_len, v_len) = sense.condition_lens(len);
+            rconde.truncate(e_len);
+            rcondv.truncate(v_len);
+
+            match info {
+                0 => Ok(EigenExpert {
+                    values: w,
+                    ilo: ilo,
+                    ihi: ihi,
+                    scale: scale,
+                    abnrm: abnrm,
+                    rconde: rconde,
+                    rcondv: rcondv,
+                }),
+                x => Err(geevx_error(x)),
+            }
+        }
+    }
+)+));
+
+real_geevx_impl!(f32, f64);
+complex_geevx_impl!(Complex32: f32, Complex64: f64);
+
 #[cfg(test)]
 mod geev_tests {
     use num::Complex;
-    use eigenvalues::Geev;
+    use num::complex::Complex64;
+    use eigenvalues::{Geev, Geevx};
     use matrix::tests::M;
+    use types::{Balance, Sense};
     use types::Order::*;
 
     #[test]
@@ -138,4 +326,40 @@ mod geev_tests {
 
         assert_eq!(lambda, vec![Complex::new(1.0, 0.0), Complex::new(-5.0, 0.0)]);
     }
+
+    #[test]
+    fn expert() {
+        let mut a = M(RowMajor, 2i32, 2i32, vec![2.0f64, 7.0, -1.0, -6.0]);
+        let e = Geevx::geevx(Balance::Both, Sense::Both, &mut a, None, None).unwrap();
+
+        assert!((e.values[0] - Complex::new(1.0, 0.0)).norm() < 1e-12);
+        assert!((e.values[1] - Complex::new(-5.0, 0.0)).norm() < 1e-12);
+        assert_eq!(e.scale.len(), 2);
+        assert!(e.abnrm > 0.0);
+        assert!(e.rconde.iter().all(|&x| x > 0.0 && x <= 1.0));
+        assert!(e.rcondv.iter().all(|&x| x > 0.0));
+        assert_eq!((e.rconde.len(), e.rcondv.len()), (2, 2));
+
+        let mut a = M(RowMajor, 2i32, 2i32, vec![2.0f64, 7.0, -1.0, -6.0]);
+        let e = Geevx::geevx(Balance::None, Sense::None, &mut a, None, None).unwrap();
+        assert!(e.rconde.is_empty() && e.rcondv.is_empty());
+    }
+
+    #[test]
+    fn complex_expert() {
+        let c = |re, im| Complex64::new(re, im);
+        let data = vec![c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0), c(0.0, 2.0)];
+        let mut a = M(ColMajor, 2i32, 2i32, data.clone());
+        let mut v = M(ColMajor, 2i32, 2i32, vec![c(0.0, 0.0); 4]);
+        let e = Geevx::geevx(Balance::Both, Sense::Eigenvectors, &mut a, None, Some(&mut v)).unwrap();
+
+        assert!(e.rconde.is_empty());
+        assert_eq!(e.rcondv.len(), 2);
+        for j in 0..2 {
+            for i in 0..2 {
+                let av = (0..2).fold(c(0.0, 0.0), |s, k| s + data[i + k * 2] * v.3[k + j * 2]);
+                assert!((av - e.values[j] * v.3[i + j * 2]).norm() < 1e-12);
+            }
+        }
+    }
 }
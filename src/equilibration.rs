@@ -0,0 +1,390 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::marker::PhantomData;
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use band::BandStorage;
+use error::Error;
+use linear_equations::Gesv;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+};
+use scalar::Scalar;
+use types::{
+    Equilibration,
+    Order,
+};
+use util::{ColMem, col_dims, col_uplo};
+
+/// Row and column scale factors of a general matrix. Scaling row `i` by
+/// `rows[i]` and column `j` by `cols[j]` makes the largest element in each
+/// row and column close to 1.
+#[derive(Clone, Debug)]
+pub struct Scaling<Real> {
+    pub rows: Vec<Real>,
+    pub cols: Vec<Real>,
+    /// Ratio of the smallest to the largest row scale factor.
+    pub row_cond: Real,
+    /// Ratio of the smallest to the largest column scale factor.
+    pub col_cond: Real,
+    /// Largest absolute element of the matrix.
+    pub amax: Real,
+}
+
+/// Scale factors of a symmetric (Hermitian) positive definite matrix,
+/// applied to both its rows and columns.
+#[derive(Clone, Debug)]
+pub struct SymmetricScaling<Real> {
+    pub scale: Vec<Real>,
+    /// Ratio of the smallest to the largest scale factor.
+    pub cond: Real,
+    /// Largest absolute element of the matrix.
+    pub amax: Real,
+}
+
+/// Scale factors that equilibrate a general matrix.
+pub trait Geequ<Real>: Sized {
+    fn geequ(a: &Matrix<Self>) -> Result<Scaling<Real>, Error>;
+}
+
+/// Like `Geequ`, but restricts the factors to powers of the radix so that
+/// scaling introduces no rounding error.
+pub trait Geequb<Real>: Sized {
+    fn geequb(a: &Matrix<Self>) -> Result<Scaling<Real>, Error>;
+}
+
+/// Scale factors that equilibrate a band matrix.
+pub trait Gbequ<Real>: Sized {
+    fn gbequ(a: &BandStorage<Self>) -> Result<Scaling<Real>, Error>;
+}
+
+/// Scale factors that equilibrate a symmetric (Hermitian) positive definite
+/// matrix, computed from its diagonal.
+pub trait Poequ<Real>: Sized {
+    fn poequ(a: &SymmetricMatrix<Self>) -> Result<SymmetricScaling<Real>, Error>;
+}
+
+/// Like `Poequ`, but restricts the factors to powers of the radix.
+pub trait Poequb<Real>: Sized {
+    fn poequb(a: &SymmetricMatrix<Self>) -> Result<SymmetricScaling<Real>, Error>;
+}
+
+/// Applies `scaling` to a general matrix when it is worthwhile, reporting
+/// which sides were scaled.
+pub trait Laqge<Real>: Sized {
+    fn laqge(a: &mut Matrix<Self>, scaling: &Scaling<Real>) -> Result<Equilibration, Error>;
+}
+
+/// Applies `scaling` to both sides of a symmetric matrix when it is
+/// worthwhile.
+pub trait Laqsy<Real>: Sized {
+    fn laqsy(a: &mut SymmetricMatrix<Self>, scaling: &SymmetricScaling<Real>) -> Result<Equilibration, Error>;
+}
+
+/// Multiplies row `i` of `x` by `d[i]`.
+pub trait Lascl2<Real>: Sized {
+    fn lascl2(d: &[Real], x: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+type GeneralEqu<T, R> = unsafe extern "C" fn(*mut c_int, *mut c_int, *mut T, *mut c_int,
+    *mut R, *mut R, *mut R, *mut R, *mut R, *mut c_int);
+
+type SymmetricEqu<T, R> = unsafe extern "C" fn(*mut c_int, *mut T, *mut c_int,
+    *mut R, *mut R, *mut R, *mut c_int);
+
+fn general_equ<T, R>(f: GeneralEqu<T, R>, a: &Matrix<T>) -> Result<Scaling<R>, Error>
+    where R: Clone + Default {
+
+    let mut info: c_int = 0;
+    let (m, n, lda) = col_dims(a);
+    let mut r = vec![R::default(); m as usize];
+    let mut c = vec![R::default(); n as usize];
+    let mut rowcnd = R::default();
+    let mut colcnd = R::default();
+    let mut amax = R::default();
+
+    unsafe {
+        f(&m as *const _ as *mut _, &n as *const _ as *mut _,
+            a.as_ptr() as *mut _, &lda as *const _ as *mut _,
+            r.as_mut_ptr(), c.as_mut_ptr(),
+            &mut rowcnd, &mut colcnd, &mut amax,
+            &mut info as *mut c_int);
+    }
+
+    // LAPACK sees the transpose of a row major matrix.
+    let scaling = match a.order() {
+        Order::ColMajor => Scaling { rows: r, cols: c, row_cond: rowcnd, col_cond: colcnd, amax: amax },
+        Order::RowMajor => Scaling { rows: c, cols: r, row_cond: colcnd, col_cond: rowcnd, amax: amax },
+    };
+
+    match (info, a.order()) {
+        (0, _) => Ok(scaling),
+        (x, _) if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        (x, Order::ColMajor) if x <= m => Err(Error::ZeroRow(x as usize)),
+        (x, Order::ColMajor) => Err(Error::ZeroColumn((x - m) as usize)),
+        (x, Order::RowMajor) if x <= m => Err(Error::ZeroColumn(x as usize)),
+        (x, Order::RowMajor) => Err(Error::ZeroRow((x - m) as usize)),
+    }
+}
+
+fn symmetric_equ<T, R>(f: SymmetricEqu<T, R>, a: &SymmetricMatrix<T>) -> Result<SymmetricScaling<R>, Error>
+    where R: Clone + Default {
+
+    let mut info: c_int = 0;
+    let (_, n, lda) = col_dims(a);
+    let mut s = vec![R::default(); n as usize];
+    let mut scond = R::default();
+    let mut amax = R::default();
+
+    unsafe {
+        f(&n as *const _ as *mut _,
+            a.as_ptr() as *mut _, &lda as *const _ as *mut _,
+            s.as_mut_ptr(), &mut scond, &mut amax,
+            &mut info as *mut c_int);
+    }
+
+    match info {
+        0 => Ok(SymmetricScaling { scale: s, cond: scond, amax: amax }),
+        x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        x => Err(Error::NotPositiveDefinite(x as usize)),
+    }
+}
+
+macro_rules! equ_impl(($($t: ident: $r: ident), +) => ($(
+    impl Geequ<$r> for $t {
+        fn geequ(a: &Matrix<Self>) -> Result<Scaling<$r>, Error> {
+            general_equ(prefix!($t, geequ_), a)
+        }
+    }
+
+    impl Geequb<$r> for $t {
+        fn geequb(a: &Matrix<Self>) -> Result<Scaling<$r>, Error> {
+            general_equ(prefix!($t, geequb_), a)
+        }
+    }
+
+    impl Gbequ<$r> for $t {
+        fn gbequ(a: &BandStorage<Self>) -> Result<Scaling<$r>, Error> {
+            let mut info: c_int = 0;
+            let m = a.rows();
+            let n = a.cols();
+            let mut r: Vec<$r> = vec![0.0; m as usize];
+            let mut c: Vec<$r> = vec![0.0; n as usize];
+            let mut rowcnd: $r = 0.0;
+            let mut colcnd: $r = 0.0;
+            let mut amax: $r = 0.0;
+
+            unsafe {
+                prefix!($t, gbequ_)(m.as_mut(), n.as_mut(),
+                    a.sub_diagonals().as_mut(), a.sup_diagonals().as_mut(),
+                    a.as_band_ptr() as *mut _, a.lead().as_mut(),
+                    r.as_mut_ptr(), c.as_mut_ptr(),
+                    &mut rowcnd, &mut colcnd, &mut amax,
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(Scaling { rows: r, cols: c, row_cond: rowcnd, col_cond: colcnd, amax: amax }),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x if x <= m => Err(Error::ZeroRow(x as usize)),
+                x => Err(Error::ZeroColumn((x - m) as usize)),
+            }
+        }
+    }
+
+    impl Poequ<$r> for $t {
+        fn poequ(a: &SymmetricMatrix<Self>) -> Result<SymmetricScaling<$r>, Error> {
+            symmetric_equ(prefix!($t, poequ_), a)
+        }
+    }
+
+    impl Poequb<$r> for $t {
+        fn poequb(a: &SymmetricMatrix<Self>) -> Result<SymmetricScaling<$r>, Error> {
+            symmetric_equ(prefix!($t, poequb_), a)
+        }
+    }
+
+    impl Laqge<$r> for $t {
+        fn laqge(a: &mut Matrix<Self>, scaling: &Scaling<$r>) -> Result<Equilibration, Error> {
+            if scaling.rows.len() != a.rows() as usize || scaling.cols.len() != a.cols() as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let order = a.order();
+            let (m, n, lda) = col_dims(a);
+            let mut equed: i8 = 0;
+
+            let (r, c, rowcnd, colcnd) = match order {
+                Order::ColMajor => (&scaling.rows, &scaling.cols, scaling.row_cond, scaling.col_cond),
+                Order::RowMajor => (&scaling.cols, &scaling.rows, scaling.col_cond, scaling.row_cond),
+            };
+
+            unsafe {
+                prefix!($t, laqge_)(m.as_mut(), n.as_mut(),
+                    a.as_mut_ptr(), lda.as_mut(),
+                    r.as_ptr() as *mut _, c.as_ptr() as *mut _,
+                    rowcnd.as_mut(), colcnd.as_mut(), scaling.amax.as_mut(),
+                    &mut equed, 1);
+            }
+
+            Ok(match (order, Equilibration::from_i8(equed)) {
+                (Order::RowMajor, Equilibration::Row) => Equilibration::Column,
+                (Order::RowMajor, Equilibration::Column) => Equilibration::Row,
+                (_, equed) => equed,
+            })
+        }
+    }
+
+    impl Laqsy<$r> for $t {
+        fn laqsy(a: &mut SymmetricMatrix<Self>, scaling: &SymmetricScaling<$r>) -> Result<Equilibration, Error> {
+            let (_, n, lda) = col_dims(a);
+            if scaling.scale.len() != n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut equed: i8 = 0;
+
+            unsafe {
+                prefix!($t, laqsy_)(uplo.as_i8().as_mut(), n.as_mut(),
+                    a.as_mut_ptr(), lda.as_mut(),
+                    scaling.scale.as_ptr() as *mut _,
+                    scaling.cond.as_mut(), scaling.amax.as_mut(),
+                    &mut equed, 1, 1);
+            }
+
+            Ok(Equilibration::from_i8(equed))
+        }
+    }
+
+    impl Lascl2<$r> for $t {
+        fn lascl2(d: &[$r], x: &mut Matrix<Self>) -> Result<(), Error> {
+            let m = x.rows();
+            let n = x.cols();
+            if d.len() != m as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, lascl2_)(m.as_mut(), n.as_mut(),
+                    d.as_ptr() as *mut _,
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut());
+            }
+
+            Ok(())
+        }
+    }
+)+));
+
+equ_impl!(f32: f32, f64: f64, Complex32: f32, Complex64: f64);
+
+/// A general square matrix that has been equilibrated in place.
+///
+/// Solving `A x = b` with an equilibrated matrix scales `b` by the row
+/// factors, solves the scaled system and scales the result by the column
+/// factors, so callers see the solution of the original system.
+pub struct Equilibrated<M, T, Real> {
+    a: M,
+    scaling: Scaling<Real>,
+    equed: Equilibration,
+    elem: PhantomData<T>,
+}
+
+impl<M, T, Real> Equilibrated<M, T, Real>
+    where M: Matrix<T>, T: Geequb<Real> + Laqge<Real> + Lascl2<Real> + Gesv {
+
+    /// Computes scale factors with `geequb` and applies them with `laqge`.
+    pub fn new(mut a: M) -> Result<Equilibrated<M, T, Real>, Error> {
+        let scaling = try!(T::geequb(&a));
+        let equed = try!(T::laqge(&mut a, &scaling));
+
+        Ok(Equilibrated {
+            a: a,
+            scaling: scaling,
+            equed: equed,
+            elem: PhantomData,
+        })
+    }
+
+    /// The scaled matrix.
+    pub fn matrix(&self) -> &M { &self.a }
+    pub fn into_inner(self) -> M { self.a }
+    pub fn scaling(&self) -> &Scaling<Real> { &self.scaling }
+    pub fn equilibration(&self) -> Equilibration { self.equed }
+
+    /// Scales a right hand side of the original system for the scaled one.
+    pub fn scale_rhs(&self, b: &mut Matrix<T>) -> Result<(), Error> {
+        match self.equed {
+            Equilibration::Row | Equilibration::Both => T::lascl2(&self.scaling.rows, b),
+            _ => Ok(()),
+        }
+    }
+
+    /// Turns a solution of the scaled system into one of the original.
+    pub fn unscale_solution(&self, x: &mut Matrix<T>) -> Result<(), Error> {
+        match self.equed {
+            Equilibration::Column | Equilibration::Both => T::lascl2(&self.scaling.cols, x),
+            _ => Ok(()),
+        }
+    }
+
+    /// Solves `A x = b` for the original `A`, overwriting `b` with `x`, and
+    /// returns the `LU` factors of the scaled matrix with the pivots, like
+    /// `Gesv`. The matrix is factored in place, so this consumes the wrapper;
+    /// pass every right hand side as a column of `b`.
+    pub fn solve(mut self, b: &mut Matrix<T>) -> Result<(M, Vec<usize>), Error> {
+        try!(self.scale_rhs(b));
+        let pivots = try!(T::gesv(&mut self.a, b));
+        try!(self.unscale_solution(b));
+
+        Ok((self.a, pivots))
+    }
+}
+
+#[cfg(test)]
+mod equ_tests {
+    use equilibration::{Equilibrated, Geequ};
+    use matrix::tests::M;
+    use types::Equilibration;
+    use types::Order::*;
+
+    #[test]
+    fn scale_factors() {
+        let a = M(RowMajor, 2i32, 2i32, vec![1.0f64, 2.0, 300.0, 400.0]);
+        let s = Geequ::<f64>::geequ(&a).unwrap();
+
+        assert_eq!(s.rows, vec![0.5, 1.0 / 400.0]);
+        assert_eq!(s.amax, 400.0);
+    }
+
+    #[test]
+    fn zero_row() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 2.0, 0.0]);
+
+        assert!(Geequ::<f64>::geequ(&a).is_err());
+    }
+
+    #[test]
+    fn solve() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1e-3f64, 3e3, 2e-3, 4e3]);
+        let mut b = M(ColMajor, 2i32, 1i32, vec![5e-3f64, 1.1e4]);
+
+        let eq: Equilibrated<_, f64, f64> = Equilibrated::new(a).unwrap();
+        assert_eq!(eq.equilibration(), Equilibration::Row);
+        let (lu, pivots) = eq.solve(&mut b).unwrap();
+        assert_eq!(pivots.len(), 2);
+        assert_eq!(lu.3.len(), 4);
+
+        let M(_, _, _, x) = b;
+        assert!((x[0] - 1.0).abs() < 1e-12);
+        assert!((x[1] - 2.0).abs() < 1e-12);
+    }
+}
@@ -7,4 +7,11 @@ pub enum Error {
     DimensionMismatch,
     IllegalParameter(usize),
     DiagonalElementZero(usize),
+    ZeroRow(usize),
+    ZeroColumn(usize),
+    NotPositiveDefinite(usize),
+    NoConvergence(usize),
+    /// Eigenvalues were too close to be swapped while reordering a Schur
+    /// form.
+    ReorderFailed,
 }
@@ -1,3 +1,691 @@
 // Copyright 2014 Michael Yang. All rights reserved.
 // Use of this source code is governed by a MIT-style
 // license that can be found in the LICENSE file.
+use std::ptr;
+use libc::c_int;
+use num::complex::{
+    Complex,
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+};
+use scalar::Scalar;
+use types::{Balance, Compute, EigenRange, GeneralizedForm, Sense};
+use util::{ColMem, complex_values, mem_job, real_values, square_mem};
+
+/// Eigenvalues, in ascending order, of a generalized symmetric-definite
+/// problem. `a` and `b` must share a symmetry.
+///
+/// With `Compute::Value`, `a` is overwritten with the eigenvectors, which
+/// are `B`-orthonormal (`B⁻¹`-orthonormal for `BAx`); otherwise it is
+/// destroyed. `b` is overwritten with its Cholesky factor. A `b` that is
+/// not positive definite is reported as `NotPositiveDefinite` with the
+/// order of the failing leading minor, and a failure of the eigensolver as
+/// `NoConvergence`.
+pub trait Sygv<Real>: Sized {
+    fn sygv(form: GeneralizedForm, jobz: Compute,
+        a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<Real>, Error>;
+}
+
+/// `Sygv` using divide and conquer, which is faster for eigenvectors of
+/// large matrices.
+pub trait Sygvd<Real>: Sized {
+    fn sygvd(form: GeneralizedForm, jobz: Compute,
+        a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<Real>, Error>;
+}
+
+/// `Sygv` for a range of eigenvalues, with eigenvectors written to the
+/// first columns of `z`. `a` is destroyed. `NoConvergence` counts the
+/// eigenvectors that failed to converge.
+pub trait Sygvx<Real>: Sized {
+    fn sygvx(form: GeneralizedForm, a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>,
+        range: EigenRange<Real>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Real>, Error>;
+}
+
+/// The complex Hermitian counterpart of `Sygv`.
+pub trait Hegv<Real>: Sized {
+    fn hegv(form: GeneralizedForm, jobz: Compute,
+        a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<Real>, Error>;
+}
+
+/// The complex Hermitian counterpart of `Sygvd`.
+pub trait Hegvd<Real>: Sized {
+    fn hegvd(form: GeneralizedForm, jobz: Compute,
+        a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<Real>, Error>;
+}
+
+/// The complex Hermitian counterpart of `Sygvx`.
+pub trait Hegvx<Real>: Sized {
+    fn hegvx(form: GeneralizedForm, a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>,
+        range: EigenRange<Real>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Real>, Error>;
+}
+
+fn check_pair<T>(a: &SymmetricMatrix<T>, b: &SymmetricMatrix<T>) -> Result<c_int, Error> {
+    let n = a.rows();
+    if n != a.cols() || b.rows() != n || b.cols() != n {
+        return Err(Error::DimensionMismatch);
+    }
+    if a.symmetry() != b.symmetry() {
+        return Err(Error::IllegalParameter(3));
+    }
+
+    Ok(n)
+}
+
+fn gv_error(n: c_int, info: c_int) -> Error {
+    match info {
+        x if x < 0 => Error::IllegalParameter(-x as usize),
+        x if x > n => Error::NotPositiveDefinite((x - n) as usize),
+        x => Error::NoConvergence(x as usize),
+    }
+}
+
+// Workspaces are sized for the largest of the three drivers: `gvd` with
+// eigenvectors.
+macro_rules! sygv_impl(($($t: ident: $r: ident, $zero: expr, $work_len: expr,
+    $gv_trait: ident, $gv: ident, $gv_fn: ident,
+    $gvd_trait: ident, $gvd: ident, $gvd_fn: ident,
+    $gvx_trait: ident, $gvx: ident, $gvx_fn: ident,
+    [$($rwork: ident)*]), +) => ($(
+
+    impl $gv_trait<$r> for $t {
+        fn $gv(form: GeneralizedForm, jobz: Compute,
+            a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_pair(a, b));
+            let uplo = a.symmetry();
+
+            let len = if n > 0 { n as usize } else { 1 };
+            let mut info: c_int = 0;
+            let mut w: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![$zero; $work_len(len)];
+            $(let mut $rwork: Vec<$r> = vec![0.0; 1 + 5 * len + 2 * len * len];)*
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                $gv_fn(form.as_c_int().as_mut(), jobz.as_i8().as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    w.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    $($rwork.as_mut_ptr(),)*
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            w.truncate(n as usize);
+
+            match info {
+                0 => Ok(w),
+                x => Err(gv_error(n, x)),
+            }
+        }
+    }
+
+    impl $gvd_trait<$r> for $t {
+        fn $gvd(form: GeneralizedForm, jobz: Compute,
+            a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>) -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_pair(a, b));
+            let uplo = a.symmetry();
+
+            let len = if n > 0 { n as usize } else { 1 };
+            let mut info: c_int = 0;
+            let mut w: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![$zero; $work_len(len)];
+            $(let mut $rwork: Vec<$r> = vec![0.0; 1 + 5 * len + 2 * len * len];)*
+            let mut iwork: Vec<c_int> = vec![0; 3 + 5 * len];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                $gvd_fn(form.as_c_int().as_mut(), jobz.as_i8().as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    w.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    $($rwork.as_mut_ptr(), ($rwork.len() as c_int).as_mut(),)*
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            w.truncate(n as usize);
+
+            match info {
+                0 => Ok(w),
+                x => Err(gv_error(n, x)),
+            }
+        }
+    }
+
+    impl $gvx_trait<$r> for $t {
+        fn $gvx(form: GeneralizedForm, a: &mut SymmetricMatrix<Self>, b: &mut SymmetricMatrix<Self>,
+            range: EigenRange<$r>, z: Option<&mut Matrix<Self>>) -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_pair(a, b));
+            let uplo = a.symmetry();
+
+            let (mut vl, mut vu, mut il, mut iu) = match range {
+                EigenRange::All => (0.0, 0.0, 1, n),
+                EigenRange::Values(l, u) => (l, u, 1, n),
+                EigenRange::Indices(l, u) => (0.0, 0.0, l, u),
+            };
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() < iu - il + 1 {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz) = match z_mem {
+                Some(ref mut z) => (Compute::Value, z.as_mut_ptr(), z.lead()),
+                None => (Compute::None, ptr::null::<$t>() as *mut _, 1),
+            };
+
+            let len = if n > 0 { n as usize } else { 1 };
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut w: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![$zero; $work_len(len)];
+            $(let mut $rwork: Vec<$r> = vec![0.0; 1 + 5 * len + 2 * len * len];)*
+            let mut iwork: Vec<c_int> = vec![0; 5 * len];
+            let mut ifail: Vec<c_int> = vec![0; len];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                $gvx_fn(form.as_c_int().as_mut(), jobz.as_i8().as_mut(),
+                    range.as_i8().as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut vl, &mut vu, &mut il, &mut iu,
+                    (0.0 as $r).as_mut(), &mut m,
+                    w.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    $($rwork.as_mut_ptr(),)*
+                    iwork.as_mut_ptr(), ifail.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            w.truncate(m as usize);
+
+            match info {
+                0 => Ok(w),
+                x => Err(gv_error(n, x)),
+            }
+        }
+    }
+)+));
+
+sygv_impl!(
+    f32: f32, 0.0, |n: usize| 1 + 6 * n + 2 * n * n,
+        Sygv, sygv, ssygv_, Sygvd, sygvd, ssygvd_, Sygvx, sygvx, ssygvx_, [],
+    f64: f64, 0.0, |n: usize| 1 + 6 * n + 2 * n * n,
+        Sygv, sygv, dsygv_, Sygvd, sygvd, dsygvd_, Sygvx, sygvx, dsygvx_, [],
+    Complex32: f32, Complex32::new(0.0, 0.0), |n: usize| 2 * n + n * n,
+        Hegv, hegv, chegv_, Hegvd, hegvd, chegvd_, Hegvx, hegvx, chegvx_, [rwork],
+    Complex64: f64, Complex64::new(0.0, 0.0), |n: usize| 2 * n + n * n,
+        Hegv, hegv, zhegv_, Hegvd, hegvd, zhegvd_, Hegvx, hegvx, zhegvx_, [rwork]
+);
+
+/// A generalized eigenvalue `alpha / beta`. `beta` is zero for an infinite
+/// eigenvalue, and real and nonnegative for real matrices.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GeneralizedEigenvalue<T> {
+    pub alpha: Complex<T>,
+    pub beta: Complex<T>,
+}
+
+/// Balancing and condition estimates from `Ggevx`.
+#[derive(Clone, Debug)]
+pub struct GeneralizedExpert<Real> {
+    pub values: Vec<GeneralizedEigenvalue<Real>>,
+    /// Rows and columns outside `ilo..ihi + 1`, counting from one, were
+    /// isolated by balancing.
+    pub ilo: c_int,
+    pub ihi: c_int,
+    /// Permutations and scale factors applied to the left and right.
+    pub lscale: Vec<Real>,
+    pub rscale: Vec<Real>,
+    /// One-norms of the balanced `A` and `B`.
+    pub abnrm: Real,
+    pub bbnrm: Real,
+    /// Reciprocal condition numbers of the eigenvalues and eigenvectors,
+    /// empty unless requested by `Sense`.
+    pub rconde: Vec<Real>,
+    pub rcondv: Vec<Real>,
+}
+
+/// Generalized eigenvalues of a nonsymmetric pair, `A x = λ B x`, with
+/// optional left and right eigenvectors. `a` and `b` are destroyed.
+///
+/// For real matrices a complex pair of eigenvalues comes in consecutive
+/// entries, and the real and imaginary parts of the eigenvector of the
+/// first one are stored in the corresponding pair of columns, as in `Geev`.
+pub trait Ggev<Real>: Sized {
+    fn ggev(a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+        left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+        -> Result<Vec<GeneralizedEigenvalue<Real>>, Error>;
+}
+
+/// `Ggev` with balancing and condition estimates. The eigenvectors are
+/// those of the original pair.
+pub trait Ggevx<Real>: Sized {
+    fn ggevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+        left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+        -> Result<GeneralizedExpert<Real>, Error>;
+}
+
+fn check_square_pair<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<c_int, Error> {
+    let n = a.rows();
+    if n != a.cols() || b.rows() != n || b.cols() != n {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(n)
+}
+
+fn ggev_error(info: c_int) -> Error {
+    match info {
+        x if x < 0 => Error::IllegalParameter(-x as usize),
+        x => Error::NoConvergence(x as usize),
+    }
+}
+
+// Workspaces are sized for `ggevx` with `Sense::Both`.
+macro_rules! real_ggev_impl(($($t: ident), +) => ($(
+    impl GeneralizedEigenvalue<$t> {
+        pub fn is_infinite(&self) -> bool {
+            self.beta.re == 0.0 && self.beta.im == 0.0
+        }
+
+        /// `alpha / beta`, or `None` if the eigenvalue is infinite.
+        pub fn value(&self) -> Option<Complex<$t>> {
+            if self.is_infinite() {
+                None
+            } else {
+                Some(self.alpha / self.beta)
+            }
+        }
+    }
+
+    impl Ggev<$t> for $t {
+        fn ggev(a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<Vec<GeneralizedEigenvalue<$t>>, Error> {
+
+            let n = try!(check_square_pair(a, b));
+            let mut left_mem = try!(square_mem(n, left));
+            let mut right_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = mem_job(&mut left_mem);
+            let (jobvr, vr, ldvr) = mem_job(&mut right_mem);
+
+            let len = n as usize;
+            let mut info: c_int = 0;
+            let mut alphar: Vec<$t> = vec![0.0; len];
+            let mut alphai: Vec<$t> = vec![0.0; len];
+            let mut beta: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; 8 * len + 16];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, ggev_)(jobvl.as_mut(), jobvr.as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    alphar.as_mut_ptr(), alphai.as_mut_ptr(), beta.as_mut_ptr(),
+                    vl, ldvl.as_mut(), vr, ldvr.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(real_values(alphar, alphai, beta)),
+                x => Err(ggev_error(x)),
+            }
+        }
+    }
+
+    impl Ggevx<$t> for $t {
+        fn ggevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<GeneralizedExpert<$t>, Error> {
+
+            let n = try!(check_square_pair(a, b));
+            let mut left_mem = try!(square_mem(n, left));
+            let mut right_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = mem_job(&mut left_mem);
+            let (jobvr, vr, ldvr) = mem_job(&mut right_mem);
+
+            let len = n as usize;
+            let mut info: c_int = 0;
+            let mut ilo: c_int = 0;
+            let mut ihi: c_int = 0;
+            let mut abnrm: $t = 0.0;
+            let mut bbnrm: $t = 0.0;
+            let mut alphar: Vec<$t> = vec![0.0; len];
+            let mut alphai: Vec<$t> = vec![0.0; len];
+            let mut beta: Vec<$t> = vec![0.0; len];
+            let mut lscale: Vec<$t> = vec![0.0; len];
+            let mut rscale: Vec<$t> = vec![0.0; len];
+            let mut rconde: Vec<$t> = vec![0.0; len];
+            let mut rcondv: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; 2 * len * len + 10 * len + 16];
+            let mut iwork: Vec<c_int> = vec![0; len + 6];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, ggevx_)(balance.as_i8().as_mut(), jobvl.as_mut(), jobvr.as_mut(),
+                    sense.as_i8().as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    alphar.as_mut_ptr(), alphai.as_mut_ptr(), beta.as_mut_ptr(),
+                    vl, ldvl.as_mut(), vr, ldvr.as_mut(),
+                    &mut ilo, &mut ihi,
+                    lscale.as_mut_ptr(), rscale.as_mut_ptr(),
+                    &mut abnrm, &mut bbnrm,
+                    rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), bwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1, 1);
+            }
+
+            let (e_len, v_len) = sense.condition_lens(len);
+            rconde.truncate(e_len);
+            rcondv.truncate(v_len);
+
+            match info {
+                0 => Ok(GeneralizedExpert {
+                    values: real_values(alphar, alphai, beta),
+                    ilo: ilo,
+                    ihi: ihi,
+                    lscale: lscale,
+                    rscale: rscale,
+                    abnrm: abnrm,
+                    bbnrm: bbnrm,
+                    rconde: rconde,
+                    rcondv: rcondv,
+                }),
+                x => Err(ggev_error(x)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_ggev_impl(($($t: ident: $r: ident), +) => ($(
+    impl Ggev<$r> for $t {
+        fn ggev(a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<Vec<GeneralizedEigenvalue<$r>>, Error> {
+
+            let n = try!(check_square_pair(a, b));
+            let mut left_mem = try!(square_mem(n, left));
+            let mut right_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = mem_job(&mut left_mem);
+            let (jobvr, vr, ldvr) = mem_job(&mut right_mem);
+
+            let len = n as usize;
+            let zero = $t::new(0.0, 0.0);
+            let mut info: c_int = 0;
+            let mut alpha: Vec<$t> = vec![zero; len];
+            let mut beta: Vec<$t> = vec![zero; len];
+            let mut work: Vec<$t> = vec![zero; 2 * len + 1];
+            let mut rwork: Vec<$r> = vec![0.0; 8 * len];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, ggev_)(jobvl.as_mut(), jobvr.as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    alpha.as_mut_ptr(), beta.as_mut_ptr(),
+                    vl, ldvl.as_mut(), vr, ldvr.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(complex_values(alpha, beta)),
+                x => Err(ggev_error(x)),
+            }
+        }
+    }
+
+    impl Ggevx<$r> for $t {
+        fn ggevx(balance: Balance, sense: Sense, a: &mut Matrix<Self>, b: &mut Matrix<Self>,
+            left: Option<&mut Matrix<Self>>, right: Option<&mut Matrix<Self>>)
+            -> Result<GeneralizedExpert<$r>, Error> {
+
+            let n = try!(check_square_pair(a, b));
+            let mut left_mem = try!(square_mem(n, left));
+            let mut right_mem = try!(square_mem(n, right));
+            let (jobvl, vl, ldvl) = mem_job(&mut left_mem);
+            let (jobvr, vr, ldvr) = mem_job(&mut right_mem);
+
+            let len = n as usize;
+            let zero = $t::new(0.0, 0.0);
+            let mut info: c_int = 0;
+            let mut ilo: c_int = 0;
+            let mut ihi: c_int = 0;
+            let mut abnrm: $r = 0.0;
+            let mut bbnrm: $r = 0.0;
+            let mut alpha: Vec<$t> = vec![zero; len];
+            let mut beta: Vec<$t> = vec![zero; len];
+            let mut lscale: Vec<$r> = vec![0.0; len];
+            let mut rscale: Vec<$r> = vec![0.0; len];
+            let mut rconde: Vec<$r> = vec![0.0; len];
+            let mut rcondv: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![zero; 2 * len * len + 2 * len + 1];
+            let mut rwork: Vec<$r> = vec![0.0; 6 * len + 1];
+            let mut iwork: Vec<c_int> = vec![0; len + 2];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, ggevx_)(balance.as_i8().as_mut(), jobvl.as_mut(), jobvr.as_mut(),
+                    sense.as_i8().as_mut(),
+                    n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    alpha.as_mut_ptr(), beta.as_mut_ptr(),
+                    vl, ldvl.as_mut(), vr, ldvr.as_mut(),
+                    &mut ilo, &mut ihi,
+                    lscale.as_mut_ptr(), rscale.as_mut_ptr(),
+                    &mut abnrm, &mut bbnrm,
+                    rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    rwork.as_mut_ptr(), iwork.as_mut_ptr(), bwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1, 1);
+            }
+
+            let (e_len, v_len) = sense.condition_lens(len);
+            rconde.truncate(e_len);
+            rcondv.truncate(v_len);
+
+            match info {
+                0 => Ok(GeneralizedExpert {
+                    values: complex_values(alpha, beta),
+                    ilo: ilo,
+                    ihi: ihi,
+                    lscale: lscale,
+                    rscale: rscale,
+                    abnrm: abnrm,
+                    bbnrm: bbnrm,
+                    rconde: rconde,
+                    rcondv: rcondv,
+                }),
+                x => Err(ggev_error(x)),
+            }
+        }
+    }
+)+));
+
+real_ggev_impl!(f32, f64);
+complex_ggev_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod sygv_tests {
+    use num::complex::Complex64;
+    use general_eigenvalues::{Hegv, Sygv, Sygvd, Sygvx};
+    use matrix::tests::{M, S};
+    use types::{Compute, EigenRange, GeneralizedForm, Symmetry};
+    use types::Order::*;
+
+    fn pair(uplo: Symmetry) -> (S<f64>, S<f64>) {
+        (S(uplo, M(ColMajor, 2i32, 2i32, vec![2.0f64, 1.0, 1.0, 2.0])),
+            S(uplo, M(ColMajor, 2i32, 2i32, vec![2.0f64, 0.0, 0.0, 2.0])))
+    }
+
+    fn close(x: &[f64], y: &[f64]) -> bool {
+        x.len() == y.len() && x.iter().zip(y.iter()).all(|(x, y)| (x - y).abs() < 1e-12)
+    }
+
+    #[test]
+    fn forms() {
+        for &(form, ref expected) in &[(GeneralizedForm::Ax, vec![0.5, 1.5]),
+                (GeneralizedForm::ABx, vec![2.0, 6.0]),
+                (GeneralizedForm::BAx, vec![2.0, 6.0])] {
+            let (mut a, mut b) = pair(Symmetry::Upper);
+            let w = Sygv::sygv(form, Compute::None, &mut a, &mut b).unwrap();
+            assert!(close(&w, expected));
+
+            let (mut a, mut b) = pair(Symmetry::Lower);
+            let w = Sygvd::sygvd(form, Compute::Value, &mut a, &mut b).unwrap();
+            assert!(close(&w, expected));
+        }
+    }
+
+    #[test]
+    fn vectors() {
+        let (mut a, mut b) = pair(Symmetry::Upper);
+        Sygv::sygv(GeneralizedForm::Ax, Compute::Value, &mut a, &mut b).unwrap();
+
+        // Eigenvectors are B-orthonormal: each has 2 |x|² = 1.
+        let x = (a.1).3;
+        assert!((2.0 * (x[0] * x[0] + x[1] * x[1]) - 1.0).abs() < 1e-12);
+        assert!((x[0] + x[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn range() {
+        let (mut a, mut b) = pair(Symmetry::Lower);
+        let mut z = M(RowMajor, 2i32, 1i32, vec![0.0f64; 2]);
+        let w = Sygvx::sygvx(GeneralizedForm::Ax, &mut a, &mut b, EigenRange::Indices(2, 2), Some(&mut z)).unwrap();
+
+        assert!(close(&w, &[1.5]));
+        assert!((z.3[0] - z.3[1]).abs() < 1e-12);
+
+        let (mut a, mut b) = pair(Symmetry::Lower);
+        let w = Sygvx::sygvx(GeneralizedForm::Ax, &mut a, &mut b, EigenRange::Values(0.0, 1.0), None).unwrap();
+        assert!(close(&w, &[0.5]));
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let (mut a, _) = pair(Symmetry::Upper);
+        let mut b = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![1.0f64, 2.0, 2.0, 1.0]));
+
+        match Sygv::sygv(GeneralizedForm::Ax, Compute::None, &mut a, &mut b) {
+            Err(::Error::NotPositiveDefinite(2)) => {},
+            x => panic!("unexpected result: {:?}", x),
+        }
+    }
+
+    #[test]
+    fn hermitian() {
+        let c = |re, im| Complex64::new(re, im);
+        let mut a = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![c(2.0, 0.0), c(0.0, -1.0), c(0.0, 1.0), c(2.0, 0.0)]));
+        let mut b = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)]));
+
+        let w = Hegv::hegv(GeneralizedForm::Ax, Compute::None, &mut a, &mut b).unwrap();
+        assert!(close(&w, &[1.0, 3.0]));
+    }
+}
+
+#[cfg(test)]
+mod ggev_tests {
+    use num::complex::Complex;
+    use general_eigenvalues::{Ggev, Ggevx};
+    use matrix::tests::M;
+    use types::{Balance, Sense};
+    use types::Order::*;
+
+    #[test]
+    fn infinite() {
+        let mut a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 2.0]);
+        let mut b = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 0.0]);
+        let lambda = Ggev::ggev(&mut a, &mut b, None, None).unwrap();
+
+        assert_eq!(lambda.iter().filter(|x| x.is_infinite()).count(), 1);
+        let finite: Vec<_> = lambda.iter().filter_map(|x| x.value()).collect();
+        assert_eq!(finite.len(), 1);
+        assert!((finite[0] - Complex::new(1.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_pair() {
+        let mut a = M(RowMajor, 2i32, 2i32, vec![0.0f64, 1.0, -1.0, 0.0]);
+        let mut b = M(RowMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let lambda = Ggev::ggev(&mut a, &mut b, None, None).unwrap();
+
+        let x = lambda[0].value().unwrap();
+        let y = lambda[1].value().unwrap();
+        assert!(x.re.abs() < 1e-12 && (x.im.abs() - 1.0).abs() < 1e-12);
+        assert!((x.conj() - y).norm() < 1e-12);
+    }
+
+    #[test]
+    fn right_vectors() {
+        let data = vec![2.0f64, 1.0, 0.0, 3.0];
+        let mut a = M(RowMajor, 2i32, 2i32, data.clone());
+        let mut b = M(RowMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let mut v = M(ColMajor, 2i32, 2i32, vec![0.0f64; 4]);
+        let lambda = Ggev::ggev(&mut a, &mut b, None, Some(&mut v)).unwrap();
+
+        for j in 0..2 {
+            let l = lambda[j].value().unwrap().re;
+            let (x, y) = (v.3[2 * j], v.3[2 * j + 1]);
+            assert!((data[0] * x + data[1] * y - l * x).abs() < 1e-12);
+            assert!((data[2] * x + data[3] * y - l * y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn expert() {
+        let mut a = M(ColMajor, 2i32, 2i32, vec![2.0f64, 0.0, 1.0, 3.0]);
+        let mut b = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let r = Ggevx::ggevx(Balance::Both, Sense::Eigenvalues, &mut a, &mut b, None, None).unwrap();
+
+        assert_eq!(r.values.len(), 2);
+        assert_eq!(r.rconde.len(), 2);
+        assert!(r.rcondv.is_empty());
+        assert!(r.rconde.iter().all(|&c| c > 0.0));
+        assert!(r.abnrm > 0.0 && r.bbnrm > 0.0);
+    }
+}
@@ -0,0 +1,684 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::cmp;
+use std::ptr;
+use libc::c_int;
+use num::Zero;
+use num::complex::{
+    Complex,
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::Matrix;
+use scalar::Scalar;
+use types::{Balance, SchurJob, Side, VectorUpdate};
+use util::{ColMem, check_square, col_vec, square_mem};
+
+/// The result of balancing a matrix with `Gebal`, with `ilo` and `ihi`
+/// counting from one.
+///
+/// Rows and columns outside `ilo..ihi` were permuted to isolate
+/// eigenvalues, and `scale[j]` there holds the index `j` was swapped with.
+/// Inside, `scale[j]` is the factor row and column `j` were scaled by.
+#[derive(Clone, Debug)]
+pub struct Balancing<Real> {
+    pub ilo: c_int,
+    pub ihi: c_int,
+    pub scale: Vec<Real>,
+}
+
+/// Reduction to upper Hessenberg form `A = Q H Qᴴ` by `Gehrd`.
+#[derive(Clone, Debug)]
+pub struct Hessenberg<T> {
+    n: c_int,
+    ilo: c_int,
+    ihi: c_int,
+    factors: Vec<T>,
+    tau: Vec<T>,
+}
+
+impl<T> Hessenberg<T> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn ilo(&self) -> c_int { self.ilo }
+    pub fn ihi(&self) -> c_int { self.ihi }
+
+    /// The raw output of `gehrd`, column major: `H` on and above the first
+    /// subdiagonal and the reflectors forming `Q` below it.
+    pub fn factors(&self) -> &[T] { &self.factors[..] }
+    pub fn tau(&self) -> &[T] { &self.tau[..] }
+}
+
+impl<T: Copy + Zero> Hessenberg<T> {
+    /// `H`, column major.
+    pub fn h(&self) -> Vec<T> {
+        let n = self.n as usize;
+        let mut h = self.factors.clone();
+        for j in 0..n {
+            for i in (j + 2)..n {
+                h[i + j * n] = T::zero();
+            }
+        }
+        h
+    }
+}
+
+/// Eigenvectors from `Hsein` or `Trevc`, column major with `columns`
+/// columns each. For real matrices a complex pair takes two columns, the
+/// real and imaginary parts of the vector for the eigenvalue with positive
+/// imaginary part.
+#[derive(Clone, Debug)]
+pub struct EigenvectorSet<T> {
+    pub columns: usize,
+    pub left: Option<Vec<T>>,
+    pub right: Option<Vec<T>>,
+}
+
+/// Balances a general matrix in place, permuting and/or scaling it to
+/// improve the accuracy of its eigenvalues.
+pub trait Gebal<Real>: Sized {
+    fn gebal(job: Balance, a: &mut Matrix<Self>) -> Result<Balancing<Real>, Error>;
+}
+
+/// Transforms the eigenvectors `v` of a balanced matrix back into those of
+/// the original. `job` must match the one given to `Gebal`, and `side` be
+/// `Side::Left` or `Side::Right`.
+pub trait Gebak<Real>: Sized {
+    fn gebak(job: Balance, side: Side, balancing: &Balancing<Real>, v: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Reduces rows and columns `ilo..ihi` of a general matrix, counting from
+/// one, to upper Hessenberg form. Pass `1` and `n` unless the matrix was
+/// balanced. `a` is not changed.
+pub trait Gehrd: Sized {
+    fn gehrd(a: &Matrix<Self>, ilo: c_int, ihi: c_int) -> Result<Hessenberg<Self>, Error>;
+}
+
+/// The orthogonal `Q` of a Hessenberg reduction, column major.
+pub trait Orghr: Sized {
+    fn orghr(h: &Hessenberg<Self>) -> Result<Vec<Self>, Error>;
+}
+
+/// The unitary `Q` of a Hessenberg reduction, column major.
+pub trait Unghr: Sized {
+    fn unghr(h: &Hessenberg<Self>) -> Result<Vec<Self>, Error>;
+}
+
+/// The eigenvalues of an upper Hessenberg matrix `h` by the QR iteration,
+/// and optionally its Schur form, which overwrites `h`, and Schur vectors
+/// in `z`. Rows and columns outside `ilo..ihi` must already be triangular,
+/// as after `Gebal`.
+pub trait Hseqr<Real>: Sized {
+    fn hseqr(job: SchurJob, compz: VectorUpdate, ilo: c_int, ihi: c_int,
+        h: &mut Matrix<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Complex<Real>>, Error>;
+}
+
+/// Eigenvectors of an upper Hessenberg matrix for the eigenvalues marked in
+/// `select`, by inverse iteration. `values` are all its eigenvalues, such as
+/// from `Hseqr`. For real matrices selecting either of a complex pair gives
+/// the vector of the one with positive imaginary part.
+pub trait Hsein<Real>: Sized {
+    fn hsein(side: Side, h: &Matrix<Self>, values: &[Complex<Real>], select: &[bool])
+        -> Result<EigenvectorSet<Self>, Error>;
+}
+
+/// Eigenvectors of an upper (quasi-)triangular matrix `t`, such as from
+/// `Hseqr`, for the eigenvalues marked in `select` or all of them. Given
+/// the Schur vectors `q` instead of a selection, the eigenvectors are
+/// transformed into those of `Q T Qᴴ`.
+pub trait Trevc<Real>: Sized {
+    fn trevc(side: Side, t: &Matrix<Self>, select: Option<&[bool]>, q: Option<&Matrix<Self>>)
+        -> Result<EigenvectorSet<Self>, Error>;
+}
+
+fn sides(side: Side) -> (bool, bool) {
+    match side {
+        Side::Left => (true, false),
+        Side::Right => (false, true),
+        Side::Both => (true, true),
+    }
+}
+
+fn vector_set<T>(side: Side, columns: c_int, n: usize, mut vl: Vec<T>, mut vr: Vec<T>) -> EigenvectorSet<T> {
+    let (left, right) = sides(side);
+    vl.truncate(n * columns as usize);
+    vr.truncate(n * columns as usize);
+
+    EigenvectorSet {
+        columns: columns as usize,
+        left: if left { Some(vl) } else { None },
+        right: if right { Some(vr) } else { None },
+    }
+}
+
+// The `howmny` flag and logical selection for `trevc`, and the eigenvector
+// storage to start from.
+fn trevc_job<T: Copy + Zero>(side: Side, n: usize, select: Option<&[bool]>, q: Option<&Matrix<T>>)
+    -> Result<(i8, Vec<c_int>, Vec<T>, Vec<T>), Error> {
+
+    let (left, right) = sides(side);
+    let vectors = |q: Option<&Matrix<T>>, wanted: bool| match (q, wanted) {
+        (Some(q), true) => col_vec(q),
+        (None, true) => vec![T::zero(); n * n],
+        (_, false) => Vec::new(),
+    };
+
+    match (select, q) {
+        (Some(_), Some(_)) => Err(Error::IllegalParameter(2)),
+        (Some(select), None) => {
+            if select.len() != n {
+                return Err(Error::DimensionMismatch);
+            }
+            let select = select.iter().map(|&x| x as c_int).collect();
+            Ok((b'S' as i8, select, vectors(None, left), vectors(None, right)))
+        },
+        (None, Some(q)) => {
+            if q.rows() as usize != n || q.cols() as usize != n {
+                return Err(Error::DimensionMismatch);
+            }
+            Ok((b'B' as i8, vec![0; n], vectors(Some(q), left), vectors(Some(q), right)))
+        },
+        (None, None) => Ok((b'A' as i8, vec![0; n], vectors(None, left), vectors(None, right))),
+    }
+}
+
+macro_rules! hessenberg_impl(($($t: ident: $r: ident, $zero: expr, $ghr: ident, $ghr_fn: ident, $ghr_ll: ident), +) => ($(
+    impl Gebal<$r> for $t {
+        fn gebal(job: Balance, a: &mut Matrix<Self>) -> Result<Balancing<$r>, Error> {
+            let n = try!(check_square(a));
+            let mut a_mem = ColMem::new(a.order(), a);
+
+            let mut info: c_int = 0;
+            let mut ilo: c_int = 0;
+            let mut ihi: c_int = 0;
+            let mut scale: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, gebal_)(job.as_i8().as_mut(), n.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    &mut ilo, &mut ihi, scale.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(Balancing { ilo: ilo, ihi: ihi, scale: scale }),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Gebak<$r> for $t {
+        fn gebak(job: Balance, side: Side, balancing: &Balancing<$r>, v: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = v.rows();
+            let m = v.cols();
+            if balancing.scale.len() != n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+            if side == Side::Both {
+                return Err(Error::IllegalParameter(2));
+            }
+
+            let mut v_mem = ColMem::new(v.order(), v);
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, gebak_)(job.as_i8().as_mut(), side.as_i8().as_mut(),
+                    n.as_mut(), balancing.ilo.as_mut(), balancing.ihi.as_mut(),
+                    balancing.scale.as_ptr() as *mut _,
+                    m.as_mut(), v_mem.as_mut_ptr(), v_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Gehrd for $t {
+        fn gehrd(a: &Matrix<Self>, ilo: c_int, ihi: c_int) -> Result<Hessenberg<Self>, Error> {
+            let n = try!(check_square(a));
+            let lda = cmp::max(n, 1);
+            let mut factors = col_vec(a);
+            let mut tau: Vec<$t> = vec![$zero; cmp::max(n - 1, 0) as usize];
+
+            let mut info: c_int = 0;
+            let mut len: $t = $zero;
+
+            unsafe {
+                prefix!($t, gehrd_)(n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    factors.as_mut_ptr(), lda.as_mut(), tau.as_mut_ptr(),
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            let mut work: Vec<$t> = vec![$zero; cmp::max(len.as_work(), 1)];
+
+            unsafe {
+                prefix!($t, gehrd_)(n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    factors.as_mut_ptr(), lda.as_mut(), tau.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(Hessenberg {
+                    n: n,
+                    ilo: ilo,
+                    ihi: ihi,
+                    factors: factors,
+                    tau: tau,
+                }),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl $ghr for $t {
+        fn $ghr_fn(h: &Hessenberg<Self>) -> Result<Vec<Self>, Error> {
+            let n = h.n;
+            let lda = cmp::max(n, 1);
+            let mut q = h.factors.clone();
+
+            let mut info: c_int = 0;
+            let mut len: $t = $zero;
+
+            unsafe {
+                prefix!($t, $ghr_ll)(n.as_mut(), h.ilo.as_mut(), h.ihi.as_mut(),
+                    q.as_mut_ptr(), lda.as_mut(), h.tau.as_ptr() as *mut _,
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            let mut work: Vec<$t> = vec![$zero; cmp::max(len.as_work(), 1)];
+
+            unsafe {
+                prefix!($t, $ghr_ll)(n.as_mut(), h.ilo.as_mut(), h.ihi.as_mut(),
+                    q.as_mut_ptr(), lda.as_mut(), h.tau.as_ptr() as *mut _,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(q),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+// `hsein` needs `(n + 2) n` work, `trevc` `3 n`.
+macro_rules! real_hessenberg_impl(($($t: ident), +) => ($(
+    impl Hseqr<$t> for $t {
+        fn hseqr(job: SchurJob, compz: VectorUpdate, ilo: c_int, ihi: c_int,
+            h: &mut Matrix<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Complex<$t>>, Error> {
+
+            let n = try!(check_square(h));
+            let mut z_mem = try!(square_mem(n, z));
+            let (z_ptr, ldz) = match (compz, z_mem.as_mut()) {
+                (VectorUpdate::None, _) => (ptr::null_mut(), 1),
+                (_, Some(z)) => (z.as_mut_ptr(), z.lead()),
+                (_, None) => return Err(Error::IllegalParameter(2)),
+            };
+            let mut h_mem = ColMem::new(h.order(), h);
+
+            let mut info: c_int = 0;
+            let mut len: $t = 0.0;
+            let mut wr: Vec<$t> = vec![0.0; n as usize];
+            let mut wi: Vec<$t> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, hseqr_)(job.as_i8().as_mut(), compz.as_i8().as_mut(),
+                    n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    h_mem.as_mut_ptr(), h_mem.lead().as_mut(),
+                    wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            let mut work: Vec<$t> = vec![0.0; cmp::max(len.as_work(), cmp::max(n, 1) as usize)];
+
+            unsafe {
+                prefix!($t, hseqr_)(job.as_i8().as_mut(), compz.as_i8().as_mut(),
+                    n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    h_mem.as_mut_ptr(), h_mem.lead().as_mut(),
+                    wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(wr.into_iter().zip(wi.into_iter()).map(|(r, i)| Complex::new(r, i)).collect()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+
+    impl Hsein<$t> for $t {
+        fn hsein(side: Side, h: &Matrix<Self>, values: &[Complex<$t>], select: &[bool])
+            -> Result<EigenvectorSet<Self>, Error> {
+
+            let n = try!(check_square(h));
+            let len = n as usize;
+            if values.len() != len || select.len() != len {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let (left, right) = sides(side);
+            let ld = cmp::max(n, 1);
+            let mut h = col_vec(h);
+            let mut select: Vec<c_int> = select.iter().map(|&x| x as c_int).collect();
+            let mut wr: Vec<$t> = values.iter().map(|x| x.re).collect();
+            let mut wi: Vec<$t> = values.iter().map(|x| x.im).collect();
+            let mut vl: Vec<$t> = vec![0.0; if left { len * len } else { 0 }];
+            let mut vr: Vec<$t> = vec![0.0; if right { len * len } else { 0 }];
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut work: Vec<$t> = vec![0.0; (len + 2) * len];
+            let mut ifaill: Vec<c_int> = vec![0; len];
+            let mut ifailr: Vec<c_int> = vec![0; len];
+
+            unsafe {
+                prefix!($t, hsein_)(side.as_i8().as_mut(), (b'N' as i8).as_mut(), (b'N' as i8).as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    h.as_mut_ptr(), ld.as_mut(),
+                    wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(), ifaill.as_mut_ptr(), ifailr.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            match info {
+                0 => Ok(vector_set(side, m, len, vl, vr)),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+
+    impl Trevc<$t> for $t {
+        fn trevc(side: Side, t: &Matrix<Self>, select: Option<&[bool]>, q: Option<&Matrix<Self>>)
+            -> Result<EigenvectorSet<Self>, Error> {
+
+            let n = try!(check_square(t));
+            let len = n as usize;
+            let (howmny, mut select, mut vl, mut vr) = try!(trevc_job(side, len, select, q));
+            let ld = cmp::max(n, 1);
+            let mut t = col_vec(t);
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut work: Vec<$t> = vec![0.0; 3 * len];
+
+            unsafe {
+                prefix!($t, trevc_)(side.as_i8().as_mut(), howmny.as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t.as_mut_ptr(), ld.as_mut(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(vector_set(side, m, len, vl, vr)),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+// `hsein` needs `n²` work and `n` rwork, `trevc` `2 n` and `n`.
+macro_rules! complex_hessenberg_impl(($($t: ident: $r: ident), +) => ($(
+    impl Hseqr<$r> for $t {
+        fn hseqr(job: SchurJob, compz: VectorUpdate, ilo: c_int, ihi: c_int,
+            h: &mut Matrix<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Complex<$r>>, Error> {
+
+            let n = try!(check_square(h));
+            let zero = $t::new(0.0, 0.0);
+            let mut z_mem = try!(square_mem(n, z));
+            let (z_ptr, ldz) = match (compz, z_mem.as_mut()) {
+                (VectorUpdate::None, _) => (ptr::null_mut(), 1),
+                (_, Some(z)) => (z.as_mut_ptr(), z.lead()),
+                (_, None) => return Err(Error::IllegalParameter(2)),
+            };
+            let mut h_mem = ColMem::new(h.order(), h);
+
+            let mut info: c_int = 0;
+            let mut len = zero;
+            let mut w: Vec<$t> = vec![zero; n as usize];
+
+            unsafe {
+                prefix!($t, hseqr_)(job.as_i8().as_mut(), compz.as_i8().as_mut(),
+                    n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    h_mem.as_mut_ptr(), h_mem.lead().as_mut(),
+                    w.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            let mut work: Vec<$t> = vec![zero; cmp::max(len.as_work(), cmp::max(n, 1) as usize)];
+
+            unsafe {
+                prefix!($t, hseqr_)(job.as_i8().as_mut(), compz.as_i8().as_mut(),
+                    n.as_mut(), ilo.as_mut(), ihi.as_mut(),
+                    h_mem.as_mut_ptr(), h_mem.lead().as_mut(),
+                    w.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(w),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+
+    impl Hsein<$r> for $t {
+        fn hsein(side: Side, h: &Matrix<Self>, values: &[Complex<$r>], select: &[bool])
+            -> Result<EigenvectorSet<Self>, Error> {
+
+            let n = try!(check_square(h));
+            let len = n as usize;
+            if values.len() != len || select.len() != len {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let zero = $t::new(0.0, 0.0);
+            let (left, right) = sides(side);
+            let ld = cmp::max(n, 1);
+            let mut h = col_vec(h);
+            let mut select: Vec<c_int> = select.iter().map(|&x| x as c_int).collect();
+            let mut w = values.to_vec();
+            let mut vl: Vec<$t> = vec![zero; if left { len * len } else { 0 }];
+            let mut vr: Vec<$t> = vec![zero; if right { len * len } else { 0 }];
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut work: Vec<$t> = vec![zero; len * len];
+            let mut rwork: Vec<$r> = vec![0.0; len];
+            let mut ifaill: Vec<c_int> = vec![0; len];
+            let mut ifailr: Vec<c_int> = vec![0; len];
+
+            unsafe {
+                prefix!($t, hsein_)(side.as_i8().as_mut(), (b'N' as i8).as_mut(), (b'N' as i8).as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    h.as_mut_ptr(), ld.as_mut(),
+                    w.as_mut_ptr(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    ifaill.as_mut_ptr(), ifailr.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            match info {
+                0 => Ok(vector_set(side, m, len, vl, vr)),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+
+    impl Trevc<$r> for $t {
+        fn trevc(side: Side, t: &Matrix<Self>, select: Option<&[bool]>, q: Option<&Matrix<Self>>)
+            -> Result<EigenvectorSet<Self>, Error> {
+
+            let n = try!(check_square(t));
+            let len = n as usize;
+            let (howmny, mut select, mut vl, mut vr) = try!(trevc_job(side, len, select, q));
+            let ld = cmp::max(n, 1);
+            let mut t = col_vec(t);
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * len];
+            let mut rwork: Vec<$r> = vec![0.0; len];
+
+            unsafe {
+                prefix!($t, trevc_)(side.as_i8().as_mut(), howmny.as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t.as_mut_ptr(), ld.as_mut(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(vector_set(side, m, len, vl, vr)),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+hessenberg_impl!(
+    f32: f32, 0.0, Orghr, orghr, orghr_,
+    f64: f64, 0.0, Orghr, orghr, orghr_,
+    Complex32: f32, Complex32::new(0.0, 0.0), Unghr, unghr, unghr_,
+    Complex64: f64, Complex64::new(0.0, 0.0), Unghr, unghr, unghr_
+);
+real_hessenberg_impl!(f32, f64);
+complex_hessenberg_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod hessenberg_tests {
+    use num::complex::{Complex, Complex64};
+    use hessenberg::{Gebak, Gebal, Gehrd, Hsein, Hseqr, Orghr, Trevc};
+    use matrix::tests::M;
+    use types::{Balance, SchurJob, Side, VectorUpdate};
+    use types::Order::*;
+    use util::{adjoint, multiply};
+
+    fn symmetric() -> Vec<f64> {
+        vec![
+            4.0, 1.0, 0.0, 1.0,
+            1.0, 3.0, 1.0, 0.0,
+            0.0, 1.0, 2.0, 1.0,
+            1.0, 0.0, 1.0, 1.0,
+        ]
+    }
+
+    #[test]
+    fn balance() {
+        // Lower triangular, so permutations alone isolate every eigenvalue.
+        let mut a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 2.0, 0.0, 3.0]);
+        let b = Gebal::gebal(Balance::Permute, &mut a).unwrap();
+        assert_eq!(b.ilo, b.ihi);
+
+        let mut a = M(RowMajor, 2i32, 2i32, vec![1.0f64, 1e4, 1e-4, 1.0]);
+        let b = Gebal::gebal(Balance::Scale, &mut a).unwrap();
+        assert_eq!((b.ilo, b.ihi), (1, 2));
+        assert!(a.3[1] / a.3[2] < 10.0 && a.3[2] / a.3[1] < 10.0);
+
+        // Back transforming the identity gives the scaling.
+        let mut v = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        Gebak::gebak(Balance::Scale, Side::Right, &b, &mut v).unwrap();
+        assert_eq!(v.3[0], b.scale[0]);
+        assert_eq!(v.3[3], b.scale[1]);
+    }
+
+    #[test]
+    fn pipeline() {
+        let a = symmetric();
+        let hess = Gehrd::gehrd(&M(ColMajor, 4i32, 4i32, a.clone()), 1, 4).unwrap();
+        let h = hess.h();
+        let q = Orghr::orghr(&hess).unwrap();
+
+        let qhq = multiply(4, 4, 4, &q, &multiply(4, 4, 4, &h, &adjoint(4, 4, &q, |x| x)));
+        for k in 0..16 {
+            assert!((qhq[k] - a[k]).abs() < 1e-12);
+        }
+
+        let mut t = M(ColMajor, 4i32, 4i32, h);
+        let mut z = M(ColMajor, 4i32, 4i32, q);
+        let values = Hseqr::hseqr(SchurJob::Schur, VectorUpdate::Update, 1, 4, &mut t, Some(&mut z)).unwrap();
+        assert!(values.iter().all(|l| l.im == 0.0));
+
+        let v = Trevc::trevc(Side::Right, &t, None, Some(&z)).unwrap();
+        assert_eq!(v.columns, 4);
+        assert!(v.left.is_none());
+
+        let v = v.right.unwrap();
+        let av = multiply(4, 4, 4, &a, &v);
+        for j in 0..4 {
+            for i in 0..4 {
+                assert!((av[i + j * 4] - values[j].re * v[i + j * 4]).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_iteration() {
+        let hess = Gehrd::gehrd(&M(ColMajor, 4i32, 4i32, symmetric()), 1, 4).unwrap();
+        let h = hess.h();
+        let mut t = M(ColMajor, 4i32, 4i32, h.clone());
+        let values = Hseqr::hseqr(SchurJob::Eigenvalues, VectorUpdate::None, 1, 4, &mut t, None).unwrap();
+
+        let v = Hsein::hsein(Side::Right, &M(ColMajor, 4i32, 4i32, h.clone()), &values, &[false, true, false, false])
+            .unwrap();
+        assert_eq!(v.columns, 1);
+
+        let v = v.right.unwrap();
+        let hv: Vec<f64> = (0..4).map(|i| (0..4).map(|k| h[i + k * 4] * v[k]).sum()).collect();
+        for i in 0..4 {
+            assert!((hv[i] - values[1].re * v[i]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn complex_triangular() {
+        let c = |re, im| Complex64::new(re, im);
+        let t = vec![c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0), c(0.0, 2.0)];
+        let v = Trevc::trevc(Side::Both, &M(ColMajor, 2i32, 2i32, t.clone()), Some(&[false, true]), None).unwrap();
+        assert_eq!(v.columns, 1);
+
+        // T v = 2i v, and uᴴ T = 2i uᴴ.
+        let (l, r) = (v.left.unwrap(), v.right.unwrap());
+        let lambda: Complex<f64> = c(0.0, 2.0);
+        for i in 0..2 {
+            let tr = (0..2).fold(c(0.0, 0.0), |s, k| s + t[i + k * 2] * r[k]);
+            assert!((tr - lambda * r[i]).norm() < 1e-12);
+
+            let lt = (0..2).fold(c(0.0, 0.0), |s, k| s + l[k].conj() * t[k + i * 2]);
+            assert!((lt - lambda * l[i].conj()).norm() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,485 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+};
+use refinement::{Herfs, Refinement, Syrfs};
+use scalar::Scalar;
+use types::Symmetry;
+use util::{ColMem, ColSlice, ColSymmetric, col_vec};
+
+/// Counts of positive, negative and zero eigenvalues.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Inertia {
+    pub positive: usize,
+    pub negative: usize,
+    pub zero: usize,
+}
+
+/// Bunch-Kaufman factorization `A = U D Uᵀ` or `A = L D Lᵀ` (`ᴴ` for a
+/// Hermitian matrix), where `D` is block diagonal with 1 × 1 and 2 × 2
+/// blocks.
+///
+/// A singular `D` still gives a complete factorization, so its inertia can
+/// be read, but `solve` and `inverse` report it as `DiagonalElementZero`.
+#[derive(Clone, Debug)]
+pub struct LDLT<T> {
+    n: c_int,
+    uplo: Symmetry,
+    hermitian: bool,
+    factors: Vec<T>,
+    ipiv: Vec<c_int>,
+    singular: Option<usize>,
+}
+
+impl<T> LDLT<T> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+
+    /// `D` and the multipliers, column major, in the `symmetry()` triangle.
+    pub fn factors(&self) -> &[T] { &self.factors[..] }
+    pub fn pivots(&self) -> &[c_int] { &self.ipiv[..] }
+
+    /// The first zero diagonal element of `D`, counting from one.
+    pub fn singular(&self) -> Option<usize> { self.singular }
+
+    /// Whether the factorization is Hermitian, which is the case for every
+    /// real one and for complex ones from `hetrf`.
+    pub fn is_hermitian(&self) -> bool { self.hermitian }
+
+    fn check_singular(&self) -> Result<(), Error> {
+        match self.singular {
+            Some(i) => Err(Error::DiagonalElementZero(i)),
+            None => Ok(()),
+        }
+    }
+
+    /// The blocks of `D` as `(a, b, c)` for `[a b; b c]`, or `(a, None)` for
+    /// a 1 × 1 block.
+    fn blocks(&self) -> Vec<(usize, Option<usize>)> {
+        let n = self.n as usize;
+        let mut blocks = Vec::new();
+
+        match self.uplo {
+            Symmetry::Upper => {
+                let mut k = n;
+                while k > 0 {
+                    if self.ipiv[k - 1] > 0 || k == 1 {
+                        blocks.push((k - 1, None));
+                        k -= 1;
+                    } else {
+                        blocks.push((k - 2, Some(k - 1)));
+                        k -= 2;
+                    }
+                }
+            },
+            Symmetry::Lower => {
+                let mut k = 0;
+                while k < n {
+                    if self.ipiv[k] > 0 || k == n - 1 {
+                        blocks.push((k, None));
+                        k += 1;
+                    } else {
+                        blocks.push((k, Some(k + 1)));
+                        k += 2;
+                    }
+                }
+            },
+        }
+
+        blocks
+    }
+}
+
+/// Bunch-Kaufman factorization of a symmetric matrix. `a` is not changed.
+pub trait Sytrf: Sized {
+    fn sytrf(a: &SymmetricMatrix<Self>) -> Result<LDLT<Self>, Error>;
+}
+
+/// Bunch-Kaufman factorization of a complex Hermitian matrix. `a` is not
+/// changed.
+pub trait Hetrf: Sized {
+    fn hetrf(a: &SymmetricMatrix<Self>) -> Result<LDLT<Self>, Error>;
+}
+
+macro_rules! factor(($t: ident, $trf: expr, $a: ident, $hermitian: expr, $zero: expr) => ({
+    let n = $a.rows();
+    if n != $a.cols() {
+        return Err(Error::DimensionMismatch);
+    }
+
+    let uplo = $a.symmetry();
+    let lda = if n > 0 { n } else { 1 };
+    let mut factors = col_vec($a);
+    let mut ipiv: Vec<c_int> = vec![0; n as usize];
+    let mut info: c_int = 0;
+    let mut work_len: $t = $zero;
+
+    unsafe {
+        $trf(uplo.as_i8().as_mut(), n.as_mut(),
+            factors.as_mut_ptr(), lda.as_mut(),
+            ipiv.as_mut_ptr(),
+            &mut work_len, (-1 as c_int).as_mut(),
+            &mut info as *mut c_int, 1);
+    }
+
+    if info < 0 {
+        return Err(Error::IllegalParameter(-info as usize));
+    }
+
+    let lwork = if work_len.as_work() > 0 { work_len.as_work() } else { 1 };
+    let mut work: Vec<$t> = vec![$zero; lwork];
+
+    unsafe {
+        $trf(uplo.as_i8().as_mut(), n.as_mut(),
+            factors.as_mut_ptr(), lda.as_mut(),
+            ipiv.as_mut_ptr(),
+            work.as_mut_ptr(), (lwork as c_int).as_mut(),
+            &mut info as *mut c_int, 1);
+    }
+
+    match info {
+        x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        x => Ok(LDLT {
+            n: n,
+            uplo: uplo,
+            hermitian: $hermitian,
+            factors: factors,
+            ipiv: ipiv,
+            singular: if x > 0 { Some(x as usize) } else { None },
+        }),
+    }
+}));
+
+macro_rules! ldlt_impl(($($t: ident: $r: ident, $zero: expr, $work: expr,
+    $sytrs: ident, $hetrs: ident, $sytri: ident, $hetri: ident, $sycon: ident, $hecon: ident,
+    [$($iwork: ident)*], $re: expr, $abs2: expr, $conj: expr, $real: expr), +) => ($(
+
+    impl Sytrf for $t {
+        fn sytrf(a: &SymmetricMatrix<Self>) -> Result<LDLT<Self>, Error> {
+            factor!($t, prefix!($t, sytrf_), a, $real, $zero)
+        }
+    }
+
+    impl LDLT<$t> {
+        /// Solves `A X = B`, overwriting `b` with `X`.
+        pub fn solve(&self, b: &mut Matrix<$t>) -> Result<(), Error> {
+            try!(self.check_singular());
+
+            let n = self.n;
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let nrhs = b.cols();
+            let lda = if n > 0 { n } else { 1 };
+            let mut b_mem = ColMem::new(b.order(), b);
+            let trs = if self.hermitian { $hetrs } else { $sytrs };
+
+            unsafe {
+                trs(self.uplo.as_i8().as_mut(), n.as_mut(), nrhs.as_mut(),
+                    self.factors.as_ptr() as *mut _, lda.as_mut(),
+                    self.ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+
+        /// `A⁻¹` as a dense column major vector.
+        pub fn inverse(&self) -> Result<Vec<$t>, Error> {
+            try!(self.check_singular());
+
+            let n = self.n;
+            let lda = if n > 0 { n } else { 1 };
+            let mut info: c_int = 0;
+            let mut inv = self.factors.clone();
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let tri = if self.hermitian { $hetri } else { $sytri };
+
+            unsafe {
+                tri(self.uplo.as_i8().as_mut(), n.as_mut(),
+                    inv.as_mut_ptr(), lda.as_mut(),
+                    self.ipiv.as_ptr() as *mut _,
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => {},
+                x if x < 0 => return Err(Error::IllegalParameter(-x as usize)),
+                x => return Err(Error::DiagonalElementZero(x as usize)),
+            }
+
+            // Fill in the triangle LAPACK leaves untouched.
+            let n = n as usize;
+            let conj = $conj;
+            for j in 0..n {
+                for i in (j + 1)..n {
+                    match self.uplo {
+                        Symmetry::Upper => {
+                            let x = inv[j + i * n];
+                            inv[i + j * n] = if self.hermitian { conj(x) } else { x };
+                        },
+                        Symmetry::Lower => {
+                            let x = inv[i + j * n];
+                            inv[j + i * n] = if self.hermitian { conj(x) } else { x };
+                        },
+                    }
+                }
+            }
+
+            Ok(inv)
+        }
+
+        /// Reciprocal condition number in the 1-norm, given the 1-norm of
+        /// the original matrix. A singular matrix gives zero.
+        pub fn rcond(&self, anorm: $r) -> Result<$r, Error> {
+            let n = self.n;
+            let lda = if n > 0 { n } else { 1 };
+            let mut info: c_int = 0;
+            let mut rcond: $r = 0.0;
+            let mut work: Vec<$t> = vec![$zero; 2 * n as usize];
+            $(let mut $iwork: Vec<c_int> = vec![0; n as usize];)*
+            let con = if self.hermitian { $hecon } else { $sycon };
+
+            unsafe {
+                con(self.uplo.as_i8().as_mut(), n.as_mut(),
+                    self.factors.as_ptr() as *mut _, lda.as_mut(),
+                    self.ipiv.as_ptr() as *mut _,
+                    anorm.as_mut(), &mut rcond,
+                    work.as_mut_ptr(), $($iwork.as_mut_ptr(),)*
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(rcond),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+
+        /// The inertia of `A`, which equals that of `D`. Only defined for
+        /// real symmetric and complex Hermitian matrices; a complex
+        /// symmetric factorization gives `None`.
+        pub fn inertia(&self) -> Option<Inertia> {
+            if !self.hermitian {
+                return None;
+            }
+
+            let n = self.n as usize;
+            let re = $re;
+            let abs2 = $abs2;
+            let mut signs: Vec<$r> = Vec::with_capacity(n);
+
+            // A 2 × 2 block with a negative determinant has one eigenvalue of
+            // each sign; otherwise both share the sign of its trace.
+            for (k, next) in self.blocks() {
+                let a = re(self.factors[k + k * n]);
+                match next {
+                    None => signs.push(a),
+                    Some(l) => {
+                        let b = abs2(match self.uplo {
+                            Symmetry::Upper => self.factors[k + l * n],
+                            Symmetry::Lower => self.factors[l + k * n],
+                        });
+                        let c = re(self.factors[l + l * n]);
+                        let det = a * c - b;
+
+                        if det < 0.0 {
+                            signs.push(1.0);
+                            signs.push(-1.0);
+                        } else if det > 0.0 {
+                            signs.push(a + c);
+                            signs.push(a + c);
+                        } else {
+                            signs.push(0.0);
+                            signs.push(a + c);
+                        }
+                    },
+                }
+            }
+
+            let mut inertia = Inertia { positive: 0, negative: 0, zero: 0 };
+            for x in signs {
+                if x > 0.0 {
+                    inertia.positive += 1;
+                } else if x < 0.0 {
+                    inertia.negative += 1;
+                } else {
+                    inertia.zero += 1;
+                }
+            }
+
+            Some(inertia)
+        }
+    }
+)+));
+
+macro_rules! ldlt_refine_impl(($($t: ident: $r: ident, $herfs: path), +) => ($(
+    impl LDLT<$t> {
+        /// Refines a solution `x` of `A X = B`, where this is the
+        /// factorization of `a`, and bounds its error. `a` must use the same
+        /// triangle as the factorization.
+        pub fn refine(&self, a: &SymmetricMatrix<$t>, b: &mut Matrix<$t>, x: &mut Matrix<$t>)
+            -> Result<Refinement<$r>, Error> {
+
+            try!(self.check_singular());
+
+            let n = self.n;
+            let mut a_data = col_vec(a);
+            let mut factors = self.factors.clone();
+            let a = ColSymmetric(a.symmetry(), ColSlice(a.rows(), a.cols(), &mut a_data[..]));
+            let factor = ColSymmetric(self.uplo, ColSlice(n, n, &mut factors[..]));
+
+            if self.hermitian {
+                $herfs(&a, &factor, &self.ipiv, b, x)
+            } else {
+                Syrfs::syrfs(&a, &factor, &self.ipiv, b, x)
+            }
+        }
+    }
+)+));
+
+macro_rules! hermitian_ldlt_impl(($($t: ident), +) => ($(
+    impl Hetrf for $t {
+        fn hetrf(a: &SymmetricMatrix<Self>) -> Result<LDLT<Self>, Error> {
+            factor!($t, prefix!($t, hetrf_), a, true, $t::new(0.0, 0.0))
+        }
+    }
+)+));
+
+ldlt_impl!(
+    f32: f32, 0.0, 1, ssytrs_, ssytrs_, ssytri_, ssytri_, ssycon_, ssycon_,
+        [iwork], |x: f32| x, |x: f32| x * x, |x: f32| x, true,
+    f64: f64, 0.0, 1, dsytrs_, dsytrs_, dsytri_, dsytri_, dsycon_, dsycon_,
+        [iwork], |x: f64| x, |x: f64| x * x, |x: f64| x, true,
+    Complex32: f32, Complex32::new(0.0, 0.0), 2, csytrs_, chetrs_, csytri_, chetri_, csycon_, checon_,
+        [], |x: Complex32| x.re, |x: Complex32| x.norm_sqr(), |x: Complex32| x.conj(), false,
+    Complex64: f64, Complex64::new(0.0, 0.0), 2, zsytrs_, zhetrs_, zsytri_, zhetri_, zsycon_, zhecon_,
+        [], |x: Complex64| x.re, |x: Complex64| x.norm_sqr(), |x: Complex64| x.conj(), false
+);
+hermitian_ldlt_impl!(Complex32, Complex64);
+ldlt_refine_impl!(
+    f32: f32, Syrfs::syrfs,
+    f64: f64, Syrfs::syrfs,
+    Complex32: f32, Herfs::herfs,
+    Complex64: f64, Herfs::herfs
+);
+
+#[cfg(test)]
+mod ldlt_tests {
+    use num::complex::Complex64;
+    use ldlt::{Hetrf, Inertia, Sytrf};
+    use linear_equations::Sysv;
+    use matrix::tests::{M, S};
+    use types::Order::*;
+    use types::Symmetry;
+
+    fn saddle() -> S<f64> {
+        // Eigenvalues 1 ± √3 and 2.
+        S(Symmetry::Lower, M(RowMajor, 3i32, 3i32, vec![
+            0.0f64, 0.0, 0.0,
+            1.0, 2.0, 0.0,
+            1.0, 0.0, 2.0,
+        ]))
+    }
+
+    #[test]
+    fn inertia() {
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            let S(_, m) = saddle();
+            let data = m.3.clone();
+            // Mirror into a full symmetric matrix so either triangle reads it.
+            let full = vec![data[0], data[3], data[6], data[3], data[4], data[7], data[6], data[7], data[8]];
+            let a = S(uplo, M(ColMajor, 3i32, 3i32, full));
+            let f = Sytrf::sytrf(&a).unwrap();
+
+            assert_eq!(f.inertia(), Some(Inertia { positive: 2, negative: 1, zero: 0 }));
+        }
+    }
+
+    #[test]
+    fn solve_inverse() {
+        let a = saddle();
+        let f = Sytrf::sytrf(&a).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![2.0f64, 3.0, 3.0]);
+
+        f.solve(&mut b).unwrap();
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+
+        let inv = f.inverse().unwrap();
+        // Symmetric, and the (0, 0) entry is det([2 0; 0 2]) / det(A) = 4 / -4.
+        assert!((inv[0] + 1.0).abs() < 1e-12);
+        assert_eq!(inv[1], inv[3]);
+        assert!(f.rcond(3.0).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn refine() {
+        let a = saddle();
+        let f = Sytrf::sytrf(&a).unwrap();
+        let mut b = M(RowMajor, 3i32, 1i32, vec![2.0f64, 3.0, 3.0]);
+        let mut x = M(RowMajor, 3i32, 1i32, vec![1.0f64 + 1e-6, 1.0, 1.0]);
+
+        f.refine(&a, &mut b, &mut x).unwrap();
+        for x in x.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+
+        let other = S(Symmetry::Upper, M(RowMajor, 3i32, 3i32, vec![0.0f64; 9]));
+        assert!(f.refine(&other, &mut b, &mut M(RowMajor, 3i32, 1i32, vec![0.0f64; 3])).is_err());
+    }
+
+    #[test]
+    fn sysv_pivots() {
+        let mut a = saddle();
+        let f = Sytrf::sytrf(&a).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![2.0f64, 3.0, 3.0]);
+
+        let ipiv = Sysv::sysv(&mut a, &mut b).unwrap();
+        assert_eq!(&ipiv[..], f.pivots());
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn singular() {
+        let a = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![1.0f64, 1.0, 1.0, 1.0]));
+        let f = Sytrf::sytrf(&a).unwrap();
+
+        assert!(f.singular().is_some());
+        assert_eq!(f.inertia(), Some(Inertia { positive: 1, negative: 0, zero: 1 }));
+        assert!(f.solve(&mut M(ColMajor, 2i32, 1i32, vec![1.0f64, 1.0])).is_err());
+    }
+
+    #[test]
+    fn hermitian() {
+        let c = |re, im| Complex64::new(re, im);
+        let a = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 0.0), c(0.0, 2.0), c(1.0, 0.0)]));
+
+        // Eigenvalues 1 ± 2.
+        let f = Hetrf::hetrf(&a).unwrap();
+        assert_eq!(f.inertia(), Some(Inertia { positive: 1, negative: 1, zero: 0 }));
+
+        let f = Sytrf::sytrf(&a).unwrap();
+        assert!(!f.is_hermitian());
+        assert_eq!(f.inertia(), None);
+    }
+}
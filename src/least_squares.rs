@@ -52,7 +52,7 @@ macro_rules! least_sq_impl(($($t: ident), +) => ($(
                     a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
                     b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
                     work.as_mut_ptr(), (work.len() as c_int).as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1);
             }
 
             match info {
@@ -81,7 +81,7 @@ macro_rules! least_sq_impl(($($t: ident), +) => ($(
                     a.as_mut_ptr(), lda_t.as_mut(),
                     b.as_mut_ptr(), ldb_t.as_mut(),
                     len_ptr, (-1 as c_int).as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1);
             };
 
             match info {
@@ -13,10 +13,25 @@ pub use error::Error;
 pub use matrix::Matrix;
 pub use vector::Vector;
 pub use types::*;
+pub use band::*;
+pub use cholesky::*;
+pub use condition::*;
+pub use equilibration::*;
 pub use eigenvalues::*;
 pub use general_eigenvalues::*;
+pub use hessenberg::*;
+pub use ldlt::*;
 pub use least_squares::*;
 pub use linear_equations::*;
+pub use machine::*;
+pub use norms::*;
+pub use packed::*;
+pub use refinement::*;
+pub use rfp::*;
+pub use schur::*;
+pub use sylvester::*;
+pub use triangular::*;
+pub use tridiagonal::*;
 
 #[macro_use]
 mod prefix;
@@ -27,7 +42,22 @@ pub mod error;
 pub mod matrix;
 pub mod vector;
 pub mod types;
+pub mod band;
+pub mod cholesky;
+pub mod condition;
+pub mod equilibration;
 pub mod linear_equations;
 pub mod least_squares;
+pub mod ldlt;
 pub mod eigenvalues;
 pub mod general_eigenvalues;
+pub mod hessenberg;
+pub mod machine;
+pub mod norms;
+pub mod packed;
+pub mod refinement;
+pub mod rfp;
+pub mod schur;
+pub mod sylvester;
+pub mod triangular;
+pub mod tridiagonal;
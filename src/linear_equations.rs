@@ -5,62 +5,132 @@ use num::complex::{
     Complex32,
     Complex64,
 };
+use num::Zero;
 use libc::c_int;
+use band::BandStorage;
 use error::Error;
 use ll::*;
 use matrix::{
     Matrix,
     BandMatrix,
     SymmetricMatrix,
-    TridiagonalMatrix,
 };
+use packed::PackedSymmetric;
 use scalar::Scalar;
+use tridiagonal::{SymmetricTridiagonal, Tridiagonal};
 use types::Symmetry;
 use util::ColMem;
 
+/// Why a mixed precision solver fell back to factoring in full precision.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fallback {
+    /// Refinement was not expected to pay off (`iter = -1`).
+    NotWorthwhile,
+    /// The matrix overflowed when converted to single precision.
+    Overflow,
+    /// The single precision factorization failed.
+    SingleFactorization,
+    /// Refinement did not converge.
+    NoConvergence,
+}
+
+/// Outcome of a mixed precision solve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MixedIterations {
+    /// Factored in single precision and refined to full precision with this
+    /// many iterations.
+    Refined(usize),
+    /// Factored and solved in full precision. The matrix now holds the full
+    /// precision factors.
+    FullPrecision(Fallback),
+}
+
+impl MixedIterations {
+    fn from_iter(iter: c_int) -> MixedIterations {
+        match iter {
+            x if x >= 0 => MixedIterations::Refined(x as usize),
+            -2 => MixedIterations::FullPrecision(Fallback::Overflow),
+            -3 => MixedIterations::FullPrecision(Fallback::SingleFactorization),
+            -31 => MixedIterations::FullPrecision(Fallback::NoConvergence),
+            _ => MixedIterations::FullPrecision(Fallback::NotWorthwhile),
+        }
+    }
+}
+
 pub trait Gesv {
     fn gesv(a: &mut Matrix<Self>, b: &mut Matrix<Self>)
         -> Result<Vec<usize>, Error>;
 }
 
-pub trait Gbsv {
-    fn gbsv(a: &mut BandMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>);
+/// Solves `A X = B` by factoring `A` in single precision and refining `X`
+/// in full precision. `B` is not changed; `A` is only overwritten, with its
+/// `LU` factors, if the solver falls back to full precision.
+pub trait MixedGesv: Sized {
+    fn mixed_gesv(a: &mut Matrix<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+        -> Result<MixedIterations, Error>;
 }
 
-pub trait Gtsv {
-    fn gtsv(a: &mut TridiagonalMatrix<Self>, b: &mut Matrix<Self>);
+/// The symmetric (Hermitian) positive definite counterpart of `MixedGesv`.
+pub trait MixedPosv: Sized {
+    fn mixed_posv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+        -> Result<MixedIterations, Error>;
+}
+
+/// Solves a square band system `A X = B`, overwriting `a` with its `LU`
+/// factors and `b` with `X`. Returns the pivot indices.
+pub trait Gbsv: Sized {
+    fn gbsv(a: &mut BandStorage<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error>;
+}
+
+/// Solves a tridiagonal system `A X = B`, overwriting `b` with `X`. `a` is
+/// destroyed.
+pub trait Gtsv: Sized {
+    fn gtsv(a: &mut Tridiagonal<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
 }
 
 pub trait Posv {
     fn posv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>);
 }
 
-pub trait Ppsv {
-    fn ppsv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>);
+/// Solves a packed symmetric (Hermitian) positive definite system
+/// `A X = B`, overwriting `a` with its Cholesky factor and `b` with `X`.
+pub trait Ppsv: Sized {
+    fn ppsv(a: &mut PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
 }
 
 pub trait Pbsv<M>: Sized where M: SymmetricMatrix<Self> + BandMatrix<Self> {
     fn pbsv(a: &mut M, b: &mut Matrix<Self>);
 }
 
-pub trait Ptsv<M>: Sized where M: TridiagonalMatrix<Self> + SymmetricMatrix<Self> {
-    fn ptsv(a: &mut M, b: &mut Matrix<Self>);
+/// Solves a symmetric (Hermitian) positive definite tridiagonal system
+/// `A X = B`, overwriting `a` with its `L D Lᴴ` factorization and `b` with
+/// `X`.
+pub trait Ptsv<Real>: Sized {
+    fn ptsv(a: &mut SymmetricTridiagonal<Self, Real>, b: &mut Matrix<Self>) -> Result<(), Error>;
 }
 
-pub trait Sysv {
-    fn sysv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>);
+/// Solves a symmetric indefinite system `A X = B`, overwriting `a` with its
+/// Bunch-Kaufman factorization and `b` with `X`. Returns the pivot indices;
+/// use `Sytrf` to keep a reusable factorization instead.
+pub trait Sysv: Sized {
+    fn sysv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error>;
 }
 
-pub trait Hesv {
-    fn hesv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>);
+/// The complex Hermitian counterpart of `Sysv`.
+pub trait Hesv: Sized {
+    fn hesv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error>;
 }
 
-pub trait Spsv {
-    fn spsv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>);
+/// Solves a packed symmetric indefinite system `A X = B`, overwriting `a`
+/// with its Bunch-Kaufman factorization and `b` with `X`. Returns the pivot
+/// indices.
+pub trait Spsv: Sized {
+    fn spsv(a: &mut PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error>;
 }
 
-pub trait Hpsv {
-    fn hpsv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>);
+/// The complex Hermitian counterpart of `Spsv`.
+pub trait Hpsv: Sized {
+    fn hpsv(a: &mut PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error>;
 }
 
 macro_rules! lin_eq_impl(($($t: ident), +) => ($(
@@ -76,8 +146,7 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
             }
 
             let nrhs = b.cols();
-            let mut pivot_indices: Vec<usize> = Vec::with_capacity(n as usize);
-            unsafe { pivot_indices.set_len(n as usize); }
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
 
             let mut a_mem = ColMem::new(a.order(), a);
             let mut b_mem = ColMem::new(b.order(), b);
@@ -86,13 +155,13 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
                 prefix!($t, gesv_)(
                     n.as_mut(), nrhs.as_mut(),
                     a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
-                    pivot_indices.as_mut_ptr() as *mut c_int,
+                    pivot_indices.as_mut_ptr(),
                     b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
                     &mut info as *mut c_int);
             }
 
             match info {
-                0 => Ok(pivot_indices),
+                0 => Ok(pivot_indices.into_iter().map(|p| p as usize).collect()),
                 x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
                 x => Err(Error::DiagonalElementZero(x as usize)),
             }
@@ -100,33 +169,62 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
     }
 
     impl Gbsv for $t {
-        fn gbsv(a: &mut BandMatrix<$t>, b: &mut Matrix<$t>, p: &mut Matrix<c_int>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn gbsv(a: &mut BandStorage<$t>, b: &mut Matrix<$t>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
 
-                prefix!($t, gbsv_)(a.cols().as_mut(),
+            let n = a.cols();
+            if n != a.rows() || b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nrhs = b.cols();
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, gbsv_)(n.as_mut(),
                     a.sub_diagonals().as_mut(), a.sup_diagonals().as_mut(),
-                    b.cols().as_mut(),
-                    a.as_mut_ptr(), a.rows().as_mut(),
-                    p.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
+                    nrhs.as_mut(),
+                    a.as_mut_ptr(), a.lead().as_mut(),
+                    pivot_indices.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
                     &mut info as *mut c_int);
             }
+
+            match info {
+                0 => Ok(pivot_indices),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
         }
     }
 
     impl Gtsv for $t {
-        fn gtsv(a: &mut TridiagonalMatrix<Self>, b: &mut Matrix<Self>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn gtsv(a: &mut Tridiagonal<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let mut info: c_int = 0;
 
-                let (sup, diag, sub) = a.as_mut_ptrs();
+            let n = a.dim();
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
 
-                prefix!($t, gtsv_)(a.cols().as_mut(), b.cols().as_mut(),
-                    sup, diag, sub,
-                    b.as_mut_ptr(), b.rows().as_mut(),
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                let (dl, d, du) = a.as_mut_ptrs();
+
+                prefix!($t, gtsv_)(n.as_mut(), nrhs.as_mut(),
+                    dl, d, du,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
                     &mut info as *mut c_int);
             }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
         }
     }
 
@@ -139,21 +237,35 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
                     a.cols().as_mut(), b.cols().as_mut(),
                     a.as_mut_ptr(), a.rows().as_mut(),
                     b.as_mut_ptr(), b.rows().as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1);
             }
         }
     }
 
     impl Ppsv for $t {
-        fn ppsv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn ppsv(a: &mut PackedSymmetric<$t>, b: &mut Matrix<$t>) -> Result<(), Error> {
+            let mut info: c_int = 0;
 
+            let n = a.dim();
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
                 prefix!($t, ppsv_)(a.symmetry().as_i8().as_mut(),
-                    a.cols().as_mut(), b.cols().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
                     a.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
-                    &mut info as *mut c_int);
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
             }
         }
     }
@@ -173,41 +285,73 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
                     b.cols().as_mut(),
                     a.as_mut_ptr(), a.rows().as_mut(),
                     b.as_mut_ptr(), b.rows().as_mut(),
-                    &mut info as *mut c_int);
+                    &mut info as *mut c_int, 1);
             }
         }
     }
 
     impl Sysv for $t {
-        fn sysv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>, p: &mut Matrix<c_int>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn sysv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
 
-                let n = a.cols() as usize;
-                let mut work: Vec<$t> = Vec::with_capacity(n);
+            let n = a.rows();
+            if n != a.cols() || b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
 
-                prefix!($t, sysv_)(a.symmetry().as_i8().as_mut(),
-                    a.cols().as_mut(), b.cols().as_mut(),
-                    a.as_mut_ptr(), a.rows().as_mut(),
-                    p.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
-                    (&mut work[..]).as_mut_ptr(), a.cols().as_mut(),
-                    &mut info as *mut c_int);
+            let nrhs = b.cols();
+            let uplo = a.symmetry();
+            let lwork = if n > 0 { n } else { 1 };
+            let mut work: Vec<$t> = vec![Zero::zero(); lwork as usize];
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, sysv_)(uplo.as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    pivot_indices.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    work.as_mut_ptr(), lwork.as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(pivot_indices),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
             }
         }
     }
 
     impl Spsv for $t {
-        fn spsv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn spsv(a: &mut PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
 
+            let n = a.dim();
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nrhs = b.cols();
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
                 prefix!($t, spsv_)(a.symmetry().as_i8().as_mut(),
-                    a.cols().as_mut(), b.cols().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
                     a.as_mut_ptr(),
-                    p.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
-                    &mut info as *mut c_int);
+                    pivot_indices.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(pivot_indices),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
             }
         }
     }
@@ -215,42 +359,192 @@ macro_rules! lin_eq_impl(($($t: ident), +) => ($(
 
 macro_rules! complex_lin_eq_impl(($($t: ident), +) => ($(
     impl Hesv for $t {
-        fn hesv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>, p: &mut Matrix<c_int>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn hesv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
+
+            let n = a.rows();
+            if n != a.cols() || b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
 
-                let n = a.cols() as usize;
-                let mut work: Vec<$t> = Vec::with_capacity(n);
+            let nrhs = b.cols();
+            let uplo = a.symmetry();
+            let lwork = if n > 0 { n } else { 1 };
+            let mut work: Vec<$t> = vec![Zero::zero(); lwork as usize];
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
 
-                prefix!($t, hesv_)(a.symmetry().as_i8().as_mut(),
-                    a.cols().as_mut(), b.cols().as_mut(),
-                    a.as_mut_ptr(), a.rows().as_mut(),
-                    p.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
-                    (&mut work[..]).as_mut_ptr(), a.cols().as_mut(),
-                    &mut info as *mut c_int);
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, hesv_)(uplo.as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    pivot_indices.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    work.as_mut_ptr(), lwork.as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(pivot_indices),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
             }
         }
     }
 
     impl Hpsv for $t {
-        fn hpsv(a: &mut SymmetricMatrix<Self>, b: &mut Matrix<Self>, p: &mut Matrix<c_int>) {
-            unsafe {
-                let mut info: c_int = 0;
+        fn hpsv(a: &mut PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<Vec<c_int>, Error> {
+            let mut info: c_int = 0;
 
+            let n = a.dim();
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nrhs = b.cols();
+            let mut pivot_indices: Vec<c_int> = vec![0; n as usize];
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
                 prefix!($t, hpsv_)(a.symmetry().as_i8().as_mut(),
-                    a.cols().as_mut(), b.cols().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
                     a.as_mut_ptr(),
-                    p.as_mut_ptr(),
-                    b.as_mut_ptr(), b.rows().as_mut(),
+                    pivot_indices.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(pivot_indices),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! pt_impl(($($t: ident: $r: ident), +) => ($(
+    impl Ptsv<$r> for $t {
+        fn ptsv(a: &mut SymmetricTridiagonal<Self, $r>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let mut info: c_int = 0;
+
+            let n = a.dim();
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                let (d, e) = a.as_mut_ptrs();
+
+                prefix!($t, ptsv_)(n.as_mut(), nrhs.as_mut(),
+                    d, e,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
                     &mut info as *mut c_int);
             }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! mixed_impl(($($t: ident, $gesv: ident, $posv: ident, $zero: expr, $single_zero: expr, [$($rwork: ident)*]), +) => ($(
+    impl MixedGesv for $t {
+        fn mixed_gesv(a: &mut Matrix<$t>, b: &mut Matrix<$t>, x: &mut Matrix<$t>)
+            -> Result<MixedIterations, Error> {
+
+            let n = a.rows();
+            let nrhs = b.cols();
+            if n != a.cols() || b.rows() != n || x.rows() != n || x.cols() != nrhs {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut iter: c_int = 0;
+            let mut ipiv: Vec<c_int> = vec![0; n as usize];
+            let mut work = vec![$zero; (n * nrhs) as usize];
+            let mut swork = vec![$single_zero; (n * (n + nrhs)) as usize];
+            $(let mut $rwork = vec![0.0f64; n as usize];)*
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                $gesv(n.as_mut(), nrhs.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    ipiv.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    work.as_mut_ptr(), swork.as_mut_ptr(),
+                    $($rwork.as_mut_ptr(),)*
+                    &mut iter, &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(MixedIterations::from_iter(iter)),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl MixedPosv for $t {
+        fn mixed_posv(a: &mut SymmetricMatrix<$t>, b: &mut Matrix<$t>, x: &mut Matrix<$t>)
+            -> Result<MixedIterations, Error> {
+
+            let n = a.rows();
+            let nrhs = b.cols();
+            if n != a.cols() || b.rows() != n || x.rows() != n || x.cols() != nrhs {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut iter: c_int = 0;
+            let uplo = a.symmetry();
+            let mut work = vec![$zero; (n * nrhs) as usize];
+            let mut swork = vec![$single_zero; (n * (n + nrhs)) as usize];
+            $(let mut $rwork = vec![0.0f64; n as usize];)*
+
+            let mut a_mem = ColMem::new(a.order(), a);
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                $posv(uplo.as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_mem.as_mut_ptr(), a_mem.lead().as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    work.as_mut_ptr(), swork.as_mut_ptr(),
+                    $($rwork.as_mut_ptr(),)*
+                    &mut iter, &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(MixedIterations::from_iter(iter)),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
+            }
         }
     }
 )+));
 
 lin_eq_impl!(f32, f64, Complex32, Complex64);
 complex_lin_eq_impl!(Complex32, Complex64);
+pt_impl!(f32: f32, f64: f64, Complex32: f32, Complex64: f64);
+mixed_impl!(
+    f64, dsgesv_, dsposv_, 0.0f64, 0.0f32, [],
+    Complex64, zcgesv_, zcposv_, Complex64::new(0.0, 0.0), Complex32::new(0.0, 0.0), [rwork]
+);
 
 #[cfg(test)]
 mod gesv_tests {
@@ -269,3 +563,47 @@ mod gesv_tests {
         assert_eq!(x, vec![3.0f64, -5.0]);
     }
 }
+
+#[cfg(test)]
+mod mixed_tests {
+    use linear_equations::{MixedGesv, MixedIterations, MixedPosv};
+    use matrix::tests::{M, S};
+    use types::Order::*;
+    use types::Symmetry;
+
+    #[test]
+    fn real() {
+        let mut a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 4.0, 1.0, 2.0]);
+        let mut b = M(ColMajor, 2i32, 1i32, vec![-2.0f64, 2.0]);
+        let mut x = M(ColMajor, 2i32, 1i32, vec![0.0f64; 2]);
+
+        let iter = MixedGesv::mixed_gesv(&mut a, &mut b, &mut x).unwrap();
+
+        match iter {
+            MixedIterations::Refined(_) => {},
+            x => panic!("unexpected fallback: {:?}", x),
+        }
+        assert_eq!(b.3, vec![-2.0, 2.0]);
+        assert!((x.3[0] - 3.0).abs() < 1e-12);
+        assert!((x.3[1] + 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn positive_definite() {
+        for &order in &[ColMajor, RowMajor] {
+            // [4 2; 2 5] with junk in the unread lower triangle.
+            let data = match order {
+                ColMajor => vec![4.0f64, -99.0, 2.0, 5.0],
+                RowMajor => vec![4.0f64, 2.0, -99.0, 5.0],
+            };
+            let mut a = S(Symmetry::Upper, M(order, 2i32, 2i32, data));
+            let mut b = M(order, 2i32, 1i32, vec![6.0f64, 7.0]);
+            let mut x = M(order, 2i32, 1i32, vec![0.0f64; 2]);
+
+            MixedPosv::mixed_posv(&mut a, &mut b, &mut x).unwrap();
+
+            assert!((x.3[0] - 1.0).abs() < 1e-12);
+            assert!((x.3[1] - 1.0).abs() < 1e-12);
+        }
+    }
+}
@@ -13,8 +13,23 @@ pub type __CLPK_doublereal = ::libc::c_double;
 #[allow(non_camel_case_types)]
 pub type __CLPK_L_fp =
     ::std::option::Option<extern "C" fn() -> __CLPK_logical>;
+/* gfortran passes hidden CHARACTER lengths as `size_t` and returns REAL
+ * functions as `float`. f2c-translated libraries (CLAPACK, Accelerate) use
+ * `ftnlen`, promote REAL results to `double` and return COMPLEX results
+ * through a leading pointer. Trailing lengths are ignored by f2c builds,
+ * so they are always declared. */
+#[cfg(lapack_abi = "f2c")]
 #[allow(non_camel_case_types)]
 pub type __CLPK_ftnlen = ::libc::c_int;
+#[cfg(not(lapack_abi = "f2c"))]
+#[allow(non_camel_case_types)]
+pub type __CLPK_ftnlen = ::libc::size_t;
+#[cfg(lapack_abi = "f2c")]
+#[allow(non_camel_case_types)]
+pub type __CLPK_real_return = ::libc::c_double;
+#[cfg(not(lapack_abi = "f2c"))]
+#[allow(non_camel_case_types)]
+pub type __CLPK_real_return = ::libc::c_float;
 #[allow(non_camel_case_types)]
 pub type __CLPK_complex = Complex32;
 #[allow(non_camel_case_types)]
@@ -28,7 +43,7 @@ extern "C" {
                    __ldvt: *mut __CLPK_integer, __u: *mut __CLPK_complex,
                    __ldu: *mut __CLPK_integer, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cgbbrd_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
@@ -38,28 +53,26 @@ extern "C" {
                    __pt: *mut __CLPK_complex, __ldpt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen);
     pub fn cgbcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn cgbequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgbequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                     __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                     __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                     __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgbrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
@@ -69,13 +82,12 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cgbsv_(__n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                   __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgbsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -87,64 +99,60 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn cgbtf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgbtrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgbtrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cgebak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_real,
                    __m: *mut __CLPK_integer, __v: *mut __CLPK_complex,
-                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen);
     pub fn cgebal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __scale: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
     pub fn cgebd2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tauq: *mut __CLPK_complex, __taup: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cgebrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tauq: *mut __CLPK_complex, __taup: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgecon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn cgeequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgeequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                     __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                     __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgees_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                   __select: __CLPK_L_fp, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -152,7 +160,8 @@ extern "C" {
                   __vs: *mut __CLPK_complex, __ldvs: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                   __rwork: *mut __CLPK_real, __bwork: *mut __CLPK_logical,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvs_len: __CLPK_ftnlen,
+                  __sort_len: __CLPK_ftnlen);
     pub fn cgeesx_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __select: __CLPK_L_fp, __sense: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
@@ -161,38 +170,40 @@ extern "C" {
                    __ldvs: *mut __CLPK_integer, __rconde: *mut __CLPK_real,
                    __rcondv: *mut __CLPK_real, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                   __jobvs_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn cgeev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_complex,
                   __vl: *mut __CLPK_complex, __ldvl: *mut __CLPK_integer,
                   __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn cgeevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __w: *mut __CLPK_complex, __vl: *mut __CLPK_complex,
-                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_complex,
-                   __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __abnrm: *mut __CLPK_real, __rconde: *mut __CLPK_real,
-                   __rcondv: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn cgegs_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                  __alpha: *mut __CLPK_complex, __beta: *mut __CLPK_complex,
-                  __vsl: *mut __CLPK_complex, __ldvsl: *mut __CLPK_integer,
-                  __vsr: *mut __CLPK_complex, __ldvsr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn cgeevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
+                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_complex,
+                   __vl: *mut __CLPK_complex, __ldvl: *mut __CLPK_integer,
+                   __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
+                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
+                   __scale: *mut __CLPK_real, __abnrm: *mut __CLPK_real,
+                   __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
+                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __balanc_len: __CLPK_ftnlen, __jobvl_len: __CLPK_ftnlen,
+                   __jobvr_len: __CLPK_ftnlen, __sense_len: __CLPK_ftnlen);
+    pub fn cgegs_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
+                  __ldb: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
+                  __beta: *mut __CLPK_complex, __vsl: *mut __CLPK_complex,
+                  __ldvsl: *mut __CLPK_integer, __vsr: *mut __CLPK_complex,
+                  __ldvsr: *mut __CLPK_integer, __work: *mut __CLPK_complex,
+                  __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
+                  __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                  __jobvsr_len: __CLPK_ftnlen);
     pub fn cgegv_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
@@ -201,32 +212,31 @@ extern "C" {
                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_complex,
                   __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                   __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvl_len: __CLPK_ftnlen,
+                  __jobvr_len: __CLPK_ftnlen);
     pub fn cgehd2_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cgehrd_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgelq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgelqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgels_(__trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cgelsd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
@@ -234,59 +244,54 @@ extern "C" {
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgelss_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgelsx_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgelsy_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgeql2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgeqlf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgeqp3_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn cgeqpf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgeqr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgeqrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgerfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_complex,
@@ -295,20 +300,19 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cgerq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgerqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgesc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __rhs: *mut __CLPK_complex,
                    __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_real) -> ::libc::c_int;
+                   __scale: *mut __CLPK_real);
     pub fn cgesdd_(__jobz: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
@@ -316,12 +320,11 @@ extern "C" {
                    __vt: *mut __CLPK_complex, __ldvt: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen);
     pub fn cgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgesvd_(__jobu: *mut ::libc::c_char, __jobvt: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -329,7 +332,8 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_complex,
                    __ldvt: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobvt_len: __CLPK_ftnlen);
     pub fn cgesvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -340,54 +344,53 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn cgetc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgetf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgetrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgetri_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgetrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cggbak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __lscale: *mut __CLPK_real,
                    __rscale: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __v: *mut __CLPK_complex, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __side_len: __CLPK_ftnlen);
     pub fn cggbal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_real, __rscale: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn cgges_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
-                  __selctg: __CLPK_L_fp, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                  __sdim: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
-                  __beta: *mut __CLPK_complex, __vsl: *mut __CLPK_complex,
-                  __ldvsl: *mut __CLPK_integer, __vsr: *mut __CLPK_complex,
-                  __ldvsr: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                  __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
+    pub fn cgges_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __sort: *mut ::libc::c_char, __selctg: __CLPK_L_fp,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
+                  __ldb: *mut __CLPK_integer, __sdim: *mut __CLPK_integer,
+                  __alpha: *mut __CLPK_complex, __beta: *mut __CLPK_complex,
+                  __vsl: *mut __CLPK_complex, __ldvsl: *mut __CLPK_integer,
+                  __vsr: *mut __CLPK_complex, __ldvsr: *mut __CLPK_integer,
+                  __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
+                  __rwork: *mut __CLPK_real, __bwork: *mut __CLPK_logical,
+                  __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                  __jobvsr_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen);
     pub fn cggesx_(__jobvsl: *mut ::libc::c_char,
                    __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __selctg: __CLPK_L_fp, __sense: *mut ::libc::c_char,
@@ -400,9 +403,10 @@ extern "C" {
                    __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                   __jobvsr_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn cggev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
@@ -411,59 +415,59 @@ extern "C" {
                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_complex,
                   __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                   __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn cggevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __alpha: *mut __CLPK_complex, __beta: *mut __CLPK_complex,
-                   __vl: *mut __CLPK_complex, __ldvl: *mut __CLPK_integer,
-                   __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
-                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
-                   __lscale: *mut __CLPK_real, __rscale: *mut __CLPK_real,
-                   __abnrm: *mut __CLPK_real, __bbnrm: *mut __CLPK_real,
-                   __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
-                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvl_len: __CLPK_ftnlen,
+                  __jobvr_len: __CLPK_ftnlen);
+    pub fn cggevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
+                   __ldb: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
+                   __beta: *mut __CLPK_complex, __vl: *mut __CLPK_complex,
+                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_complex,
+                   __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
+                   __ihi: *mut __CLPK_integer, __lscale: *mut __CLPK_real,
+                   __rscale: *mut __CLPK_real, __abnrm: *mut __CLPK_real,
+                   __bbnrm: *mut __CLPK_real, __rconde: *mut __CLPK_real,
+                   __rcondv: *mut __CLPK_real, __work: *mut __CLPK_complex,
+                   __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
+                   __iwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn cggglm_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __d__: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __y: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgghrd_(__compq: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_complex,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_complex,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn cgglse_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_complex,
                    __d__: *mut __CLPK_complex, __x: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cggqrf_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_complex,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cggrqf_(__m: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_complex,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cggsvd_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __p: *mut __CLPK_integer,
@@ -475,8 +479,9 @@ extern "C" {
                    __v: *mut __CLPK_complex, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn cggsvp_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -489,13 +494,14 @@ extern "C" {
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __iwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen, __jobq_len: __CLPK_ftnlen);
     pub fn cgtcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_complex, __d__: *mut __CLPK_complex,
                    __du: *mut __CLPK_complex, __du2: *mut __CLPK_complex,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn cgtrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_complex,
                    __d__: *mut __CLPK_complex, __du: *mut __CLPK_complex,
@@ -505,13 +511,12 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cgtsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __dl: *mut __CLPK_complex, __d__: *mut __CLPK_complex,
                   __du: *mut __CLPK_complex, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cgtsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_complex, __d__: *mut __CLPK_complex,
@@ -522,41 +527,39 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cgttrf_(__n: *mut __CLPK_integer, __dl: *mut __CLPK_complex,
                    __d__: *mut __CLPK_complex, __du: *mut __CLPK_complex,
                    __du2: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cgttrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_complex,
                    __d__: *mut __CLPK_complex, __du: *mut __CLPK_complex,
                    __du2: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn cgtts2_(__itrans: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_complex,
                    __d__: *mut __CLPK_complex, __du: *mut __CLPK_complex,
                    __du2: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer);
     pub fn chbev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                   __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chbevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chbevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
@@ -568,15 +571,16 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chbgst_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_complex,
                    __ldbb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chbgv_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                   __kb: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
@@ -584,7 +588,8 @@ extern "C" {
                   __ldbb: *mut __CLPK_integer, __w: *mut __CLPK_real,
                   __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn chbgvd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
@@ -593,9 +598,9 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chbgvx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ka: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
@@ -608,39 +613,40 @@ extern "C" {
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chbtrd_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn checon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cheequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                     __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
                     __amax: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cheev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_real,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn cheevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cheevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -651,9 +657,9 @@ extern "C" {
                    __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn cheevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -664,34 +670,35 @@ extern "C" {
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chegs2_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chegst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chegv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                   __w: *mut __CLPK_real, __work: *mut __CLPK_complex,
                   __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn chegvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chegvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
@@ -703,8 +710,9 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cherfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_complex,
@@ -713,13 +721,13 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chesv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chesvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -729,43 +737,44 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chetd2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
-                   __tau: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chetf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chetrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chetrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chetri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chetrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chfrk_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __k: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                  __beta: *mut __CLPK_real, __c__: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                  __beta: *mut __CLPK_real, __c__: *mut __CLPK_complex,
+                  __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen);
     pub fn chgeqz_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -775,31 +784,31 @@ extern "C" {
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen,
+                   __compz_len: __CLPK_ftnlen);
     pub fn chla_transtype__(__ret_val: *mut ::libc::c_char,
                             __ret_val_len: __CLPK_ftnlen,
-                            __trans: *mut __CLPK_integer) -> ();
+                            __trans: *mut __CLPK_integer);
     pub fn chpcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chpev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chpevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chpevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __vl: *mut __CLPK_real,
@@ -809,28 +818,28 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chpgst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
-                   __bp: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bp: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chpgv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __ap: *mut __CLPK_complex, __bp: *mut __CLPK_complex,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chpgvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __bp: *mut __CLPK_complex,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_complex,
                    __lwork: *mut __CLPK_integer, __rwork: *mut __CLPK_real,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chpgvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
@@ -841,7 +850,8 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn chprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __afp: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
@@ -849,12 +859,12 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chpsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn chpsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __afp: *mut __CLPK_complex,
@@ -863,82 +873,88 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chptrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __tau: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn chptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn chsein_(__side: *mut ::libc::c_char, __eigsrc: *mut ::libc::c_char,
-                   __initv: *mut ::libc::c_char,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __h__: *mut __CLPK_complex, __ldh: *mut __CLPK_integer,
-                   __w: *mut __CLPK_complex, __vl: *mut __CLPK_complex,
-                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_complex,
-                   __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __ifaill: *mut __CLPK_integer,
-                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __initv: *mut ::libc::c_char, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
+                   __ldh: *mut __CLPK_integer, __w: *mut __CLPK_complex,
+                   __vl: *mut __CLPK_complex, __ldvl: *mut __CLPK_integer,
+                   __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
+                   __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
+                   __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
+                   __ifaill: *mut __CLPK_integer,
+                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __eigsrc_len: __CLPK_ftnlen,
+                   __initv_len: __CLPK_ftnlen);
     pub fn chseqr_(__job: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
                    __ldh: *mut __CLPK_integer, __w: *mut __CLPK_complex,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __compz_len: __CLPK_ftnlen);
     pub fn clabrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __tauq: *mut __CLPK_complex,
                    __taup: *mut __CLPK_complex, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __y: *mut __CLPK_complex,
-                   __ldy: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldy: *mut __CLPK_integer);
     pub fn clacgv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn clacn2_(__n: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __est: *mut __CLPK_real,
-                   __kase: *mut __CLPK_integer, __isave: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __kase: *mut __CLPK_integer, __isave: *mut __CLPK_integer);
     pub fn clacon_(__n: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __est: *mut __CLPK_real,
-                   __kase: *mut __CLPK_integer) -> ::libc::c_int;
+                   __kase: *mut __CLPK_integer);
     pub fn clacp2_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn clacpy_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn clacrm_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real);
     pub fn clacrt_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __cy: *mut __CLPK_complex,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_complex,
-                   __s: *mut __CLPK_complex) -> ::libc::c_int;
+                   __s: *mut __CLPK_complex);
+    #[cfg(lapack_abi = "f2c")]
     pub fn cladiv_(__ret_val: *mut __CLPK_complex, __x: *mut __CLPK_complex,
-                   __y: *mut __CLPK_complex) -> ();
+                   __y: *mut __CLPK_complex);
+    #[cfg(not(lapack_abi = "f2c"))]
+    pub fn cladiv_(__x: *mut __CLPK_complex, __y: *mut __CLPK_complex)
+     -> __CLPK_complex;
     pub fn claed0_(__qsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __qstore: *mut __CLPK_complex, __ldqs: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn claed7_(__n: *mut __CLPK_integer, __cutpnt: *mut __CLPK_integer,
                    __qsiz: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
@@ -950,8 +966,7 @@ extern "C" {
                    __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn claed8_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __qsiz: *mut __CLPK_integer, __q: *mut __CLPK_complex,
                    __ldq: *mut __CLPK_integer, __d__: *mut __CLPK_real,
@@ -962,72 +977,68 @@ extern "C" {
                    __indx: *mut __CLPK_integer, __indxq: *mut __CLPK_integer,
                    __perm: *mut __CLPK_integer, __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn claein_(__rightv: *mut __CLPK_logical,
                    __noinit: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __h__: *mut __CLPK_complex, __ldh: *mut __CLPK_integer,
                    __w: *mut __CLPK_complex, __v: *mut __CLPK_complex,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __eps3: *mut __CLPK_real,
-                   __smlnum: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __smlnum: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn claesy_(__a: *mut __CLPK_complex, __b: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __rt1: *mut __CLPK_complex,
                    __rt2: *mut __CLPK_complex, __evscal: *mut __CLPK_complex,
-                   __cs1: *mut __CLPK_complex, __sn1: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __cs1: *mut __CLPK_complex, __sn1: *mut __CLPK_complex);
     pub fn claev2_(__a: *mut __CLPK_complex, __b: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __rt1: *mut __CLPK_real,
                    __rt2: *mut __CLPK_real, __cs1: *mut __CLPK_real,
-                   __sn1: *mut __CLPK_complex) -> ::libc::c_int;
+                   __sn1: *mut __CLPK_complex);
     pub fn clag2z_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __sa: *mut __CLPK_complex, __ldsa: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn clags2_(__upper: *mut __CLPK_logical, __a1: *mut __CLPK_real,
                    __a2: *mut __CLPK_complex, __a3: *mut __CLPK_real,
                    __b1: *mut __CLPK_real, __b2: *mut __CLPK_complex,
                    __b3: *mut __CLPK_real, __csu: *mut __CLPK_real,
                    __snu: *mut __CLPK_complex, __csv: *mut __CLPK_real,
                    __snv: *mut __CLPK_complex, __csq: *mut __CLPK_real,
-                   __snq: *mut __CLPK_complex) -> ::libc::c_int;
+                   __snq: *mut __CLPK_complex);
     pub fn clagtm_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                    __dl: *mut __CLPK_complex, __d__: *mut __CLPK_complex,
                    __du: *mut __CLPK_complex, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __beta: *mut __CLPK_real,
-                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn clahef_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_complex,
-                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn clahqr_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
                    __ldh: *mut __CLPK_integer, __w: *mut __CLPK_complex,
                    __iloz: *mut __CLPK_integer, __ihiz: *mut __CLPK_integer,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn clahr2_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_complex, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_complex, __ldy: *mut __CLPK_integer);
     pub fn clahrd_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_complex, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_complex, __ldy: *mut __CLPK_integer);
     pub fn claic1_(__job: *mut __CLPK_integer, __j: *mut __CLPK_integer,
                    __x: *mut __CLPK_complex, __sest: *mut __CLPK_real,
                    __w: *mut __CLPK_complex, __gamma: *mut __CLPK_complex,
                    __sestpr: *mut __CLPK_real, __s: *mut __CLPK_complex,
-                   __c__: *mut __CLPK_complex) -> ::libc::c_int;
+                   __c__: *mut __CLPK_complex);
     pub fn clals0_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_complex,
@@ -1040,8 +1051,7 @@ extern "C" {
                    __difl: *mut __CLPK_real, __difr: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __s: *mut __CLPK_real,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn clalsa_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_complex,
@@ -1055,122 +1065,132 @@ extern "C" {
                    __ldgcol: *mut __CLPK_integer, __perm: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __s: *mut __CLPK_real, __rwork: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn clalsd_(__uplo: *mut ::libc::c_char, __smlsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn clangb_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn clange_(__norm: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clangt_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_complex, __d__: *mut __CLPK_complex,
-                   __du: *mut __CLPK_complex) -> __CLPK_doublereal;
+                   __du: *mut __CLPK_complex, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn clanhb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clanhe_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn clanhf_(__norm: *mut ::libc::c_char, __transr: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_complex, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __a: *mut __CLPK_complex, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clanhp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clanhs_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn clanht_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_real, __e: *mut __CLPK_complex)
-     -> __CLPK_doublereal;
+                   __d__: *mut __CLPK_real, __e: *mut __CLPK_complex,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clansb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clansp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clansy_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn clantb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
-                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clantp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_complex, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __ap: *mut __CLPK_complex, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clantr_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn clapll_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_complex,
-                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_real);
     pub fn clapmt_(__forwrd: *mut __CLPK_logical, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
-                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer);
     pub fn claqgb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqge_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqhb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqhe_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn claqhp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqp2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __vn1: *mut __CLPK_real,
-                   __vn2: *mut __CLPK_real, __work: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __vn2: *mut __CLPK_real, __work: *mut __CLPK_complex);
     pub fn claqps_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __nb: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __vn1: *mut __CLPK_real,
                    __vn2: *mut __CLPK_real, __auxv: *mut __CLPK_complex,
-                   __f: *mut __CLPK_complex, __ldf: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __f: *mut __CLPK_complex, __ldf: *mut __CLPK_integer);
     pub fn claqr0_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
@@ -1178,11 +1198,10 @@ extern "C" {
                    __iloz: *mut __CLPK_integer, __ihiz: *mut __CLPK_integer,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn claqr1_(__n: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
                    __ldh: *mut __CLPK_integer, __s1: *mut __CLPK_complex,
-                   __s2: *mut __CLPK_complex, __v: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __s2: *mut __CLPK_complex, __v: *mut __CLPK_complex);
     pub fn claqr2_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -1195,7 +1214,7 @@ extern "C" {
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
                    __nv: *mut __CLPK_integer, __wv: *mut __CLPK_complex,
                    __ldwv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer);
     pub fn claqr3_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -1208,7 +1227,7 @@ extern "C" {
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
                    __nv: *mut __CLPK_integer, __wv: *mut __CLPK_complex,
                    __ldwv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer);
     pub fn claqr4_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_complex,
@@ -1216,7 +1235,7 @@ extern "C" {
                    __iloz: *mut __CLPK_integer, __ihiz: *mut __CLPK_integer,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn claqr5_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __kacc22: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ktop: *mut __CLPK_integer, __kbot: *mut __CLPK_integer,
@@ -1228,22 +1247,23 @@ extern "C" {
                    __u: *mut __CLPK_complex, __ldu: *mut __CLPK_integer,
                    __nv: *mut __CLPK_integer, __wv: *mut __CLPK_complex,
                    __ldwv: *mut __CLPK_integer, __nh: *mut __CLPK_integer,
-                   __wh: *mut __CLPK_complex, __ldwh: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __wh: *mut __CLPK_complex, __ldwh: *mut __CLPK_integer);
     pub fn claqsb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqsp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn claqsy_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn clar1v_(__n: *mut __CLPK_integer, __b1: *mut __CLPK_integer,
                    __bn: *mut __CLPK_integer, __lambda: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __l: *mut __CLPK_real,
@@ -1254,22 +1274,21 @@ extern "C" {
                    __mingma: *mut __CLPK_real, __r__: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __nrminv: *mut __CLPK_real,
                    __resid: *mut __CLPK_real, __rqcorr: *mut __CLPK_real,
-                   __work: *mut __CLPK_real) -> ::libc::c_int;
+                   __work: *mut __CLPK_real);
     pub fn clar2v_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __y: *mut __CLPK_complex, __z__: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __s: *mut __CLPK_complex, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_complex, __incc: *mut __CLPK_integer);
     pub fn clarcm_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real);
     pub fn clarf_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                   __incv: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                   __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_complex) -> ::libc::c_int;
+                  __work: *mut __CLPK_complex, __side_len: __CLPK_ftnlen);
     pub fn clarfb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -1277,32 +1296,32 @@ extern "C" {
                    __v: *mut __CLPK_complex, __ldv: *mut __CLPK_integer,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __ldwork: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __ldwork: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn clarfg_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_complex) -> ::libc::c_int;
+                   __tau: *mut __CLPK_complex);
     pub fn clarfp_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_complex) -> ::libc::c_int;
+                   __tau: *mut __CLPK_complex);
     pub fn clarft_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn clarfx_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
-                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
+                   __side_len: __CLPK_ftnlen);
     pub fn clargv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_complex,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incc: *mut __CLPK_integer);
     pub fn clarnv_(__idist: *mut __CLPK_integer, __iseed: *mut __CLPK_integer,
-                   __n: *mut __CLPK_integer, __x: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __n: *mut __CLPK_integer, __x: *mut __CLPK_complex);
     pub fn clarrv_(__n: *mut __CLPK_integer, __vl: *mut __CLPK_real,
                    __vu: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __l: *mut __CLPK_real, __pivmin: *mut __CLPK_real,
@@ -1315,25 +1334,23 @@ extern "C" {
                    __indexw: *mut __CLPK_integer, __gers: *mut __CLPK_real,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn clarscl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                      __d__: *mut __CLPK_real, __x: *mut __CLPK_complex,
-                     __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                     __ldx: *mut __CLPK_integer);
     pub fn clartg_(__f: *mut __CLPK_complex, __g: *mut __CLPK_complex,
                    __cs: *mut __CLPK_real, __sn: *mut __CLPK_complex,
-                   __r__: *mut __CLPK_complex) -> ::libc::c_int;
+                   __r__: *mut __CLPK_complex);
     pub fn clartv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_complex,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __s: *mut __CLPK_complex, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_complex, __incc: *mut __CLPK_integer);
     pub fn clarz_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                   __v: *mut __CLPK_complex, __incv: *mut __CLPK_integer,
                   __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
-                  __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                  __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
+                  __side_len: __CLPK_ftnlen);
     pub fn clarzb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -1342,100 +1359,107 @@ extern "C" {
                    __ldv: *mut __CLPK_integer, __t: *mut __CLPK_complex,
                    __ldt: *mut __CLPK_integer, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn clarzt_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn clascl_(__type__: *mut ::libc::c_char, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __cfrom: *mut __CLPK_real,
                    __cto: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __type_len: __CLPK_ftnlen);
     pub fn clascl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __d__: *mut __CLPK_real, __x: *mut __CLPK_complex,
-                    __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                    __ldx: *mut __CLPK_integer);
     pub fn claset_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
                    __beta: *mut __CLPK_complex, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn clasr_(__side: *mut ::libc::c_char, __pivot: *mut ::libc::c_char,
                   __direct: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                   __s: *mut __CLPK_real, __a: *mut __CLPK_complex,
-                  __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                  __lda: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                  __pivot_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen);
     pub fn classq_(__n: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __incx: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __sumsq: *mut __CLPK_real) -> ::libc::c_int;
+                   __sumsq: *mut __CLPK_real);
     pub fn claswp_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __k1: *mut __CLPK_integer,
                    __k2: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn clasyf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_complex,
-                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn clatbs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __x: *mut __CLPK_complex, __scale: *mut __CLPK_real,
-                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen, __normin_len: __CLPK_ftnlen);
     pub fn clatdf_(__ijob: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __rhs: *mut __CLPK_complex, __rdsum: *mut __CLPK_real,
                    __rdscal: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer) -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer);
     pub fn clatps_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __x: *mut __CLPK_complex, __scale: *mut __CLPK_real,
-                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen, __normin_len: __CLPK_ftnlen);
     pub fn clatrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __e: *mut __CLPK_real,
                    __tau: *mut __CLPK_complex, __w: *mut __CLPK_complex,
-                   __ldw: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn clatrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __scale: *mut __CLPK_real, __cnorm: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn clatrz_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __l: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex) -> ::libc::c_int;
+                   __work: *mut __CLPK_complex);
     pub fn clatzm_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_complex,
                    __incv: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c1: *mut __CLPK_complex, __c2: *mut __CLPK_complex,
-                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
+                   __side_len: __CLPK_ftnlen);
     pub fn clauu2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn clauum_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpbcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpbequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpbrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
@@ -1444,16 +1468,16 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpbstf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpbsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpbsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
@@ -1464,44 +1488,47 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn cpbtf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpbtrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpbtrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpftrf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpftrs_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn cpocon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpoequ_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cpoequb_(__n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                     __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
                     __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer);
     pub fn cporfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_complex,
@@ -1509,13 +1536,13 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn cposvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -1525,43 +1552,44 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn cpotf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpotrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpotri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpotrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cppcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cppequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __afp: *mut __CLPK_complex, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cppsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cppsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __afp: *mut __CLPK_complex,
@@ -1570,37 +1598,38 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn cpptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cpptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpstf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cpstrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cptcon_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_complex, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cpteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn cptrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_complex, __df: *mut __CLPK_real,
@@ -1608,12 +1637,12 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cptsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __d__: *mut __CLPK_real, __e: *mut __CLPK_complex,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer);
     pub fn cptsvx_(__fact: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_complex, __df: *mut __CLPK_real,
@@ -1622,37 +1651,36 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen);
     pub fn cpttrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
-                   __e: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cpttrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_complex, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cptts2_(__iuplo: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_complex, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer);
     pub fn crot_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_complex,
                  __incx: *mut __CLPK_integer, __cy: *mut __CLPK_complex,
                  __incy: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                 __s: *mut __CLPK_complex) -> ::libc::c_int;
+                 __s: *mut __CLPK_complex);
     pub fn cspcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cspmv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_complex, __ap: *mut __CLPK_complex,
                   __x: *mut __CLPK_complex, __incx: *mut __CLPK_integer,
                   __beta: *mut __CLPK_complex, __y: *mut __CLPK_complex,
-                  __incy: *mut __CLPK_integer) -> ::libc::c_int;
+                  __incy: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cspr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                  __alpha: *mut __CLPK_complex, __x: *mut __CLPK_complex,
-                 __incx: *mut __CLPK_integer, __ap: *mut __CLPK_complex)
-     -> ::libc::c_int;
+                 __incx: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
+                 __uplo_len: __CLPK_ftnlen);
     pub fn csprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __afp: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
@@ -1660,12 +1688,12 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn cspsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn cspsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __afp: *mut __CLPK_complex,
@@ -1674,30 +1702,29 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn csptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn csptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn csrscl_(__n: *mut __CLPK_integer, __sa: *mut __CLPK_real,
-                   __sx: *mut __CLPK_complex, __incx: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __sx: *mut __CLPK_complex, __incx: *mut __CLPK_integer);
     pub fn cstedc_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_real, __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __compz_len: __CLPK_ftnlen);
     pub fn cstegr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -1707,15 +1734,15 @@ extern "C" {
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn cstein_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __iblock: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cstemr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -1726,33 +1753,33 @@ extern "C" {
                    __isuppz: *mut __CLPK_integer,
                    __tryrac: *mut __CLPK_logical, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn csteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn csycon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csyequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                     __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
                     __amax: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csymv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_complex, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                   __incx: *mut __CLPK_integer, __beta: *mut __CLPK_complex,
-                  __y: *mut __CLPK_complex, __incy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __y: *mut __CLPK_complex, __incy: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn csyr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                  __alpha: *mut __CLPK_complex, __x: *mut __CLPK_complex,
                  __incx: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                 __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                 __lda: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csyrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_complex,
@@ -1761,13 +1788,13 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csysv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csysvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -1777,32 +1804,33 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn csytf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn csytrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn csytri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn csytrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ctbcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_complex,
                    __ldab: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctbrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -1811,31 +1839,37 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctbtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_complex, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctfsm_(__transr: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                   __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
                   __a: *mut __CLPK_complex, __b: *mut __CLPK_complex,
-                  __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                  __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __a: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ctfttp_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_complex,
-                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ctfttr_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_complex,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ctgevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __s: *mut __CLPK_complex, __lds: *mut __CLPK_integer,
@@ -1844,36 +1878,34 @@ extern "C" {
                    __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ctgex2_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_complex,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __j1: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn ctgexc_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_complex,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_complex,
                    __ldz: *mut __CLPK_integer, __ifst: *mut __CLPK_integer,
-                   __ilst: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ilst: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn ctgsen_(__ijob: *mut __CLPK_integer, __wantq: *mut __CLPK_logical,
-                   __wantz: *mut __CLPK_logical,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __alpha: *mut __CLPK_complex, __beta: *mut __CLPK_complex,
-                   __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
-                   __z__: *mut __CLPK_complex, __ldz: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer, __pl: *mut __CLPK_real,
-                   __pr: *mut __CLPK_real, __dif: *mut __CLPK_real,
-                   __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __wantz: *mut __CLPK_logical, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
+                   __ldb: *mut __CLPK_integer, __alpha: *mut __CLPK_complex,
+                   __beta: *mut __CLPK_complex, __q: *mut __CLPK_complex,
+                   __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_complex,
+                   __ldz: *mut __CLPK_integer, __m: *mut __CLPK_integer,
+                   __pl: *mut __CLPK_real, __pr: *mut __CLPK_real,
+                   __dif: *mut __CLPK_real, __work: *mut __CLPK_complex,
+                   __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn ctgsja_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -1886,7 +1918,8 @@ extern "C" {
                    __v: *mut __CLPK_complex, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __ncycle: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen, __jobq_len: __CLPK_ftnlen);
     pub fn ctgsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -1896,8 +1929,8 @@ extern "C" {
                    __s: *mut __CLPK_real, __dif: *mut __CLPK_real,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn ctgsy2_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -1907,8 +1940,8 @@ extern "C" {
                    __e: *mut __CLPK_complex, __lde: *mut __CLPK_integer,
                    __f: *mut __CLPK_complex, __ldf: *mut __CLPK_integer,
                    __scale: *mut __CLPK_real, __rdsum: *mut __CLPK_real,
-                   __rdscal: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rdscal: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn ctgsyl_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
@@ -1919,13 +1952,14 @@ extern "C" {
                    __f: *mut __CLPK_complex, __ldf: *mut __CLPK_integer,
                    __scale: *mut __CLPK_real, __dif: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn ctpcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctprfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
@@ -1933,29 +1967,33 @@ extern "C" {
                    __x: *mut __CLPK_complex, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctptri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ctptrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __b: *mut __CLPK_complex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctpttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
-                   __arf: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __arf: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ctpttr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ctrcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ctrevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
@@ -1963,12 +2001,13 @@ extern "C" {
                    __vr: *mut __CLPK_complex, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __rwork: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ctrexc_(__compq: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
                    __ifst: *mut __CLPK_integer, __ilst: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __compq_len: __CLPK_ftnlen);
     pub fn ctrrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
@@ -1976,8 +2015,9 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_complex,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_complex,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ctrsen_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
@@ -1985,7 +2025,8 @@ extern "C" {
                    __w: *mut __CLPK_complex, __m: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __sep: *mut __CLPK_real,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __compq_len: __CLPK_ftnlen);
     pub fn ctrsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_complex, __ldt: *mut __CLPK_integer,
@@ -1994,215 +2035,220 @@ extern "C" {
                    __s: *mut __CLPK_real, __sep: *mut __CLPK_real,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __ldwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rwork: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn ctrsyl_(__trana: *mut ::libc::c_char, __tranb: *mut ::libc::c_char,
                    __isgn: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trana_len: __CLPK_ftnlen,
+                   __tranb_len: __CLPK_ftnlen);
     pub fn ctrti2_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctrtri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ctrtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_complex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ctrttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __arf: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ctrttp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ctzrqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn ctzrzf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn cung2l_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cung2r_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cungbr_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn cunghr_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cungl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cunglq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cungql_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cungqr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cungr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer);
     pub fn cungrq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn cungtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cunm2l_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunm2r_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunmbr_(__vect: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmhr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunml2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunmlq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmql_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmqr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmr2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunmr3_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmrq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn cunmrz_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cunmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_complex,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_complex,
                    __c__: *mut __CLPK_complex, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_complex, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn cupgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_complex, __tau: *mut __CLPK_complex,
                    __q: *mut __CLPK_complex, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_complex, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn cupmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_complex,
                    __tau: *mut __CLPK_complex, __c__: *mut __CLPK_complex,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_complex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dbdsdc_(__uplo: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublereal,
                    __ldvt: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
                    __iq: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen);
     pub fn dbdsqr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ncvt: *mut __CLPK_integer, __nru: *mut __CLPK_integer,
                    __ncc: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
@@ -2210,11 +2256,11 @@ extern "C" {
                    __ldvt: *mut __CLPK_integer, __u: *mut __CLPK_doublereal,
                    __ldu: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ddisna_(__job: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __sep: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __sep: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
     pub fn dgbbrd_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
@@ -2223,8 +2269,8 @@ extern "C" {
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
                    __pt: *mut __CLPK_doublereal, __ldpt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn dgbcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
@@ -2232,8 +2278,8 @@ extern "C" {
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn dgbequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
@@ -2241,8 +2287,7 @@ extern "C" {
                    __c__: *mut __CLPK_doublereal,
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgbequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                     __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
@@ -2250,8 +2295,7 @@ extern "C" {
                     __c__: *mut __CLPK_doublereal,
                     __rowcnd: *mut __CLPK_doublereal,
                     __colcnd: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgbrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
@@ -2262,96 +2306,88 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dgbsv_(__n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                   __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgbsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __afb: *mut __CLPK_doublereal,
-                   __ldafb: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __equed: *mut ::libc::c_char,
+                   __afb: *mut __CLPK_doublereal, __ldafb: *mut __CLPK_integer,
+                   __ipiv: *mut __CLPK_integer, __equed: *mut ::libc::c_char,
                    __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dgbtf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgbtrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgbtrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dgebak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __v: *mut __CLPK_doublereal, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
+                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen);
     pub fn dgebal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __scale: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen);
     pub fn dgebd2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tauq: *mut __CLPK_doublereal,
                    __taup: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgebrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tauq: *mut __CLPK_doublereal,
                    __taup: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgecon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn dgeequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgeequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                     __r__: *mut __CLPK_doublereal,
                     __c__: *mut __CLPK_doublereal,
                     __rowcnd: *mut __CLPK_doublereal,
                     __colcnd: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgees_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                   __select: __CLPK_L_fp, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -2359,7 +2395,8 @@ extern "C" {
                   __wi: *mut __CLPK_doublereal, __vs: *mut __CLPK_doublereal,
                   __ldvs: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                   __lwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvs_len: __CLPK_ftnlen,
+                  __sort_len: __CLPK_ftnlen);
     pub fn dgeesx_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __select: __CLPK_L_fp, __sense: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
@@ -2370,67 +2407,63 @@ extern "C" {
                    __rcondv: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __jobvs_len: __CLPK_ftnlen,
+                   __sort_len: __CLPK_ftnlen, __sense_len: __CLPK_ftnlen);
     pub fn dgeev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
                   __wi: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_doublereal,
                   __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn dgeevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __wr: *mut __CLPK_doublereal, __wi: *mut __CLPK_doublereal,
-                   __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
-                   __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
-                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn dgeevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
+                   __lda: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
+                   __wi: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_doublereal,
+                   __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
+                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
                    __abnrm: *mut __CLPK_doublereal,
                    __rconde: *mut __CLPK_doublereal,
                    __rcondv: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn dgegs_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __alphar: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
+    pub fn dgegs_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
+                  __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_doublereal,
                   __alphai: *mut __CLPK_doublereal,
                   __beta: *mut __CLPK_doublereal,
                   __vsl: *mut __CLPK_doublereal, __ldvsl: *mut __CLPK_integer,
                   __vsr: *mut __CLPK_doublereal, __ldvsr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                  __jobvsr_len: __CLPK_ftnlen);
     pub fn dgegv_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer,
-                  __alphar: *mut __CLPK_doublereal,
+                  __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_doublereal,
                   __alphai: *mut __CLPK_doublereal,
-                  __beta: *mut __CLPK_doublereal,
-                  __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
-                  __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __beta: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                  __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_doublereal,
+                  __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
     pub fn dgehd2_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgehrd_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgejsv_(__joba: *mut ::libc::c_char, __jobu: *mut ::libc::c_char,
                    __jobv: *mut ::libc::c_char, __jobr: *mut ::libc::c_char,
                    __jobt: *mut ::libc::c_char, __jobp: *mut ::libc::c_char,
@@ -2440,93 +2473,81 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __ldv: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __joba_len: __CLPK_ftnlen,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobr_len: __CLPK_ftnlen, __jobt_len: __CLPK_ftnlen,
+                   __jobp_len: __CLPK_ftnlen);
     pub fn dgelq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgelqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgels_(__trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dgelsd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
-                   __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
+                   __rank: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dgelss_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
-                   __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rank: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgelsx_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
-                   __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rank: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer);
     pub fn dgelsy_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
-                   __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rank: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgeql2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgeqlf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgeqp3_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgeqpf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgeqr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgeqrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgerfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublereal,
@@ -2536,23 +2557,21 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dgerq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dgerqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgesc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __rhs: *mut __CLPK_doublereal,
                    __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __scale: *mut __CLPK_doublereal);
     pub fn dgesdd_(__jobz: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
@@ -2560,29 +2579,28 @@ extern "C" {
                    __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen);
     pub fn dgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgesvd_(__jobu: *mut ::libc::c_char, __jobvt: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublereal,
-                   __ldvt: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldvt: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobvt_len: __CLPK_ftnlen);
     pub fn dgesvj_(__joba: *mut ::libc::c_char, __jobu: *mut ::libc::c_char,
                    __jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __sva: *mut __CLPK_doublereal,
                    __mv: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __ldv: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __joba_len: __CLPK_ftnlen, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen);
     pub fn dgesvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -2591,64 +2609,61 @@ extern "C" {
                    __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dgetc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgetf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgetrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgetri_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgetrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dggbak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_doublereal,
                    __rscale: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __v: *mut __CLPK_doublereal, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __side_len: __CLPK_ftnlen);
     pub fn dggbal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_doublereal,
                    __rscale: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn dgges_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
-                  __selctg: __CLPK_L_fp, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __sdim: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
+    pub fn dgges_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __sort: *mut ::libc::c_char, __selctg: __CLPK_L_fp,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
+                  __ldb: *mut __CLPK_integer, __sdim: *mut __CLPK_integer,
                   __alphar: *mut __CLPK_doublereal,
                   __alphai: *mut __CLPK_doublereal,
                   __beta: *mut __CLPK_doublereal,
                   __vsl: *mut __CLPK_doublereal, __ldvsl: *mut __CLPK_integer,
                   __vsr: *mut __CLPK_doublereal, __ldvsr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer,
+                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                  __jobvsl_len: __CLPK_ftnlen, __jobvsr_len: __CLPK_ftnlen,
+                  __sort_len: __CLPK_ftnlen);
     pub fn dggesx_(__jobvsl: *mut ::libc::c_char,
                    __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __selctg: __CLPK_L_fp, __sense: *mut ::libc::c_char,
@@ -2658,34 +2673,31 @@ extern "C" {
                    __alphar: *mut __CLPK_doublereal,
                    __alphai: *mut __CLPK_doublereal,
                    __beta: *mut __CLPK_doublereal,
-                   __vsl: *mut __CLPK_doublereal,
-                   __ldvsl: *mut __CLPK_integer,
-                   __vsr: *mut __CLPK_doublereal,
-                   __ldvsr: *mut __CLPK_integer,
+                   __vsl: *mut __CLPK_doublereal, __ldvsl: *mut __CLPK_integer,
+                   __vsr: *mut __CLPK_doublereal, __ldvsr: *mut __CLPK_integer,
                    __rconde: *mut __CLPK_doublereal,
                    __rcondv: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                   __jobvsr_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn dggev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer,
-                  __alphar: *mut __CLPK_doublereal,
+                  __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_doublereal,
                   __alphai: *mut __CLPK_doublereal,
-                  __beta: *mut __CLPK_doublereal,
-                  __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
-                  __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn dggevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
+                  __beta: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                  __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_doublereal,
+                  __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn dggevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
+                   __ldb: *mut __CLPK_integer,
                    __alphar: *mut __CLPK_doublereal,
                    __alphai: *mut __CLPK_doublereal,
                    __beta: *mut __CLPK_doublereal,
@@ -2700,48 +2712,45 @@ extern "C" {
                    __rcondv: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                   __balanc_len: __CLPK_ftnlen, __jobvl_len: __CLPK_ftnlen,
+                   __jobvr_len: __CLPK_ftnlen, __sense_len: __CLPK_ftnlen);
     pub fn dggglm_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __y: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgghrd_(__compq: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn dgglse_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __x: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dggqrf_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dggrqf_(__m: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dggsvd_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __p: *mut __CLPK_integer,
@@ -2749,13 +2758,13 @@ extern "C" {
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
-                   __v: *mut __CLPK_doublereal, __ldv: *mut __CLPK_integer,
-                   __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __beta: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
+                   __ldu: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
+                   __ldv: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
+                   __ldq: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn dggsvp_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -2768,8 +2777,9 @@ extern "C" {
                    __ldv: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn dgsvj0_(__jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
@@ -2780,8 +2790,8 @@ extern "C" {
                    __tol: *mut __CLPK_doublereal,
                    __nsweep: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobv_len: __CLPK_ftnlen);
     pub fn dgsvj1_(__jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __n1: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -2793,24 +2803,21 @@ extern "C" {
                    __tol: *mut __CLPK_doublereal,
                    __nsweep: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobv_len: __CLPK_ftnlen);
     pub fn dgtcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
-                   __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
+                   __dl: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __du: *mut __CLPK_doublereal, __du2: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn dgtrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
-                   __dlf: *mut __CLPK_doublereal,
-                   __df: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __du: *mut __CLPK_doublereal,
+                   __dlf: *mut __CLPK_doublereal, __df: *mut __CLPK_doublereal,
                    __duf: *mut __CLPK_doublereal,
                    __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
@@ -2818,21 +2825,17 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dgtsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __dl: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
                   __du: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dgtsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
-                   __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
-                   __dlf: *mut __CLPK_doublereal,
-                   __df: *mut __CLPK_doublereal,
-                   __duf: *mut __CLPK_doublereal,
+                   __dl: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __du: *mut __CLPK_doublereal, __dlf: *mut __CLPK_doublereal,
+                   __df: *mut __CLPK_doublereal, __duf: *mut __CLPK_doublereal,
                    __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
@@ -2840,27 +2843,23 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dgttrf_(__n: *mut __CLPK_integer, __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __du: *mut __CLPK_doublereal,
                    __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dgttrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __du: *mut __CLPK_doublereal,
                    __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dgtts2_(__itrans: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __du: *mut __CLPK_doublereal,
                    __du2: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer);
     pub fn dhgeqz_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -2868,65 +2867,60 @@ extern "C" {
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
                    __alphar: *mut __CLPK_doublereal,
                    __alphai: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __beta: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
+                   __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
+                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen,
+                   __compz_len: __CLPK_ftnlen);
     pub fn dhsein_(__side: *mut ::libc::c_char, __eigsrc: *mut ::libc::c_char,
-                   __initv: *mut ::libc::c_char,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __h__: *mut __CLPK_doublereal, __ldh: *mut __CLPK_integer,
-                   __wr: *mut __CLPK_doublereal, __wi: *mut __CLPK_doublereal,
-                   __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
-                   __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
-                   __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
+                   __initv: *mut ::libc::c_char, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
+                   __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
+                   __wi: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_doublereal,
+                   __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __ifaill: *mut __CLPK_integer,
-                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __eigsrc_len: __CLPK_ftnlen,
+                   __initv_len: __CLPK_ftnlen);
     pub fn dhseqr_(__job: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
                    __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
-                   __wi: *mut __CLPK_doublereal,
-                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __wi: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
+                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn disnan_(__din: *mut __CLPK_doublereal) -> __CLPK_logical;
     pub fn dlabad_(__small: *mut __CLPK_doublereal,
-                   __large: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __large: *mut __CLPK_doublereal);
     pub fn dlabrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal,
-                   __tauq: *mut __CLPK_doublereal,
-                   __taup: *mut __CLPK_doublereal,
-                   __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublereal, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_doublereal, __tauq: *mut __CLPK_doublereal,
+                   __taup: *mut __CLPK_doublereal, __x: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __y: *mut __CLPK_doublereal,
+                   __ldy: *mut __CLPK_integer);
     pub fn dlacn2_(__n: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __isgn: *mut __CLPK_integer,
                    __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer,
-                   __isave: *mut __CLPK_integer) -> ::libc::c_int;
+                   __isave: *mut __CLPK_integer);
     pub fn dlacon_(__n: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __isgn: *mut __CLPK_integer,
-                   __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer);
     pub fn dlacpy_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dladiv_(__a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __p: *mut __CLPK_doublereal,
-                   __q: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __q: *mut __CLPK_doublereal);
     pub fn dlae2_(__a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
-                  __c__: *mut __CLPK_doublereal,
-                  __rt1: *mut __CLPK_doublereal,
-                  __rt2: *mut __CLPK_doublereal) -> ::libc::c_int;
+                  __c__: *mut __CLPK_doublereal, __rt1: *mut __CLPK_doublereal,
+                  __rt2: *mut __CLPK_doublereal);
     pub fn dlaebz_(__ijob: *mut __CLPK_integer, __nitmax: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __mmax: *mut __CLPK_integer,
                    __minp: *mut __CLPK_integer, __nbmin: *mut __CLPK_integer,
@@ -2935,40 +2929,33 @@ extern "C" {
                    __pivmin: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __e2: *mut __CLPK_doublereal, __nval: *mut __CLPK_integer,
-                   __ab: *mut __CLPK_doublereal,
-                   __c__: *mut __CLPK_doublereal, __mout: *mut __CLPK_integer,
-                   __nab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ab: *mut __CLPK_doublereal, __c__: *mut __CLPK_doublereal,
+                   __mout: *mut __CLPK_integer, __nab: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaed0_(__icompq: *mut __CLPK_integer, __qsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer,
                    __qstore: *mut __CLPK_doublereal,
-                   __ldqs: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldqs: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaed1_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __indxq: *mut __CLPK_integer,
-                   __rho: *mut __CLPK_doublereal,
+                   __indxq: *mut __CLPK_integer, __rho: *mut __CLPK_doublereal,
                    __cutpnt: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaed2_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __indxq: *mut __CLPK_integer,
-                   __rho: *mut __CLPK_doublereal,
+                   __indxq: *mut __CLPK_integer, __rho: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __dlamda: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal, __q2: *mut __CLPK_doublereal,
                    __indx: *mut __CLPK_integer, __indxc: *mut __CLPK_integer,
-                   __indxp: *mut __CLPK_integer,
-                   __coltyp: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __indxp: *mut __CLPK_integer, __coltyp: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer);
     pub fn dlaed3_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
@@ -2976,28 +2963,25 @@ extern "C" {
                    __dlamda: *mut __CLPK_doublereal,
                    __q2: *mut __CLPK_doublereal, __indx: *mut __CLPK_integer,
                    __ctot: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlaed4_(__n: *mut __CLPK_integer, __i__: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __delta: *mut __CLPK_doublereal,
                    __rho: *mut __CLPK_doublereal,
-                   __dlam: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __dlam: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlaed5_(__i__: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __delta: *mut __CLPK_doublereal,
                    __rho: *mut __CLPK_doublereal,
-                   __dlam: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __dlam: *mut __CLPK_doublereal);
     pub fn dlaed6_(__kniter: *mut __CLPK_integer,
                    __orgati: *mut __CLPK_logical,
                    __rho: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __finit: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlaed7_(__icompq: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __qsiz: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
@@ -3012,8 +2996,7 @@ extern "C" {
                    __givcol: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaed8_(__icompq: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __qsiz: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
@@ -3028,15 +3011,14 @@ extern "C" {
                    __givcol: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_doublereal,
                    __indxp: *mut __CLPK_integer, __indx: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlaed9_(__k: *mut __CLPK_integer, __kstart: *mut __CLPK_integer,
                    __kstop: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer, __rho: *mut __CLPK_doublereal,
                    __dlamda: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
-                   __lds: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lds: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaeda_(__n: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
                    __curpbm: *mut __CLPK_integer,
@@ -3046,8 +3028,7 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublereal, __qptr: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal,
-                   __ztemp: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ztemp: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlaein_(__rightv: *mut __CLPK_logical,
                    __noinit: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __h__: *mut __CLPK_doublereal, __ldh: *mut __CLPK_integer,
@@ -3058,62 +3039,57 @@ extern "C" {
                    __eps3: *mut __CLPK_doublereal,
                    __smlnum: *mut __CLPK_doublereal,
                    __bignum: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlaev2_(__a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __rt1: *mut __CLPK_doublereal,
                    __rt2: *mut __CLPK_doublereal,
-                   __cs1: *mut __CLPK_doublereal,
-                   __sn1: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __cs1: *mut __CLPK_doublereal, __sn1: *mut __CLPK_doublereal);
     pub fn dlaexc_(__wantq: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
                    __j1: *mut __CLPK_integer, __n1: *mut __CLPK_integer,
                    __n2: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlag2_(__a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                   __safmin: *mut __CLPK_doublereal,
                   __scale1: *mut __CLPK_doublereal,
                   __scale2: *mut __CLPK_doublereal,
-                  __wr1: *mut __CLPK_doublereal,
-                  __wr2: *mut __CLPK_doublereal, __wi: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                  __wr1: *mut __CLPK_doublereal, __wr2: *mut __CLPK_doublereal,
+                  __wi: *mut __CLPK_doublereal);
     pub fn dlag2s_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __sa: *mut __CLPK_real, __ldsa: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlags2_(__upper: *mut __CLPK_logical, __a1: *mut __CLPK_doublereal,
                    __a2: *mut __CLPK_doublereal, __a3: *mut __CLPK_doublereal,
                    __b1: *mut __CLPK_doublereal, __b2: *mut __CLPK_doublereal,
-                   __b3: *mut __CLPK_doublereal,
-                   __csu: *mut __CLPK_doublereal,
+                   __b3: *mut __CLPK_doublereal, __csu: *mut __CLPK_doublereal,
                    __snu: *mut __CLPK_doublereal,
                    __csv: *mut __CLPK_doublereal,
                    __snv: *mut __CLPK_doublereal,
-                   __csq: *mut __CLPK_doublereal,
-                   __snq: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __csq: *mut __CLPK_doublereal, __snq: *mut __CLPK_doublereal);
     pub fn dlagtf_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lambda: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __c__: *mut __CLPK_doublereal,
                    __tol: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __in: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlagtm_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublereal,
-                   __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
+                   __dl: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
                    __du: *mut __CLPK_doublereal, __x: *mut __CLPK_doublereal,
                    __ldx: *mut __CLPK_integer, __beta: *mut __CLPK_doublereal,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dlagts_(__job: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __in: *mut __CLPK_integer,
                    __y: *mut __CLPK_doublereal, __tol: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlagv2_(__a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __alphar: *mut __CLPK_doublereal,
@@ -3121,36 +3097,30 @@ extern "C" {
                    __beta: *mut __CLPK_doublereal,
                    __csl: *mut __CLPK_doublereal,
                    __snl: *mut __CLPK_doublereal,
-                   __csr: *mut __CLPK_doublereal,
-                   __snr: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __csr: *mut __CLPK_doublereal, __snr: *mut __CLPK_doublereal);
     pub fn dlahqr_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
                    __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
                    __wi: *mut __CLPK_doublereal, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlahr2_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublereal, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_doublereal, __ldy: *mut __CLPK_integer);
     pub fn dlahrd_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublereal, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_doublereal, __ldy: *mut __CLPK_integer);
     pub fn dlaic1_(__job: *mut __CLPK_integer, __j: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublereal,
-                   __sest: *mut __CLPK_doublereal,
+                   __x: *mut __CLPK_doublereal, __sest: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal,
                    __gamma: *mut __CLPK_doublereal,
                    __sestpr: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublereal, __c__: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_doublereal, __c__: *mut __CLPK_doublereal);
     pub fn dlaisnan_(__din1: *mut __CLPK_doublereal,
                      __din2: *mut __CLPK_doublereal) -> __CLPK_logical;
     pub fn dlaln2_(__ltrans: *mut __CLPK_logical, __na: *mut __CLPK_integer,
@@ -3160,10 +3130,8 @@ extern "C" {
                    __d2: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __wr: *mut __CLPK_doublereal,
                    __wi: *mut __CLPK_doublereal, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
-                   __xnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldx: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
+                   __xnorm: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlals0_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
@@ -3179,8 +3147,7 @@ extern "C" {
                    __difr: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlalsa_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
@@ -3197,73 +3164,80 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlalsd_(__uplo: *mut ::libc::c_char, __smlsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
-                   __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rank: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dlamrg_(__n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __dtrd1: *mut __CLPK_integer,
-                   __dtrd2: *mut __CLPK_integer, __index: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __dtrd2: *mut __CLPK_integer, __index: *mut __CLPK_integer);
     pub fn dlaneg_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __lld: *mut __CLPK_doublereal,
                    __sigma: *mut __CLPK_doublereal,
-                   __pivmin: *mut __CLPK_doublereal,
-                   __r__: *mut __CLPK_integer) -> __CLPK_integer;
+                   __pivmin: *mut __CLPK_doublereal, __r__: *mut __CLPK_integer)
+     -> __CLPK_integer;
     pub fn dlangb_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn dlange_(__norm: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlangt_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __dl: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __du: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __dl: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __du: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn dlanhs_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn dlansb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlansf_(__norm: *mut ::libc::c_char, __transr: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __a: *mut __CLPK_doublereal, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlansp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlanst_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal)
-     -> __CLPK_doublereal;
+                   __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlansy_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
      -> __CLPK_doublereal;
     pub fn dlantb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
-                   __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlantp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn dlantr_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn dlanv2_(__a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal,
@@ -3271,16 +3245,13 @@ extern "C" {
                    __rt1i: *mut __CLPK_doublereal,
                    __rt2r: *mut __CLPK_doublereal,
                    __rt2i: *mut __CLPK_doublereal,
-                   __cs: *mut __CLPK_doublereal, __sn: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __cs: *mut __CLPK_doublereal, __sn: *mut __CLPK_doublereal);
     pub fn dlapll_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublereal,
-                   __incy: *mut __CLPK_integer,
-                   __ssmin: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_doublereal);
     pub fn dlapmt_(__forwrd: *mut __CLPK_logical, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer);
     pub fn dlapy2_(__x: *mut __CLPK_doublereal, __y: *mut __CLPK_doublereal)
      -> __CLPK_doublereal;
     pub fn dlapy3_(__x: *mut __CLPK_doublereal, __y: *mut __CLPK_doublereal,
@@ -3293,7 +3264,7 @@ extern "C" {
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __equed_len: __CLPK_ftnlen);
     pub fn dlaqge_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_doublereal,
@@ -3301,14 +3272,14 @@ extern "C" {
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __equed_len: __CLPK_ftnlen);
     pub fn dlaqp2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __vn1: *mut __CLPK_doublereal,
                    __vn2: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal);
     pub fn dlaqps_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __nb: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
@@ -3316,9 +3287,8 @@ extern "C" {
                    __tau: *mut __CLPK_doublereal,
                    __vn1: *mut __CLPK_doublereal,
                    __vn2: *mut __CLPK_doublereal,
-                   __auxv: *mut __CLPK_doublereal,
-                   __f: *mut __CLPK_doublereal, __ldf: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __auxv: *mut __CLPK_doublereal, __f: *mut __CLPK_doublereal,
+                   __ldf: *mut __CLPK_integer);
     pub fn dlaqr0_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
@@ -3326,14 +3296,12 @@ extern "C" {
                    __wi: *mut __CLPK_doublereal, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaqr1_(__n: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
                    __ldh: *mut __CLPK_integer, __sr1: *mut __CLPK_doublereal,
                    __si1: *mut __CLPK_doublereal,
                    __sr2: *mut __CLPK_doublereal,
-                   __si2: *mut __CLPK_doublereal, __v: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __si2: *mut __CLPK_doublereal, __v: *mut __CLPK_doublereal);
     pub fn dlaqr2_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -3346,8 +3314,7 @@ extern "C" {
                    __nh: *mut __CLPK_integer, __t: *mut __CLPK_doublereal,
                    __ldt: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_doublereal, __ldwv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer);
     pub fn dlaqr3_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -3360,8 +3327,7 @@ extern "C" {
                    __nh: *mut __CLPK_integer, __t: *mut __CLPK_doublereal,
                    __ldt: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_doublereal, __ldwv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer);
     pub fn dlaqr4_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_doublereal,
@@ -3369,54 +3335,52 @@ extern "C" {
                    __wi: *mut __CLPK_doublereal, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlaqr5_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __kacc22: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ktop: *mut __CLPK_integer, __kbot: *mut __CLPK_integer,
-                   __nshfts: *mut __CLPK_integer,
-                   __sr: *mut __CLPK_doublereal, __si: *mut __CLPK_doublereal,
-                   __h__: *mut __CLPK_doublereal, __ldh: *mut __CLPK_integer,
-                   __iloz: *mut __CLPK_integer, __ihiz: *mut __CLPK_integer,
-                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __v: *mut __CLPK_doublereal, __ldv: *mut __CLPK_integer,
-                   __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
-                   __nv: *mut __CLPK_integer, __wv: *mut __CLPK_doublereal,
-                   __ldwv: *mut __CLPK_integer, __nh: *mut __CLPK_integer,
-                   __wh: *mut __CLPK_doublereal, __ldwh: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __nshfts: *mut __CLPK_integer, __sr: *mut __CLPK_doublereal,
+                   __si: *mut __CLPK_doublereal, __h__: *mut __CLPK_doublereal,
+                   __ldh: *mut __CLPK_integer, __iloz: *mut __CLPK_integer,
+                   __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
+                   __ldz: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
+                   __ldv: *mut __CLPK_integer, __u: *mut __CLPK_doublereal,
+                   __ldu: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
+                   __wv: *mut __CLPK_doublereal, __ldwv: *mut __CLPK_integer,
+                   __nh: *mut __CLPK_integer, __wh: *mut __CLPK_doublereal,
+                   __ldwh: *mut __CLPK_integer);
     pub fn dlaqsb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dlaqsp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dlaqsy_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dlaqtr_(__ltran: *mut __CLPK_logical,
                    __l__CLPK_real: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __t: *mut __CLPK_doublereal,
                    __ldt: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal,
                    __scale: *mut __CLPK_doublereal,
-                   __x: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __x: *mut __CLPK_doublereal, __work: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer);
     pub fn dlar1v_(__n: *mut __CLPK_integer, __b1: *mut __CLPK_integer,
-                   __bn: *mut __CLPK_integer,
-                   __lambda: *mut __CLPK_doublereal,
+                   __bn: *mut __CLPK_integer, __lambda: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __l: *mut __CLPK_doublereal,
-                   __ld: *mut __CLPK_doublereal,
-                   __lld: *mut __CLPK_doublereal,
+                   __ld: *mut __CLPK_doublereal, __lld: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __gaptol: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
@@ -3428,17 +3392,16 @@ extern "C" {
                    __nrminv: *mut __CLPK_doublereal,
                    __resid: *mut __CLPK_doublereal,
                    __rqcorr: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal);
     pub fn dlar2v_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __y: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __incx: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublereal, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_doublereal, __incc: *mut __CLPK_integer);
     pub fn dlarf_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                   __incv: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                   __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __side_len: __CLPK_ftnlen);
     pub fn dlarfb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -3447,42 +3410,41 @@ extern "C" {
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn dlarfg_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal);
     pub fn dlarfp_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal);
     pub fn dlarft_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn dlarfx_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __side_len: __CLPK_ftnlen);
     pub fn dlargv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublereal,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incc: *mut __CLPK_integer);
     pub fn dlarnv_(__idist: *mut __CLPK_integer, __iseed: *mut __CLPK_integer,
-                   __n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal);
     pub fn dlarra_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __e2: *mut __CLPK_doublereal,
                    __spltol: *mut __CLPK_doublereal,
                    __tnrm: *mut __CLPK_doublereal,
                    __nsplit: *mut __CLPK_integer,
-                   __isplit: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __isplit: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlarrb_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __lld: *mut __CLPK_doublereal,
-                   __ifirst: *mut __CLPK_integer,
-                   __ilast: *mut __CLPK_integer,
+                   __ifirst: *mut __CLPK_integer, __ilast: *mut __CLPK_integer,
                    __rtol1: *mut __CLPK_doublereal,
                    __rtol2: *mut __CLPK_doublereal,
                    __offset: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
@@ -3492,15 +3454,14 @@ extern "C" {
                    __iwork: *mut __CLPK_integer,
                    __pivmin: *mut __CLPK_doublereal,
                    __spdiam: *mut __CLPK_doublereal,
-                   __twist: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __twist: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlarrc_(__jobt: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __vl: *mut __CLPK_doublereal, __vu: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __eigcnt: *mut __CLPK_integer, __lcnt: *mut __CLPK_integer,
-                   __rcnt: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __rcnt: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobt_len: __CLPK_ftnlen);
     pub fn dlarrd_(__range: *mut ::libc::c_char, __order: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
@@ -3511,14 +3472,13 @@ extern "C" {
                    __pivmin: *mut __CLPK_doublereal,
                    __nsplit: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
-                   __werr: *mut __CLPK_doublereal,
+                   __w: *mut __CLPK_doublereal, __werr: *mut __CLPK_doublereal,
                    __wl: *mut __CLPK_doublereal, __wu: *mut __CLPK_doublereal,
                    __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen, __order_len: __CLPK_ftnlen);
     pub fn dlarre_(__range: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __vl: *mut __CLPK_doublereal, __vu: *mut __CLPK_doublereal,
                    __il: *mut __CLPK_integer, __iu: *mut __CLPK_integer,
@@ -3529,21 +3489,19 @@ extern "C" {
                    __spltol: *mut __CLPK_doublereal,
                    __nsplit: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
-                   __werr: *mut __CLPK_doublereal,
+                   __w: *mut __CLPK_doublereal, __werr: *mut __CLPK_doublereal,
                    __wgap: *mut __CLPK_doublereal,
                    __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer,
                    __gers: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen);
     pub fn dlarrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __l: *mut __CLPK_doublereal, __ld: *mut __CLPK_doublereal,
-                   __clstrt: *mut __CLPK_integer,
-                   __clend: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                   __wgap: *mut __CLPK_doublereal,
+                   __clstrt: *mut __CLPK_integer, __clend: *mut __CLPK_integer,
+                   __w: *mut __CLPK_doublereal, __wgap: *mut __CLPK_doublereal,
                    __werr: *mut __CLPK_doublereal,
                    __spdiam: *mut __CLPK_doublereal,
                    __clgapl: *mut __CLPK_doublereal,
@@ -3552,11 +3510,9 @@ extern "C" {
                    __sigma: *mut __CLPK_doublereal,
                    __dplus: *mut __CLPK_doublereal,
                    __lplus: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlarrj_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e2: *mut __CLPK_doublereal,
-                   __ifirst: *mut __CLPK_integer,
+                   __e2: *mut __CLPK_doublereal, __ifirst: *mut __CLPK_integer,
                    __ilast: *mut __CLPK_integer,
                    __rtol: *mut __CLPK_doublereal,
                    __offset: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
@@ -3565,30 +3521,26 @@ extern "C" {
                    __iwork: *mut __CLPK_integer,
                    __pivmin: *mut __CLPK_doublereal,
                    __spdiam: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlarrk_(__n: *mut __CLPK_integer, __iw: *mut __CLPK_integer,
                    __gl: *mut __CLPK_doublereal, __gu: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal,
-                   __e2: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __e2: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __reltol: *mut __CLPK_doublereal,
-                   __w: *mut __CLPK_doublereal,
-                   __werr: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __w: *mut __CLPK_doublereal, __werr: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer);
     pub fn dlarrr_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlarrv_(__n: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
-                   __vu: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal, __l: *mut __CLPK_doublereal,
+                   __vu: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __l: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __isplit: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __dol: *mut __CLPK_integer, __dou: *mut __CLPK_integer,
                    __minrgp: *mut __CLPK_doublereal,
                    __rtol1: *mut __CLPK_doublereal,
                    __rtol2: *mut __CLPK_doublereal,
-                   __w: *mut __CLPK_doublereal,
-                   __werr: *mut __CLPK_doublereal,
+                   __w: *mut __CLPK_doublereal, __werr: *mut __CLPK_doublereal,
                    __wgap: *mut __CLPK_doublereal,
                    __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer,
@@ -3596,28 +3548,25 @@ extern "C" {
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlarscl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                      __d__: *mut __CLPK_doublereal,
-                     __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                     __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer);
     pub fn dlartg_(__f: *mut __CLPK_doublereal, __g: *mut __CLPK_doublereal,
                    __cs: *mut __CLPK_doublereal, __sn: *mut __CLPK_doublereal,
-                   __r__: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __r__: *mut __CLPK_doublereal);
     pub fn dlartv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublereal,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublereal, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_doublereal, __incc: *mut __CLPK_integer);
     pub fn dlaruv_(__iseed: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __x: *mut __CLPK_doublereal);
     pub fn dlarz_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                   __v: *mut __CLPK_doublereal, __incv: *mut __CLPK_integer,
-                  __tau: *mut __CLPK_doublereal,
-                  __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                  __tau: *mut __CLPK_doublereal, __c__: *mut __CLPK_doublereal,
+                  __ldc: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                  __side_len: __CLPK_ftnlen);
     pub fn dlarzb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -3626,59 +3575,56 @@ extern "C" {
                    __ldv: *mut __CLPK_integer, __t: *mut __CLPK_doublereal,
                    __ldt: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn dlarzt_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn dlas2_(__f: *mut __CLPK_doublereal, __g: *mut __CLPK_doublereal,
                   __h__: *mut __CLPK_doublereal,
                   __ssmin: *mut __CLPK_doublereal,
-                  __ssmax: *mut __CLPK_doublereal) -> ::libc::c_int;
+                  __ssmax: *mut __CLPK_doublereal);
     pub fn dlascl_(__type__: *mut ::libc::c_char, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __cfrom: *mut __CLPK_doublereal,
                    __cto: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __type_len: __CLPK_ftnlen);
     pub fn dlascl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                    __d__: *mut __CLPK_doublereal,
-                    __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                    __d__: *mut __CLPK_doublereal, __x: *mut __CLPK_doublereal,
+                    __ldx: *mut __CLPK_integer);
     pub fn dlasd0_(__n: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
                    __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
-                   __smlsiz: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __smlsiz: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasd1_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
-                   __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
-                   __idxq: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __beta: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
+                   __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublereal,
+                   __ldvt: *mut __CLPK_integer, __idxq: *mut __CLPK_integer,
+                   __iwork: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasd2_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
-                   __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
+                   __beta: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
+                   __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublereal,
+                   __ldvt: *mut __CLPK_integer,
                    __dsigma: *mut __CLPK_doublereal,
                    __u2: *mut __CLPK_doublereal, __ldu2: *mut __CLPK_integer,
-                   __vt2: *mut __CLPK_doublereal,
-                   __ldvt2: *mut __CLPK_integer, __idxp: *mut __CLPK_integer,
-                   __idx: *mut __CLPK_integer, __idxc: *mut __CLPK_integer,
-                   __idxq: *mut __CLPK_integer, __coltyp: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __vt2: *mut __CLPK_doublereal, __ldvt2: *mut __CLPK_integer,
+                   __idxp: *mut __CLPK_integer, __idx: *mut __CLPK_integer,
+                   __idxc: *mut __CLPK_integer, __idxq: *mut __CLPK_integer,
+                   __coltyp: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlasd3_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
@@ -3687,32 +3633,29 @@ extern "C" {
                    __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
                    __u2: *mut __CLPK_doublereal, __ldu2: *mut __CLPK_integer,
                    __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
-                   __vt2: *mut __CLPK_doublereal,
-                   __ldvt2: *mut __CLPK_integer, __idxc: *mut __CLPK_integer,
-                   __ctot: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __vt2: *mut __CLPK_doublereal, __ldvt2: *mut __CLPK_integer,
+                   __idxc: *mut __CLPK_integer, __ctot: *mut __CLPK_integer,
+                   __z__: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasd4_(__n: *mut __CLPK_integer, __i__: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __delta: *mut __CLPK_doublereal,
                    __rho: *mut __CLPK_doublereal,
                    __sigma: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasd5_(__i__: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal,
                    __delta: *mut __CLPK_doublereal,
                    __rho: *mut __CLPK_doublereal,
                    __dsigma: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal);
     pub fn dlasd6_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_doublereal,
-                   __vf: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                   __d__: *mut __CLPK_doublereal, __vf: *mut __CLPK_doublereal,
+                   __vl: *mut __CLPK_doublereal,
                    __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __idxq: *mut __CLPK_integer, __perm: *mut __CLPK_integer,
-                   __givptr: *mut __CLPK_integer,
+                   __beta: *mut __CLPK_doublereal, __idxq: *mut __CLPK_integer,
+                   __perm: *mut __CLPK_integer, __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer,
                    __ldgcol: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_doublereal,
@@ -3723,16 +3666,13 @@ extern "C" {
                    __z__: *mut __CLPK_doublereal, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlasd7_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __z__: *mut __CLPK_doublereal,
-                   __zw: *mut __CLPK_doublereal, __vf: *mut __CLPK_doublereal,
-                   __vfw: *mut __CLPK_doublereal,
-                   __vl: *mut __CLPK_doublereal,
-                   __vlw: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __zw: *mut __CLPK_doublereal,
+                   __vf: *mut __CLPK_doublereal, __vfw: *mut __CLPK_doublereal,
+                   __vl: *mut __CLPK_doublereal, __vlw: *mut __CLPK_doublereal,
                    __alpha: *mut __CLPK_doublereal,
                    __beta: *mut __CLPK_doublereal,
                    __dsigma: *mut __CLPK_doublereal,
@@ -3744,17 +3684,16 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __ldgnum: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlasd8_(__icompq: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
-                   __z__: *mut __CLPK_doublereal,
-                   __vf: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __vf: *mut __CLPK_doublereal,
+                   __vl: *mut __CLPK_doublereal,
                    __difl: *mut __CLPK_doublereal,
                    __difr: *mut __CLPK_doublereal,
                    __lddifr: *mut __CLPK_integer,
                    __dsigma: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasda_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
@@ -3770,8 +3709,7 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dlasdq_(__uplo: *mut ::libc::c_char, __sqre: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncvt: *mut __CLPK_integer,
                    __nru: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
@@ -3779,23 +3717,21 @@ extern "C" {
                    __vt: *mut __CLPK_doublereal, __ldvt: *mut __CLPK_integer,
                    __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dlasdt_(__n: *mut __CLPK_integer, __lvl: *mut __CLPK_integer,
                    __nd: *mut __CLPK_integer, __inode: *mut __CLPK_integer,
                    __ndiml: *mut __CLPK_integer, __ndimr: *mut __CLPK_integer,
-                   __msub: *mut __CLPK_integer) -> ::libc::c_int;
+                   __msub: *mut __CLPK_integer);
     pub fn dlaset_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __beta: *mut __CLPK_doublereal, __a: *mut __CLPK_doublereal,
+                   __lda: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dlasq1_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __e: *mut __CLPK_doublereal, __work: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer);
     pub fn dlasq2_(__n: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dlasq3_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal, __pp: *mut __CLPK_integer,
                    __dmin__: *mut __CLPK_doublereal,
@@ -3807,22 +3743,19 @@ extern "C" {
                    __ttype: *mut __CLPK_integer,
                    __dmin1: *mut __CLPK_doublereal,
                    __dmin2: *mut __CLPK_doublereal,
-                   __dn: *mut __CLPK_doublereal,
-                   __dn1: *mut __CLPK_doublereal,
+                   __dn: *mut __CLPK_doublereal, __dn1: *mut __CLPK_doublereal,
                    __dn2: *mut __CLPK_doublereal, __g: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal);
     pub fn dlasq4_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal, __pp: *mut __CLPK_integer,
                    __n0in: *mut __CLPK_integer,
                    __dmin__: *mut __CLPK_doublereal,
                    __dmin1: *mut __CLPK_doublereal,
                    __dmin2: *mut __CLPK_doublereal,
-                   __dn: *mut __CLPK_doublereal,
-                   __dn1: *mut __CLPK_doublereal,
+                   __dn: *mut __CLPK_doublereal, __dn1: *mut __CLPK_doublereal,
                    __dn2: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal,
-                   __ttype: *mut __CLPK_integer, __g: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal, __ttype: *mut __CLPK_integer,
+                   __g: *mut __CLPK_doublereal);
     pub fn dlasq5_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal, __pp: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
@@ -3831,8 +3764,7 @@ extern "C" {
                    __dmin2: *mut __CLPK_doublereal,
                    __dn: *mut __CLPK_doublereal,
                    __dnm1: *mut __CLPK_doublereal,
-                   __dnm2: *mut __CLPK_doublereal,
-                   __ieee: *mut __CLPK_logical) -> ::libc::c_int;
+                   __dnm2: *mut __CLPK_doublereal, __ieee: *mut __CLPK_logical);
     pub fn dlasq6_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal, __pp: *mut __CLPK_integer,
                    __dmin__: *mut __CLPK_doublereal,
@@ -3840,31 +3772,31 @@ extern "C" {
                    __dmin2: *mut __CLPK_doublereal,
                    __dn: *mut __CLPK_doublereal,
                    __dnm1: *mut __CLPK_doublereal,
-                   __dnm2: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __dnm2: *mut __CLPK_doublereal);
     pub fn dlasr_(__side: *mut ::libc::c_char, __pivot: *mut ::libc::c_char,
                   __direct: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
                   __s: *mut __CLPK_doublereal, __a: *mut __CLPK_doublereal,
-                  __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                  __lda: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                  __pivot_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen);
     pub fn dlasrt_(__id: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __d__: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __id_len: __CLPK_ftnlen);
     pub fn dlassq_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __incx: *mut __CLPK_integer,
                    __scale: *mut __CLPK_doublereal,
-                   __sumsq: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __sumsq: *mut __CLPK_doublereal);
     pub fn dlasv2_(__f: *mut __CLPK_doublereal, __g: *mut __CLPK_doublereal,
                    __h__: *mut __CLPK_doublereal,
                    __ssmin: *mut __CLPK_doublereal,
                    __ssmax: *mut __CLPK_doublereal,
                    __snr: *mut __CLPK_doublereal,
                    __csr: *mut __CLPK_doublereal,
-                   __snl: *mut __CLPK_doublereal,
-                   __csl: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __snl: *mut __CLPK_doublereal, __csl: *mut __CLPK_doublereal);
     pub fn dlaswp_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __k1: *mut __CLPK_integer,
                    __k2: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn dlasy2_(__ltranl: *mut __CLPK_logical,
                    __ltranr: *mut __CLPK_logical, __isgn: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
@@ -3873,18 +3805,17 @@ extern "C" {
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __scale: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
-                   __xnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __xnorm: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dlasyf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dlat2s_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __sa: *mut __CLPK_real, __ldsa: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dlatbs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
@@ -3892,138 +3823,135 @@ extern "C" {
                    __x: *mut __CLPK_doublereal,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn dlatdf_(__ijob: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __rhs: *mut __CLPK_doublereal,
                    __rdsum: *mut __CLPK_doublereal,
                    __rdscal: *mut __CLPK_doublereal,
-                   __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer);
     pub fn dlatps_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __x: *mut __CLPK_doublereal,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn dlatrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublereal, __w: *mut __CLPK_doublereal,
-                   __ldw: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dlatrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn dlatrz_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __l: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal);
     pub fn dlatzm_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
                    __incv: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c1: *mut __CLPK_doublereal, __c2: *mut __CLPK_doublereal,
-                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
-     -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __side_len: __CLPK_ftnlen);
     pub fn dlauu2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dlauum_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dopgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
-                   __ldq: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __tau: *mut __CLPK_doublereal,
+                   __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dopmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dorg2l_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dorg2r_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dorgbr_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn dorghr_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dorgl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dorglq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dorgql_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dorgqr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dorgr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dorgrq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dorgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dorm2l_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dorm2r_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormbr_(__vect: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
@@ -4031,8 +3959,9 @@ extern "C" {
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dormhr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -4040,62 +3969,62 @@ extern "C" {
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dorml2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormlq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormql_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormqr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormr2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormr3_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormrq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormrz_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
@@ -4103,50 +4032,51 @@ extern "C" {
                    __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dormtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn dpbcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
-                   __afb: *mut __CLPK_doublereal,
-                   __ldafb: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
+                   __afb: *mut __CLPK_doublereal, __ldafb: *mut __CLPK_integer,
+                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
+                   __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
+                   __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbstf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpbsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
@@ -4154,54 +4084,54 @@ extern "C" {
                    __ldafb: *mut __CLPK_integer, __equed: *mut ::libc::c_char,
                    __s: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dpbtf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbtrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpbtrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpftrf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpftrs_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dpocon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpoequ_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dpoequb_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                     __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                     __scond: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dporfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublereal,
@@ -4210,13 +4140,13 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dposvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4228,34 +4158,35 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dpotf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpotrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpotri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpotrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dppcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dppequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __afp: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
@@ -4263,16 +4194,15 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dppsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dppsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal,
-                   __afp: *mut __CLPK_doublereal,
+                   __ap: *mut __CLPK_doublereal, __afp: *mut __CLPK_doublereal,
                    __equed: *mut ::libc::c_char, __s: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
@@ -4280,41 +4210,41 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn dpptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dpstf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dpstrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dptcon_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dpteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn dptrfs_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __df: *mut __CLPK_doublereal, __ef: *mut __CLPK_doublereal,
@@ -4322,78 +4252,76 @@ extern "C" {
                    __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dptsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer);
     pub fn dptsvx_(__fact: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __df: *mut __CLPK_doublereal,
                    __ef: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen);
     pub fn dpttrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dpttrs_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dptts2_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer);
     pub fn drscl_(__n: *mut __CLPK_integer, __sa: *mut __CLPK_doublereal,
-                  __sx: *mut __CLPK_doublereal, __incx: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __sx: *mut __CLPK_doublereal, __incx: *mut __CLPK_integer);
     pub fn dsbev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                   __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dsbevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                    __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsbevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsbgst_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_doublereal,
                    __ldbb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __ldx: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsbgv_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                   __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                   __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_doublereal,
                   __ldbb: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsbgvd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
@@ -4402,8 +4330,8 @@ extern "C" {
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsbgvx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ka: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
@@ -4416,92 +4344,98 @@ extern "C" {
                    __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsbtrd_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsfrk_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __k: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                   __beta: *mut __CLPK_doublereal,
-                  __c__: *mut __CLPK_doublereal) -> ::libc::c_int;
+                  __c__: *mut __CLPK_doublereal, __transr_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dsgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __ldx: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __swork: *mut __CLPK_real, __iter: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dspcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dspev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dspevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dspevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dspgst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
-                   __bp: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bp: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dspgv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __ap: *mut __CLPK_doublereal, __bp: *mut __CLPK_doublereal,
                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dspgvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __bp: *mut __CLPK_doublereal,
                    __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dspgvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __bp: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
                    __ldx: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __swork: *mut __CLPK_real, __iter: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __afp: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
@@ -4510,73 +4444,70 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dspsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dspsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal,
-                   __afp: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublereal, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ap: *mut __CLPK_doublereal, __afp: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsptrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __e: *mut __CLPK_doublereal, __tau: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dstebz_(__range: *mut ::libc::c_char, __order: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __m: *mut __CLPK_integer, __nsplit: *mut __CLPK_integer,
                    __w: *mut __CLPK_doublereal, __iblock: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen, __order_len: __CLPK_ftnlen);
     pub fn dstedc_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn dstegr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn dstein_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __w: *mut __CLPK_doublereal, __iblock: *mut __CLPK_integer,
@@ -4584,7 +4515,7 @@ extern "C" {
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn dstemr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
@@ -4596,78 +4527,78 @@ extern "C" {
                    __tryrac: *mut __CLPK_logical,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn dsteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn dsterf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dstev_(__jobz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen);
     pub fn dstevd_(__jobz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen);
     pub fn dstevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn dstevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen);
     pub fn dsycon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsyequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                     __s: *mut __CLPK_doublereal,
                     __scond: *mut __CLPK_doublereal,
                     __amax: *mut __CLPK_doublereal,
                     __work: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsyev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn dsyevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsyevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4678,8 +4609,9 @@ extern "C" {
                    __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsyevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4689,47 +4621,48 @@ extern "C" {
                    __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsygs2_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsygst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsygv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                   __w: *mut __CLPK_doublereal, __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsygvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
+                   __w: *mut __CLPK_doublereal, __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dsygvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal, __z__: *mut __CLPK_doublereal,
-                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
+                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsyrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublereal,
@@ -4739,68 +4672,66 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsysv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublereal,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_doublereal, __lwork: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsysvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __af: *mut __CLPK_doublereal, __ldaf: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublereal,
-                   __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsytd2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
-                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsytf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsytrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsytrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dsytri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dsytrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn dtbcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublereal,
                    __ldab: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtbrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -4810,32 +4741,38 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtbtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublereal, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn dtfsm_(__transr: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                   __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
                   __a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal,
-                  __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                  __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn dtftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __a: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtfttp_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_doublereal,
-                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dtfttr_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_doublereal,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dtgevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal, __lds: *mut __CLPK_integer,
@@ -4843,8 +4780,8 @@ extern "C" {
                    __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn dtgex2_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
@@ -4853,35 +4790,30 @@ extern "C" {
                    __ldz: *mut __CLPK_integer, __j1: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dtgexc_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
                    __ldz: *mut __CLPK_integer, __ifst: *mut __CLPK_integer,
-                   __ilst: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ilst: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dtgsen_(__ijob: *mut __CLPK_integer, __wantq: *mut __CLPK_logical,
-                   __wantz: *mut __CLPK_logical,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
+                   __wantz: *mut __CLPK_logical, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
+                   __ldb: *mut __CLPK_integer,
                    __alphar: *mut __CLPK_doublereal,
                    __alphai: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __z__: *mut __CLPK_doublereal, __ldz: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer, __pl: *mut __CLPK_doublereal,
-                   __pr: *mut __CLPK_doublereal,
+                   __beta: *mut __CLPK_doublereal, __q: *mut __CLPK_doublereal,
+                   __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_doublereal,
+                   __ldz: *mut __CLPK_integer, __m: *mut __CLPK_integer,
+                   __pl: *mut __CLPK_doublereal, __pr: *mut __CLPK_doublereal,
                    __dif: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dtgsja_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -4891,13 +4823,13 @@ extern "C" {
                    __tola: *mut __CLPK_doublereal,
                    __tolb: *mut __CLPK_doublereal,
                    __alpha: *mut __CLPK_doublereal,
-                   __beta: *mut __CLPK_doublereal,
-                   __u: *mut __CLPK_doublereal, __ldu: *mut __CLPK_integer,
-                   __v: *mut __CLPK_doublereal, __ldv: *mut __CLPK_integer,
-                   __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __ncycle: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __beta: *mut __CLPK_doublereal, __u: *mut __CLPK_doublereal,
+                   __ldu: *mut __CLPK_integer, __v: *mut __CLPK_doublereal,
+                   __ldv: *mut __CLPK_integer, __q: *mut __CLPK_doublereal,
+                   __ldq: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __ncycle: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn dtgsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4908,7 +4840,8 @@ extern "C" {
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn dtgsy2_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4921,7 +4854,7 @@ extern "C" {
                    __rdsum: *mut __CLPK_doublereal,
                    __rdscal: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __pq: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dtgsyl_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
@@ -4934,14 +4867,15 @@ extern "C" {
                    __dif: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn dtpcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtprfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
@@ -4950,45 +4884,49 @@ extern "C" {
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtptri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtptrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn dtpttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublereal,
-                   __arf: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __arf: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn dtpttr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublereal, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dtrcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtrevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
                    __vl: *mut __CLPK_doublereal, __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublereal, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn dtrexc_(__compq: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_doublereal, __ldq: *mut __CLPK_integer,
                    __ifst: *mut __CLPK_integer, __ilst: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen);
     pub fn dtrrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
@@ -4997,8 +4935,9 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtrsen_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
@@ -5008,8 +4947,8 @@ extern "C" {
                    __sep: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen);
     pub fn dtrsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublereal, __ldt: *mut __CLPK_integer,
@@ -5018,49 +4957,49 @@ extern "C" {
                    __s: *mut __CLPK_doublereal, __sep: *mut __CLPK_doublereal,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __ldwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn dtrsyl_(__trana: *mut ::libc::c_char, __tranb: *mut ::libc::c_char,
                    __isgn: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __ldc: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __trana_len: __CLPK_ftnlen,
+                   __tranb_len: __CLPK_ftnlen);
     pub fn dtrti2_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn dtrtri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn dtrtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublereal,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn dtrttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __arf: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dtrttp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn dtzrqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn dtzrzf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublereal,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn dzsum1_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_doublecomplex,
                    __incx: *mut __CLPK_integer) -> __CLPK_doublereal;
     pub fn icmax1_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_complex,
@@ -5073,45 +5012,50 @@ extern "C" {
     pub fn ilaclr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_complex, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
-    pub fn iladiag_(__diag: *mut ::libc::c_char) -> __CLPK_integer;
+    pub fn iladiag_(__diag: *mut ::libc::c_char, __diag_len: __CLPK_ftnlen)
+     -> __CLPK_integer;
     pub fn iladlc_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
     pub fn iladlr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
-    pub fn ilaenv_(__ispec: *mut __CLPK_integer,
-                   __name__: *mut ::libc::c_char, __opts: *mut ::libc::c_char,
-                   __n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
-                   __n3: *mut __CLPK_integer, __n4: *mut __CLPK_integer)
+    pub fn ilaenv_(__ispec: *mut __CLPK_integer, __name__: *mut ::libc::c_char,
+                   __opts: *mut ::libc::c_char, __n1: *mut __CLPK_integer,
+                   __n2: *mut __CLPK_integer, __n3: *mut __CLPK_integer,
+                   __n4: *mut __CLPK_integer, __name_len: __CLPK_ftnlen,
+                   __opts_len: __CLPK_ftnlen) -> __CLPK_integer;
+    pub fn ilaprec_(__prec: *mut ::libc::c_char, __prec_len: __CLPK_ftnlen)
      -> __CLPK_integer;
-    pub fn ilaprec_(__prec: *mut ::libc::c_char) -> __CLPK_integer;
     pub fn ilaslc_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
     pub fn ilaslr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
-    pub fn ilatrans_(__trans: *mut ::libc::c_char) -> __CLPK_integer;
-    pub fn ilauplo_(__uplo: *mut ::libc::c_char) -> __CLPK_integer;
+    pub fn ilatrans_(__trans: *mut ::libc::c_char, __trans_len: __CLPK_ftnlen)
+     -> __CLPK_integer;
+    pub fn ilauplo_(__uplo: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_integer;
     pub fn ilaver_(__vers_major__: *mut __CLPK_integer,
                    __vers_minor__: *mut __CLPK_integer,
-                   __vers_patch__: *mut __CLPK_integer) -> ::libc::c_int;
+                   __vers_patch__: *mut __CLPK_integer);
     pub fn ilazlc_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
     pub fn ilazlr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer)
      -> __CLPK_integer;
-    pub fn iparmq_(__ispec: *mut __CLPK_integer,
-                   __name__: *mut ::libc::c_char, __opts: *mut ::libc::c_char,
-                   __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer, __lwork: *mut __CLPK_integer)
-     -> __CLPK_integer;
+    pub fn iparmq_(__ispec: *mut __CLPK_integer, __name__: *mut ::libc::c_char,
+                   __opts: *mut ::libc::c_char, __n: *mut __CLPK_integer,
+                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
+                   __lwork: *mut __CLPK_integer, __name_len: __CLPK_ftnlen,
+                   __opts_len: __CLPK_ftnlen) -> __CLPK_integer;
     pub fn izmax1_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_doublecomplex,
                    __incx: *mut __CLPK_integer) -> __CLPK_integer;
     pub fn lsamen_(__n: *mut __CLPK_integer, __ca: *mut ::libc::c_char,
-                   __cb: *mut ::libc::c_char) -> __CLPK_logical;
+                   __cb: *mut ::libc::c_char, __ca_len: __CLPK_ftnlen,
+                   __cb_len: __CLPK_ftnlen) -> __CLPK_logical;
     pub fn smaxloc_(__a: *mut __CLPK_real, __dimm: *mut __CLPK_integer)
      -> __CLPK_integer;
     pub fn sbdsdc_(__uplo: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
@@ -5120,8 +5064,8 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_real,
                    __ldvt: *mut __CLPK_integer, __q: *mut __CLPK_real,
                    __iq: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen);
     pub fn sbdsqr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ncvt: *mut __CLPK_integer, __nru: *mut __CLPK_integer,
                    __ncc: *mut __CLPK_integer, __d__: *mut __CLPK_real,
@@ -5129,13 +5073,13 @@ extern "C" {
                    __ldvt: *mut __CLPK_integer, __u: *mut __CLPK_real,
                    __ldu: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn scsum1_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_complex,
-                   __incx: *mut __CLPK_integer) -> __CLPK_doublereal;
+                   __incx: *mut __CLPK_integer) -> __CLPK_real_return;
     pub fn sdisna_(__job: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
-                   __sep: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __sep: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
     pub fn sgbbrd_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
@@ -5144,29 +5088,27 @@ extern "C" {
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __pt: *mut __CLPK_real, __ldpt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn sgbcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn sgbequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgbequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                     __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                     __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                     __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgbrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_real,
@@ -5176,13 +5118,12 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sgbsv_(__n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                   __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgbsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -5194,64 +5135,60 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn sgbtf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgbtrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgbtrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sgebak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_real,
                    __m: *mut __CLPK_integer, __v: *mut __CLPK_real,
-                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen);
     pub fn sgebal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __scale: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
     pub fn sgebd2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tauq: *mut __CLPK_real, __taup: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgebrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tauq: *mut __CLPK_real, __taup: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgecon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn sgeequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgeequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                     __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                     __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                    __amax: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgees_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                   __select: __CLPK_L_fp, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -5259,7 +5196,8 @@ extern "C" {
                   __wi: *mut __CLPK_real, __vs: *mut __CLPK_real,
                   __ldvs: *mut __CLPK_integer, __work: *mut __CLPK_real,
                   __lwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvs_len: __CLPK_ftnlen,
+                  __sort_len: __CLPK_ftnlen);
     pub fn sgeesx_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __select: __CLPK_L_fp, __sense: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
@@ -5268,41 +5206,42 @@ extern "C" {
                    __vs: *mut __CLPK_real, __ldvs: *mut __CLPK_integer,
                    __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                   __jobvs_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn sgeev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __wr: *mut __CLPK_real,
                   __wi: *mut __CLPK_real, __vl: *mut __CLPK_real,
                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_real,
                   __ldvr: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn sgeevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __wr: *mut __CLPK_real, __wi: *mut __CLPK_real,
-                   __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
-                   __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
-                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_real, __abnrm: *mut __CLPK_real,
-                   __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn sgegs_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                  __alphar: *mut __CLPK_real, __alphai: *mut __CLPK_real,
-                  __beta: *mut __CLPK_real, __vsl: *mut __CLPK_real,
-                  __ldvsl: *mut __CLPK_integer, __vsr: *mut __CLPK_real,
-                  __ldvsr: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn sgeevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
+                   __lda: *mut __CLPK_integer, __wr: *mut __CLPK_real,
+                   __wi: *mut __CLPK_real, __vl: *mut __CLPK_real,
+                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_real,
+                   __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
+                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_real,
+                   __abnrm: *mut __CLPK_real, __rconde: *mut __CLPK_real,
+                   __rcondv: *mut __CLPK_real, __work: *mut __CLPK_real,
+                   __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
+    pub fn sgegs_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
+                  __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_real,
+                  __alphai: *mut __CLPK_real, __beta: *mut __CLPK_real,
+                  __vsl: *mut __CLPK_real, __ldvsl: *mut __CLPK_integer,
+                  __vsr: *mut __CLPK_real, __ldvsr: *mut __CLPK_integer,
+                  __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                  __jobvsr_len: __CLPK_ftnlen);
     pub fn sgegv_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -5311,17 +5250,17 @@ extern "C" {
                   __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
                   __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvl_len: __CLPK_ftnlen,
+                  __jobvr_len: __CLPK_ftnlen);
     pub fn sgehd2_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgehrd_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgejsv_(__joba: *mut ::libc::c_char, __jobu: *mut ::libc::c_char,
                    __jobv: *mut ::libc::c_char, __jobr: *mut ::libc::c_char,
                    __jobt: *mut ::libc::c_char, __jobp: *mut ::libc::c_char,
@@ -5331,79 +5270,76 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __ldv: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __joba_len: __CLPK_ftnlen,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobr_len: __CLPK_ftnlen, __jobt_len: __CLPK_ftnlen,
+                   __jobp_len: __CLPK_ftnlen);
     pub fn sgelq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgelqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgels_(__trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sgelsd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgelss_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgelsx_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgelsy_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgeql2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgeqlf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgeqp3_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgeqpf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sgeqr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgeqrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgerfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_real,
@@ -5412,49 +5348,48 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sgerq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgerqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgesc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __rhs: *mut __CLPK_real,
                    __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_real) -> ::libc::c_int;
+                   __scale: *mut __CLPK_real);
     pub fn sgesdd_(__jobz: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __u: *mut __CLPK_real, __ldu: *mut __CLPK_integer,
                    __vt: *mut __CLPK_real, __ldvt: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen);
     pub fn sgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgesvd_(__jobu: *mut ::libc::c_char, __jobvt: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __u: *mut __CLPK_real,
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_real,
                    __ldvt: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobvt_len: __CLPK_ftnlen);
     pub fn sgesvj_(__joba: *mut ::libc::c_char, __jobu: *mut ::libc::c_char,
                    __jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __sva: *mut __CLPK_real,
                    __mv: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __ldv: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __joba_len: __CLPK_ftnlen, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen);
     pub fn sgesvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -5465,54 +5400,53 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn sgetc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgetf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgetrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgetri_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgetrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sggbak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __lscale: *mut __CLPK_real,
                    __rscale: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __v: *mut __CLPK_real, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __side_len: __CLPK_ftnlen);
     pub fn sggbal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_real, __rscale: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
-    pub fn sgges_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
-                  __selctg: __CLPK_L_fp, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                  __sdim: *mut __CLPK_integer, __alphar: *mut __CLPK_real,
-                  __alphai: *mut __CLPK_real, __beta: *mut __CLPK_real,
-                  __vsl: *mut __CLPK_real, __ldvsl: *mut __CLPK_integer,
-                  __vsr: *mut __CLPK_real, __ldvsr: *mut __CLPK_integer,
-                  __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
+    pub fn sgges_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __sort: *mut ::libc::c_char, __selctg: __CLPK_L_fp,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
+                  __ldb: *mut __CLPK_integer, __sdim: *mut __CLPK_integer,
+                  __alphar: *mut __CLPK_real, __alphai: *mut __CLPK_real,
+                  __beta: *mut __CLPK_real, __vsl: *mut __CLPK_real,
+                  __ldvsl: *mut __CLPK_integer, __vsr: *mut __CLPK_real,
+                  __ldvsr: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                  __lwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                  __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                  __jobvsr_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen);
     pub fn sggesx_(__jobvsl: *mut ::libc::c_char,
                    __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __selctg: __CLPK_L_fp, __sense: *mut ::libc::c_char,
@@ -5525,9 +5459,10 @@ extern "C" {
                    __ldvsr: *mut __CLPK_integer, __rconde: *mut __CLPK_real,
                    __rcondv: *mut __CLPK_real, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __jobvsl_len: __CLPK_ftnlen,
+                   __jobvsr_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn sggev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -5536,59 +5471,59 @@ extern "C" {
                   __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
                   __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn sggevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __alphar: *mut __CLPK_real, __alphai: *mut __CLPK_real,
-                   __beta: *mut __CLPK_real, __vl: *mut __CLPK_real,
-                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_real,
-                   __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer, __lscale: *mut __CLPK_real,
-                   __rscale: *mut __CLPK_real, __abnrm: *mut __CLPK_real,
-                   __bbnrm: *mut __CLPK_real, __rconde: *mut __CLPK_real,
-                   __rcondv: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobvl_len: __CLPK_ftnlen,
+                  __jobvr_len: __CLPK_ftnlen);
+    pub fn sggevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
+                   __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_real,
+                   __alphai: *mut __CLPK_real, __beta: *mut __CLPK_real,
+                   __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
+                   __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
+                   __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
+                   __lscale: *mut __CLPK_real, __rscale: *mut __CLPK_real,
+                   __abnrm: *mut __CLPK_real, __bbnrm: *mut __CLPK_real,
+                   __rconde: *mut __CLPK_real, __rcondv: *mut __CLPK_real,
+                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
+                   __iwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn sggglm_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __y: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgghrd_(__compq: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_real,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_real,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn sgglse_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __x: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sggqrf_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sggrqf_(__m: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __taua: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sggsvd_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __p: *mut __CLPK_integer,
@@ -5600,7 +5535,8 @@ extern "C" {
                    __v: *mut __CLPK_real, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen, __jobq_len: __CLPK_ftnlen);
     pub fn sggsvp_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -5612,8 +5548,9 @@ extern "C" {
                    __v: *mut __CLPK_real, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __iwork: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn sgsvj0_(__jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_real,
@@ -5622,7 +5559,7 @@ extern "C" {
                    __eps: *mut __CLPK_real, __sfmin: *mut __CLPK_real,
                    __tol: *mut __CLPK_real, __nsweep: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobv_len: __CLPK_ftnlen);
     pub fn sgsvj1_(__jobv: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __n1: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -5631,15 +5568,15 @@ extern "C" {
                    __ldv: *mut __CLPK_integer, __eps: *mut __CLPK_real,
                    __sfmin: *mut __CLPK_real, __tol: *mut __CLPK_real,
                    __nsweep: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobv_len: __CLPK_ftnlen);
     pub fn sgtcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __du: *mut __CLPK_real, __du2: *mut __CLPK_real,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen);
     pub fn sgtrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __du: *mut __CLPK_real,
@@ -5649,13 +5586,12 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sgtsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __dl: *mut __CLPK_real, __d__: *mut __CLPK_real,
                   __du: *mut __CLPK_real, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn sgtsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_real, __d__: *mut __CLPK_real,
@@ -5666,24 +5602,23 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sgttrf_(__n: *mut __CLPK_integer, __dl: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __du: *mut __CLPK_real,
                    __du2: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sgttrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __du: *mut __CLPK_real,
                    __du2: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn sgtts2_(__itrans: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __dl: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __du: *mut __CLPK_real,
                    __du2: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer);
     pub fn shgeqz_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -5693,56 +5628,55 @@ extern "C" {
                    __beta: *mut __CLPK_real, __q: *mut __CLPK_real,
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen,
+                   __compz_len: __CLPK_ftnlen);
     pub fn shsein_(__side: *mut ::libc::c_char, __eigsrc: *mut ::libc::c_char,
-                   __initv: *mut ::libc::c_char,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __h__: *mut __CLPK_real, __ldh: *mut __CLPK_integer,
-                   __wr: *mut __CLPK_real, __wi: *mut __CLPK_real,
-                   __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
-                   __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
-                   __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __ifaill: *mut __CLPK_integer,
-                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __initv: *mut ::libc::c_char, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __h__: *mut __CLPK_real,
+                   __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_real,
+                   __wi: *mut __CLPK_real, __vl: *mut __CLPK_real,
+                   __ldvl: *mut __CLPK_integer, __vr: *mut __CLPK_real,
+                   __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __ifaill: *mut __CLPK_integer,
+                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __eigsrc_len: __CLPK_ftnlen,
+                   __initv_len: __CLPK_ftnlen);
     pub fn shseqr_(__job: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_real,
                    __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_real,
                    __wi: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn sisnan_(__sin__: *mut __CLPK_real) -> __CLPK_logical;
-    pub fn slabad_(__small: *mut __CLPK_real, __large: *mut __CLPK_real)
-     -> ::libc::c_int;
+    pub fn slabad_(__small: *mut __CLPK_real, __large: *mut __CLPK_real);
     pub fn slabrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __tauq: *mut __CLPK_real,
                    __taup: *mut __CLPK_real, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __y: *mut __CLPK_real,
-                   __ldy: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldy: *mut __CLPK_integer);
     pub fn slacn2_(__n: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __isgn: *mut __CLPK_integer,
                    __est: *mut __CLPK_real, __kase: *mut __CLPK_integer,
-                   __isave: *mut __CLPK_integer) -> ::libc::c_int;
+                   __isave: *mut __CLPK_integer);
     pub fn slacon_(__n: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __isgn: *mut __CLPK_integer,
-                   __est: *mut __CLPK_real, __kase: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __est: *mut __CLPK_real, __kase: *mut __CLPK_integer);
     pub fn slacpy_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sladiv_(__a: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __d__: *mut __CLPK_real,
-                   __p: *mut __CLPK_real, __q: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __p: *mut __CLPK_real, __q: *mut __CLPK_real);
     pub fn slae2_(__a: *mut __CLPK_real, __b: *mut __CLPK_real,
                   __c__: *mut __CLPK_real, __rt1: *mut __CLPK_real,
-                  __rt2: *mut __CLPK_real) -> ::libc::c_int;
+                  __rt2: *mut __CLPK_real);
     pub fn slaebz_(__ijob: *mut __CLPK_integer, __nitmax: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __mmax: *mut __CLPK_integer,
                    __minp: *mut __CLPK_integer, __nbmin: *mut __CLPK_integer,
@@ -5752,21 +5686,18 @@ extern "C" {
                    __nval: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __mout: *mut __CLPK_integer,
                    __nab: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slaed0_(__icompq: *mut __CLPK_integer, __qsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __q: *mut __CLPK_real,
                    __ldq: *mut __CLPK_integer, __qstore: *mut __CLPK_real,
                    __ldqs: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slaed1_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __indxq: *mut __CLPK_integer, __rho: *mut __CLPK_real,
                    __cutpnt: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slaed2_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
@@ -5774,31 +5705,27 @@ extern "C" {
                    __z__: *mut __CLPK_real, __dlamda: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __q2: *mut __CLPK_real,
                    __indx: *mut __CLPK_integer, __indxc: *mut __CLPK_integer,
-                   __indxp: *mut __CLPK_integer,
-                   __coltyp: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __indxp: *mut __CLPK_integer, __coltyp: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer);
     pub fn slaed3_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __rho: *mut __CLPK_real, __dlamda: *mut __CLPK_real,
                    __q2: *mut __CLPK_real, __indx: *mut __CLPK_integer,
                    __ctot: *mut __CLPK_integer, __w: *mut __CLPK_real,
-                   __s: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slaed4_(__n: *mut __CLPK_integer, __i__: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __delta: *mut __CLPK_real, __rho: *mut __CLPK_real,
-                   __dlam: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __dlam: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slaed5_(__i__: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __delta: *mut __CLPK_real,
-                   __rho: *mut __CLPK_real, __dlam: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __rho: *mut __CLPK_real, __dlam: *mut __CLPK_real);
     pub fn slaed6_(__kniter: *mut __CLPK_integer,
                    __orgati: *mut __CLPK_logical, __rho: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __finit: *mut __CLPK_real, __tau: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaed7_(__icompq: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __qsiz: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
@@ -5810,7 +5737,7 @@ extern "C" {
                    __perm: *mut __CLPK_integer, __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaed8_(__icompq: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __qsiz: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __q: *mut __CLPK_real,
@@ -5822,14 +5749,14 @@ extern "C" {
                    __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
                    __indxp: *mut __CLPK_integer, __indx: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaed9_(__k: *mut __CLPK_integer, __kstart: *mut __CLPK_integer,
                    __kstop: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __q: *mut __CLPK_real,
                    __ldq: *mut __CLPK_integer, __rho: *mut __CLPK_real,
                    __dlamda: *mut __CLPK_real, __w: *mut __CLPK_real,
                    __s: *mut __CLPK_real, __lds: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaeda_(__n: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
                    __curpbm: *mut __CLPK_integer,
@@ -5838,7 +5765,7 @@ extern "C" {
                    __givcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __qptr: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __ztemp: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaein_(__rightv: *mut __CLPK_logical,
                    __noinit: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __h__: *mut __CLPK_real, __ldh: *mut __CLPK_integer,
@@ -5847,83 +5774,78 @@ extern "C" {
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __eps3: *mut __CLPK_real,
                    __smlnum: *mut __CLPK_real, __bignum: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slaev2_(__a: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __rt1: *mut __CLPK_real,
                    __rt2: *mut __CLPK_real, __cs1: *mut __CLPK_real,
-                   __sn1: *mut __CLPK_real) -> ::libc::c_int;
+                   __sn1: *mut __CLPK_real);
     pub fn slaexc_(__wantq: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __j1: *mut __CLPK_integer, __n1: *mut __CLPK_integer,
                    __n2: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slag2_(__a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                   __safmin: *mut __CLPK_real, __scale1: *mut __CLPK_real,
                   __scale2: *mut __CLPK_real, __wr1: *mut __CLPK_real,
-                  __wr2: *mut __CLPK_real, __wi: *mut __CLPK_real)
-     -> ::libc::c_int;
+                  __wr2: *mut __CLPK_real, __wi: *mut __CLPK_real);
     pub fn slag2d_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __sa: *mut __CLPK_real, __ldsa: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slags2_(__upper: *mut __CLPK_logical, __a1: *mut __CLPK_real,
                    __a2: *mut __CLPK_real, __a3: *mut __CLPK_real,
                    __b1: *mut __CLPK_real, __b2: *mut __CLPK_real,
                    __b3: *mut __CLPK_real, __csu: *mut __CLPK_real,
                    __snu: *mut __CLPK_real, __csv: *mut __CLPK_real,
                    __snv: *mut __CLPK_real, __csq: *mut __CLPK_real,
-                   __snq: *mut __CLPK_real) -> ::libc::c_int;
+                   __snq: *mut __CLPK_real);
     pub fn slagtf_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lambda: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __tol: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __in: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slagtm_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                    __dl: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __du: *mut __CLPK_real, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __beta: *mut __CLPK_real,
-                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn slagts_(__job: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __in: *mut __CLPK_integer, __y: *mut __CLPK_real,
-                   __tol: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tol: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slagv2_(__a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __alphar: *mut __CLPK_real, __alphai: *mut __CLPK_real,
                    __beta: *mut __CLPK_real, __csl: *mut __CLPK_real,
                    __snl: *mut __CLPK_real, __csr: *mut __CLPK_real,
-                   __snr: *mut __CLPK_real) -> ::libc::c_int;
+                   __snr: *mut __CLPK_real);
     pub fn slahqr_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_real,
                    __ldh: *mut __CLPK_integer, __wr: *mut __CLPK_real,
                    __wi: *mut __CLPK_real, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_real,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slahr2_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_real, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_real, __ldy: *mut __CLPK_integer);
     pub fn slahrd_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_real, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_real, __ldy: *mut __CLPK_integer);
     pub fn slaic1_(__job: *mut __CLPK_integer, __j: *mut __CLPK_integer,
                    __x: *mut __CLPK_real, __sest: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __gamma: *mut __CLPK_real,
                    __sestpr: *mut __CLPK_real, __s: *mut __CLPK_real,
-                   __c__: *mut __CLPK_real) -> ::libc::c_int;
+                   __c__: *mut __CLPK_real);
     pub fn slaisnan_(__sin1: *mut __CLPK_real, __sin2: *mut __CLPK_real)
      -> __CLPK_logical;
     pub fn slaln2_(__ltrans: *mut __CLPK_logical, __na: *mut __CLPK_integer,
@@ -5934,8 +5856,7 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __wr: *mut __CLPK_real,
                    __wi: *mut __CLPK_real, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __xnorm: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __xnorm: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slals0_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -5948,8 +5869,7 @@ extern "C" {
                    __difl: *mut __CLPK_real, __difr: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __s: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slalsa_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -5963,19 +5883,17 @@ extern "C" {
                    __ldgcol: *mut __CLPK_integer, __perm: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __s: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slalsd_(__uplo: *mut ::libc::c_char, __smlsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn slamrg_(__n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __strd1: *mut __CLPK_integer,
-                   __strd2: *mut __CLPK_integer, __index: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __strd2: *mut __CLPK_integer, __index: *mut __CLPK_integer);
     pub fn slaneg_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __lld: *mut __CLPK_real, __sigma: *mut __CLPK_real,
                    __pivmin: *mut __CLPK_real, __r__: *mut __CLPK_integer)
@@ -5983,94 +5901,99 @@ extern "C" {
     pub fn slangb_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn slange_(__norm: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slangt_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_real, __d__: *mut __CLPK_real,
-                   __du: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __du: *mut __CLPK_real, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn slanhs_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn slansb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slansf_(__norm: *mut ::libc::c_char, __transr: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_real, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __a: *mut __CLPK_real, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slansp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
-                   __work: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_real, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slanst_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_real, __e: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slansy_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn slantb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __ab: *mut __CLPK_real,
-                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slantp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_real, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __ap: *mut __CLPK_real, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slantr_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_real_return;
     pub fn slanv2_(__a: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __rt1r: *mut __CLPK_real, __rt1i: *mut __CLPK_real,
                    __rt2r: *mut __CLPK_real, __rt2i: *mut __CLPK_real,
-                   __cs: *mut __CLPK_real, __sn: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __cs: *mut __CLPK_real, __sn: *mut __CLPK_real);
     pub fn slapll_(__n: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_real,
-                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_real);
     pub fn slapmt_(__forwrd: *mut __CLPK_logical, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __x: *mut __CLPK_real,
-                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer);
     pub fn slapy2_(__x: *mut __CLPK_real, __y: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+     -> __CLPK_real_return;
     pub fn slapy3_(__x: *mut __CLPK_real, __y: *mut __CLPK_real,
-                   __z__: *mut __CLPK_real) -> __CLPK_doublereal;
+                   __z__: *mut __CLPK_real) -> __CLPK_real_return;
     pub fn slaqgb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __equed_len: __CLPK_ftnlen);
     pub fn slaqge_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __rowcnd: *mut __CLPK_real, __colcnd: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __equed_len: __CLPK_ftnlen);
     pub fn slaqp2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __vn1: *mut __CLPK_real,
-                   __vn2: *mut __CLPK_real, __work: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __vn2: *mut __CLPK_real, __work: *mut __CLPK_real);
     pub fn slaqps_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __nb: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __vn1: *mut __CLPK_real,
                    __vn2: *mut __CLPK_real, __auxv: *mut __CLPK_real,
-                   __f: *mut __CLPK_real, __ldf: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __f: *mut __CLPK_real, __ldf: *mut __CLPK_integer);
     pub fn slaqr0_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_real,
@@ -6078,13 +6001,11 @@ extern "C" {
                    __wi: *mut __CLPK_real, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slaqr1_(__n: *mut __CLPK_integer, __h__: *mut __CLPK_real,
                    __ldh: *mut __CLPK_integer, __sr1: *mut __CLPK_real,
                    __si1: *mut __CLPK_real, __sr2: *mut __CLPK_real,
-                   __si2: *mut __CLPK_real, __v: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __si2: *mut __CLPK_real, __v: *mut __CLPK_real);
     pub fn slaqr2_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -6097,8 +6018,7 @@ extern "C" {
                    __nh: *mut __CLPK_integer, __t: *mut __CLPK_real,
                    __ldt: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_real, __ldwv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer);
     pub fn slaqr3_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -6111,8 +6031,7 @@ extern "C" {
                    __nh: *mut __CLPK_integer, __t: *mut __CLPK_real,
                    __ldt: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_real, __ldwv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer);
     pub fn slaqr4_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __h__: *mut __CLPK_real,
@@ -6120,8 +6039,7 @@ extern "C" {
                    __wi: *mut __CLPK_real, __iloz: *mut __CLPK_integer,
                    __ihiz: *mut __CLPK_integer, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slaqr5_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __kacc22: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ktop: *mut __CLPK_integer, __kbot: *mut __CLPK_integer,
@@ -6134,28 +6052,30 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_real, __ldwv: *mut __CLPK_integer,
                    __nh: *mut __CLPK_integer, __wh: *mut __CLPK_real,
-                   __ldwh: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwh: *mut __CLPK_integer);
     pub fn slaqsb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn slaqsp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn slaqsy_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
-                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char)
-     -> ::libc::c_int;
+                   __amax: *mut __CLPK_real, __equed: *mut ::libc::c_char,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn slaqtr_(__ltran: *mut __CLPK_logical,
                    __l__CLPK_real: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __t: *mut __CLPK_real,
                    __ldt: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __scale: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slar1v_(__n: *mut __CLPK_integer, __b1: *mut __CLPK_integer,
                    __bn: *mut __CLPK_integer, __lambda: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __l: *mut __CLPK_real,
@@ -6166,17 +6086,16 @@ extern "C" {
                    __mingma: *mut __CLPK_real, __r__: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __nrminv: *mut __CLPK_real,
                    __resid: *mut __CLPK_real, __rqcorr: *mut __CLPK_real,
-                   __work: *mut __CLPK_real) -> ::libc::c_int;
+                   __work: *mut __CLPK_real);
     pub fn slar2v_(__n: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __y: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __incx: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __s: *mut __CLPK_real, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_real, __incc: *mut __CLPK_integer);
     pub fn slarf_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __v: *mut __CLPK_real,
                   __incv: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                   __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_real) -> ::libc::c_int;
+                  __work: *mut __CLPK_real, __side_len: __CLPK_ftnlen);
     pub fn slarfb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -6184,38 +6103,37 @@ extern "C" {
                    __v: *mut __CLPK_real, __ldv: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __ldwork: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __ldwork: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn slarfg_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_real) -> ::libc::c_int;
+                   __tau: *mut __CLPK_real);
     pub fn slarfp_(__n: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_real) -> ::libc::c_int;
+                   __tau: *mut __CLPK_real);
     pub fn slarft_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn slarfx_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
-                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __side_len: __CLPK_ftnlen);
     pub fn slargv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_real,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incc: *mut __CLPK_integer);
     pub fn slarnv_(__idist: *mut __CLPK_integer, __iseed: *mut __CLPK_integer,
-                   __n: *mut __CLPK_integer, __x: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __n: *mut __CLPK_integer, __x: *mut __CLPK_real);
     pub fn slarra_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __e2: *mut __CLPK_real,
                    __spltol: *mut __CLPK_real, __tnrm: *mut __CLPK_real,
                    __nsplit: *mut __CLPK_integer,
-                   __isplit: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __isplit: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slarrb_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __lld: *mut __CLPK_real, __ifirst: *mut __CLPK_integer,
                    __ilast: *mut __CLPK_integer, __rtol1: *mut __CLPK_real,
@@ -6224,13 +6142,13 @@ extern "C" {
                    __werr: *mut __CLPK_real, __work: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __pivmin: *mut __CLPK_real,
                    __spdiam: *mut __CLPK_real, __twist: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slarrc_(__jobt: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __vl: *mut __CLPK_real, __vu: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __pivmin: *mut __CLPK_real, __eigcnt: *mut __CLPK_integer,
                    __lcnt: *mut __CLPK_integer, __rcnt: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobt_len: __CLPK_ftnlen);
     pub fn slarrd_(__range: *mut ::libc::c_char, __order: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __vl: *mut __CLPK_real,
                    __vu: *mut __CLPK_real, __il: *mut __CLPK_integer,
@@ -6243,8 +6161,8 @@ extern "C" {
                    __wl: *mut __CLPK_real, __wu: *mut __CLPK_real,
                    __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen, __order_len: __CLPK_ftnlen);
     pub fn slarre_(__range: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __vl: *mut __CLPK_real, __vu: *mut __CLPK_real,
                    __il: *mut __CLPK_integer, __iu: *mut __CLPK_integer,
@@ -6257,35 +6175,32 @@ extern "C" {
                    __wgap: *mut __CLPK_real, __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer, __gers: *mut __CLPK_real,
                    __pivmin: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen);
     pub fn slarrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __l: *mut __CLPK_real, __ld: *mut __CLPK_real,
-                   __clstrt: *mut __CLPK_integer,
-                   __clend: *mut __CLPK_integer, __w: *mut __CLPK_real,
-                   __wgap: *mut __CLPK_real, __werr: *mut __CLPK_real,
-                   __spdiam: *mut __CLPK_real, __clgapl: *mut __CLPK_real,
-                   __clgapr: *mut __CLPK_real, __pivmin: *mut __CLPK_real,
-                   __sigma: *mut __CLPK_real, __dplus: *mut __CLPK_real,
-                   __lplus: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __clstrt: *mut __CLPK_integer, __clend: *mut __CLPK_integer,
+                   __w: *mut __CLPK_real, __wgap: *mut __CLPK_real,
+                   __werr: *mut __CLPK_real, __spdiam: *mut __CLPK_real,
+                   __clgapl: *mut __CLPK_real, __clgapr: *mut __CLPK_real,
+                   __pivmin: *mut __CLPK_real, __sigma: *mut __CLPK_real,
+                   __dplus: *mut __CLPK_real, __lplus: *mut __CLPK_real,
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slarrj_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e2: *mut __CLPK_real, __ifirst: *mut __CLPK_integer,
                    __ilast: *mut __CLPK_integer, __rtol: *mut __CLPK_real,
                    __offset: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __werr: *mut __CLPK_real, __work: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __pivmin: *mut __CLPK_real,
-                   __spdiam: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __spdiam: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slarrk_(__n: *mut __CLPK_integer, __iw: *mut __CLPK_integer,
                    __gl: *mut __CLPK_real, __gu: *mut __CLPK_real,
                    __d__: *mut __CLPK_real, __e2: *mut __CLPK_real,
                    __pivmin: *mut __CLPK_real, __reltol: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __werr: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slarrr_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
-                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slarrv_(__n: *mut __CLPK_integer, __vl: *mut __CLPK_real,
                    __vu: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __l: *mut __CLPK_real, __pivmin: *mut __CLPK_real,
@@ -6298,27 +6213,25 @@ extern "C" {
                    __indexw: *mut __CLPK_integer, __gers: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slarscl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                      __d__: *mut __CLPK_real, __x: *mut __CLPK_real,
-                     __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                     __ldx: *mut __CLPK_integer);
     pub fn slartg_(__f: *mut __CLPK_real, __g: *mut __CLPK_real,
                    __cs: *mut __CLPK_real, __sn: *mut __CLPK_real,
-                   __r__: *mut __CLPK_real) -> ::libc::c_int;
+                   __r__: *mut __CLPK_real);
     pub fn slartv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __incx: *mut __CLPK_integer, __y: *mut __CLPK_real,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __s: *mut __CLPK_real, __incc: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_real, __incc: *mut __CLPK_integer);
     pub fn slaruv_(__iseed: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __x: *mut __CLPK_real) -> ::libc::c_int;
+                   __x: *mut __CLPK_real);
     pub fn slarz_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                   __v: *mut __CLPK_real, __incv: *mut __CLPK_integer,
                   __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
-                  __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> ::libc::c_int;
+                  __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                  __side_len: __CLPK_ftnlen);
     pub fn slarzb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -6327,40 +6240,40 @@ extern "C" {
                    __ldv: *mut __CLPK_integer, __t: *mut __CLPK_real,
                    __ldt: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn slarzt_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __ldv: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn slas2_(__f: *mut __CLPK_real, __g: *mut __CLPK_real,
                   __h__: *mut __CLPK_real, __ssmin: *mut __CLPK_real,
-                  __ssmax: *mut __CLPK_real) -> ::libc::c_int;
+                  __ssmax: *mut __CLPK_real);
     pub fn slascl_(__type__: *mut ::libc::c_char, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __cfrom: *mut __CLPK_real,
                    __cto: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __type_len: __CLPK_ftnlen);
     pub fn slascl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __d__: *mut __CLPK_real, __x: *mut __CLPK_real,
-                    __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                    __ldx: *mut __CLPK_integer);
     pub fn slasd0_(__n: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __u: *mut __CLPK_real, __ldu: *mut __CLPK_integer,
                    __vt: *mut __CLPK_real, __ldvt: *mut __CLPK_integer,
-                   __smlsiz: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __smlsiz: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slasd1_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __alpha: *mut __CLPK_real, __beta: *mut __CLPK_real,
                    __u: *mut __CLPK_real, __ldu: *mut __CLPK_integer,
                    __vt: *mut __CLPK_real, __ldvt: *mut __CLPK_integer,
                    __idxq: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slasd2_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __z__: *mut __CLPK_real,
@@ -6372,7 +6285,7 @@ extern "C" {
                    __ldvt2: *mut __CLPK_integer, __idxp: *mut __CLPK_integer,
                    __idx: *mut __CLPK_integer, __idxc: *mut __CLPK_integer,
                    __idxq: *mut __CLPK_integer, __coltyp: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasd3_(__nl: *mut __CLPK_integer, __nr: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __q: *mut __CLPK_real,
@@ -6382,17 +6295,16 @@ extern "C" {
                    __vt: *mut __CLPK_real, __ldvt: *mut __CLPK_integer,
                    __vt2: *mut __CLPK_real, __ldvt2: *mut __CLPK_integer,
                    __idxc: *mut __CLPK_integer, __ctot: *mut __CLPK_integer,
-                   __z__: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __z__: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slasd4_(__n: *mut __CLPK_integer, __i__: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __delta: *mut __CLPK_real, __rho: *mut __CLPK_real,
                    __sigma: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasd5_(__i__: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __delta: *mut __CLPK_real,
                    __rho: *mut __CLPK_real, __dsigma: *mut __CLPK_real,
-                   __work: *mut __CLPK_real) -> ::libc::c_int;
+                   __work: *mut __CLPK_real);
     pub fn slasd6_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __vf: *mut __CLPK_real,
@@ -6406,7 +6318,7 @@ extern "C" {
                    __z__: *mut __CLPK_real, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __s: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasd7_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __d__: *mut __CLPK_real,
@@ -6420,15 +6332,13 @@ extern "C" {
                    __givcol: *mut __CLPK_integer,
                    __ldgcol: *mut __CLPK_integer, __givnum: *mut __CLPK_real,
                    __ldgnum: *mut __CLPK_integer, __c__: *mut __CLPK_real,
-                   __s: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __s: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slasd8_(__icompq: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __vf: *mut __CLPK_real, __vl: *mut __CLPK_real,
                    __difl: *mut __CLPK_real, __difr: *mut __CLPK_real,
                    __lddifr: *mut __CLPK_integer, __dsigma: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn slasda_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __sqre: *mut __CLPK_integer, __d__: *mut __CLPK_real,
@@ -6441,8 +6351,7 @@ extern "C" {
                    __ldgcol: *mut __CLPK_integer, __perm: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __s: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn slasdq_(__uplo: *mut ::libc::c_char, __sqre: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncvt: *mut __CLPK_integer,
                    __nru: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
@@ -6450,21 +6359,21 @@ extern "C" {
                    __vt: *mut __CLPK_real, __ldvt: *mut __CLPK_integer,
                    __u: *mut __CLPK_real, __ldu: *mut __CLPK_integer,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn slasdt_(__n: *mut __CLPK_integer, __lvl: *mut __CLPK_integer,
                    __nd: *mut __CLPK_integer, __inode: *mut __CLPK_integer,
                    __ndiml: *mut __CLPK_integer, __ndimr: *mut __CLPK_integer,
-                   __msub: *mut __CLPK_integer) -> ::libc::c_int;
+                   __msub: *mut __CLPK_integer);
     pub fn slaset_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                    __beta: *mut __CLPK_real, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn slasq1_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasq2_(__n: *mut __CLPK_integer, __z__: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasq3_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __pp: *mut __CLPK_integer,
                    __dmin__: *mut __CLPK_real, __sigma: *mut __CLPK_real,
@@ -6474,49 +6383,46 @@ extern "C" {
                    __ttype: *mut __CLPK_integer, __dmin1: *mut __CLPK_real,
                    __dmin2: *mut __CLPK_real, __dn: *mut __CLPK_real,
                    __dn1: *mut __CLPK_real, __dn2: *mut __CLPK_real,
-                   __g: *mut __CLPK_real, __tau: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __g: *mut __CLPK_real, __tau: *mut __CLPK_real);
     pub fn slasq4_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __pp: *mut __CLPK_integer,
                    __n0in: *mut __CLPK_integer, __dmin__: *mut __CLPK_real,
                    __dmin1: *mut __CLPK_real, __dmin2: *mut __CLPK_real,
                    __dn: *mut __CLPK_real, __dn1: *mut __CLPK_real,
                    __dn2: *mut __CLPK_real, __tau: *mut __CLPK_real,
-                   __ttype: *mut __CLPK_integer, __g: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __ttype: *mut __CLPK_integer, __g: *mut __CLPK_real);
     pub fn slasq5_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __pp: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __dmin__: *mut __CLPK_real,
                    __dmin1: *mut __CLPK_real, __dmin2: *mut __CLPK_real,
                    __dn: *mut __CLPK_real, __dnm1: *mut __CLPK_real,
-                   __dnm2: *mut __CLPK_real, __ieee: *mut __CLPK_logical)
-     -> ::libc::c_int;
+                   __dnm2: *mut __CLPK_real, __ieee: *mut __CLPK_logical);
     pub fn slasq6_(__i0: *mut __CLPK_integer, __n0: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __pp: *mut __CLPK_integer,
                    __dmin__: *mut __CLPK_real, __dmin1: *mut __CLPK_real,
                    __dmin2: *mut __CLPK_real, __dn: *mut __CLPK_real,
-                   __dnm1: *mut __CLPK_real, __dnm2: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __dnm1: *mut __CLPK_real, __dnm2: *mut __CLPK_real);
     pub fn slasr_(__side: *mut ::libc::c_char, __pivot: *mut ::libc::c_char,
                   __direct: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                   __s: *mut __CLPK_real, __a: *mut __CLPK_real,
-                  __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                  __lda: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                  __pivot_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen);
     pub fn slasrt_(__id: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __d__: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __id_len: __CLPK_ftnlen);
     pub fn slassq_(__n: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __incx: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __sumsq: *mut __CLPK_real) -> ::libc::c_int;
+                   __sumsq: *mut __CLPK_real);
     pub fn slasv2_(__f: *mut __CLPK_real, __g: *mut __CLPK_real,
                    __h__: *mut __CLPK_real, __ssmin: *mut __CLPK_real,
                    __ssmax: *mut __CLPK_real, __snr: *mut __CLPK_real,
                    __csr: *mut __CLPK_real, __snl: *mut __CLPK_real,
-                   __csl: *mut __CLPK_real) -> ::libc::c_int;
+                   __csl: *mut __CLPK_real);
     pub fn slaswp_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __k1: *mut __CLPK_integer,
                    __k2: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn slasy2_(__ltranl: *mut __CLPK_logical,
                    __ltranr: *mut __CLPK_logical, __isgn: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
@@ -6525,230 +6431,238 @@ extern "C" {
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __scale: *mut __CLPK_real, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __xnorm: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn slasyf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_real,
-                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn slatbs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __x: *mut __CLPK_real, __scale: *mut __CLPK_real,
-                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen, __normin_len: __CLPK_ftnlen);
     pub fn slatdf_(__ijob: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __rhs: *mut __CLPK_real, __rdsum: *mut __CLPK_real,
                    __rdscal: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer) -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer);
     pub fn slatps_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __x: *mut __CLPK_real, __scale: *mut __CLPK_real,
-                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __cnorm: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen, __normin_len: __CLPK_ftnlen);
     pub fn slatrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __e: *mut __CLPK_real,
                    __tau: *mut __CLPK_real, __w: *mut __CLPK_real,
-                   __ldw: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldw: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn slatrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __scale: *mut __CLPK_real, __cnorm: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn slatrz_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __l: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real) -> ::libc::c_int;
+                   __work: *mut __CLPK_real);
     pub fn slatzm_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_real,
                    __incv: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c1: *mut __CLPK_real, __c2: *mut __CLPK_real,
-                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
+                   __side_len: __CLPK_ftnlen);
     pub fn slauu2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn slauum_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sopgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __tau: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sopmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sorg2l_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sorg2r_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sorgbr_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn sorghr_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sorgl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sorglq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sorgql_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sorgqr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sorgr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sorgrq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sorgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sorm2l_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sorm2r_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sormbr_(__vect: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormhr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sorml2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sormlq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormql_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormqr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormr2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sormr3_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormrq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn sormrz_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn sormtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __tau: *mut __CLPK_real,
                    __c__: *mut __CLPK_real, __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn spbcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spbequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spbrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
@@ -6757,16 +6671,16 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spbstf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spbsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spbsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ab: *mut __CLPK_real,
@@ -6777,44 +6691,47 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn spbtf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spbtrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spbtrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spftrf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spftrs_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn spocon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spoequ_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn spoequb_(__n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                     __lda: *mut __CLPK_integer, __s: *mut __CLPK_real,
                     __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer);
     pub fn sporfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_real,
@@ -6822,13 +6739,13 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn sposvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -6838,43 +6755,44 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn spotf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spotrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spotri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spotrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sppcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sppequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __s: *mut __CLPK_real,
                    __scond: *mut __CLPK_real, __amax: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __afp: *mut __CLPK_real, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sppsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sppsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __afp: *mut __CLPK_real,
@@ -6883,49 +6801,49 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn spptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn spptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spstf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn spstrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sptcon_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn spteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn sptrfs_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __df: *mut __CLPK_real, __ef: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sptsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer);
     pub fn sptsvx_(__fact: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __df: *mut __CLPK_real,
@@ -6933,36 +6851,34 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen);
     pub fn spttrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
-                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn spttrs_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sptts2_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
-                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer);
     pub fn srscl_(__n: *mut __CLPK_integer, __sa: *mut __CLPK_real,
-                  __sx: *mut __CLPK_real, __incx: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __sx: *mut __CLPK_real, __incx: *mut __CLPK_integer);
     pub fn ssbev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                   __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn ssbevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssbevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
@@ -6973,23 +6889,25 @@ extern "C" {
                    __m: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssbgst_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_real,
                    __ldbb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssbgv_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                   __kb: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                   __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_real,
                   __ldbb: *mut __CLPK_integer, __w: *mut __CLPK_real,
                   __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
-                  __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssbgvd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_real,
@@ -6997,9 +6915,9 @@ extern "C" {
                    __ldbb: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssbgvx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ka: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
@@ -7012,37 +6930,40 @@ extern "C" {
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssbtrd_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssfrk_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __k: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                  __beta: *mut __CLPK_real, __c__: *mut __CLPK_real)
-     -> ::libc::c_int;
+                  __beta: *mut __CLPK_real, __c__: *mut __CLPK_real,
+                  __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen);
     pub fn sspcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sspev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn sspevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn sspevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -7051,26 +6972,28 @@ extern "C" {
                    __m: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sspgst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
-                   __bp: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bp: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sspgv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __ap: *mut __CLPK_real, __bp: *mut __CLPK_real,
                   __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                   __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn sspgvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __bp: *mut __CLPK_real,
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn sspgvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
@@ -7080,8 +7003,9 @@ extern "C" {
                    __m: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __afp: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
@@ -7089,12 +7013,12 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn sspsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn sspsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __afp: *mut __CLPK_real,
@@ -7103,23 +7027,24 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssptrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __tau: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __ipiv: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn sstebz_(__range: *mut ::libc::c_char, __order: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __vl: *mut __CLPK_real,
                    __vu: *mut __CLPK_real, __il: *mut __CLPK_integer,
@@ -7128,15 +7053,14 @@ extern "C" {
                    __m: *mut __CLPK_integer, __nsplit: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __iblock: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __range_len: __CLPK_ftnlen, __order_len: __CLPK_ftnlen);
     pub fn sstedc_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __compz_len: __CLPK_ftnlen);
     pub fn sstegr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -7146,15 +7070,15 @@ extern "C" {
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn sstein_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __m: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __iblock: *mut __CLPK_integer,
                    __isplit: *mut __CLPK_integer, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn sstemr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -7165,28 +7089,26 @@ extern "C" {
                    __isuppz: *mut __CLPK_integer,
                    __tryrac: *mut __CLPK_logical, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn ssteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn ssterf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
-                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __e: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn sstev_(__jobz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                   __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
-                  __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen);
     pub fn sstevd_(__jobz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen);
     pub fn sstevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -7196,8 +7118,8 @@ extern "C" {
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __isuppz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn sstevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_real,
                    __e: *mut __CLPK_real, __vl: *mut __CLPK_real,
@@ -7206,31 +7128,32 @@ extern "C" {
                    __m: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn ssycon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __anorm: *mut __CLPK_real,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssyequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                     __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                     __s: *mut __CLPK_real, __scond: *mut __CLPK_real,
                     __amax: *mut __CLPK_real, __work: *mut __CLPK_real,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssyev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_real,
                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn ssyevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __w: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssyevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7240,9 +7163,9 @@ extern "C" {
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssyevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7252,33 +7175,34 @@ extern "C" {
                    __w: *mut __CLPK_real, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifail: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssygs2_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssygst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssygv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                   __w: *mut __CLPK_real, __work: *mut __CLPK_real,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssygvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                    __w: *mut __CLPK_real, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssygvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
@@ -7290,7 +7214,8 @@ extern "C" {
                    __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssyrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __af: *mut __CLPK_real,
@@ -7299,13 +7224,13 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssysv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssysvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7315,43 +7240,44 @@ extern "C" {
                    __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __fact_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn ssytd2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
-                   __tau: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssytf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssytrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_real, __e: *mut __CLPK_real,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssytrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ssytri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ssytrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn stbcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_real,
                    __ldab: *mut __CLPK_integer, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stbrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -7360,31 +7286,37 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stbtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_real, __ldab: *mut __CLPK_integer,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stfsm_(__transr: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                   __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __alpha: *mut __CLPK_real,
                   __a: *mut __CLPK_real, __b: *mut __CLPK_real,
-                  __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                  __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __a: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn stfttp_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_real,
-                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn stfttr_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_real,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn stgevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __s: *mut __CLPK_real, __lds: *mut __CLPK_integer,
@@ -7392,8 +7324,8 @@ extern "C" {
                    __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn stgex2_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -7402,7 +7334,7 @@ extern "C" {
                    __ldz: *mut __CLPK_integer, __j1: *mut __CLPK_integer,
                    __n1: *mut __CLPK_integer, __n2: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn stgexc_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
@@ -7410,22 +7342,20 @@ extern "C" {
                    __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_real,
                    __ldz: *mut __CLPK_integer, __ifst: *mut __CLPK_integer,
                    __ilst: *mut __CLPK_integer, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn stgsen_(__ijob: *mut __CLPK_integer, __wantq: *mut __CLPK_logical,
-                   __wantz: *mut __CLPK_logical,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __alphar: *mut __CLPK_real, __alphai: *mut __CLPK_real,
-                   __beta: *mut __CLPK_real, __q: *mut __CLPK_real,
-                   __ldq: *mut __CLPK_integer, __z__: *mut __CLPK_real,
-                   __ldz: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __pl: *mut __CLPK_real, __pr: *mut __CLPK_real,
-                   __dif: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __wantz: *mut __CLPK_logical, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
+                   __ldb: *mut __CLPK_integer, __alphar: *mut __CLPK_real,
+                   __alphai: *mut __CLPK_real, __beta: *mut __CLPK_real,
+                   __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
+                   __z__: *mut __CLPK_real, __ldz: *mut __CLPK_integer,
+                   __m: *mut __CLPK_integer, __pl: *mut __CLPK_real,
+                   __pr: *mut __CLPK_real, __dif: *mut __CLPK_real,
+                   __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer);
     pub fn stgsja_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -7438,7 +7368,8 @@ extern "C" {
                    __v: *mut __CLPK_real, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __ncycle: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen, __jobq_len: __CLPK_ftnlen);
     pub fn stgsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7448,8 +7379,8 @@ extern "C" {
                    __s: *mut __CLPK_real, __dif: *mut __CLPK_real,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn stgsy2_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7460,8 +7391,8 @@ extern "C" {
                    __f: *mut __CLPK_real, __ldf: *mut __CLPK_integer,
                    __scale: *mut __CLPK_real, __rdsum: *mut __CLPK_real,
                    __rdscal: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __pq: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __pq: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn stgsyl_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
@@ -7472,13 +7403,14 @@ extern "C" {
                    __f: *mut __CLPK_real, __ldf: *mut __CLPK_integer,
                    __scale: *mut __CLPK_real, __dif: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __lwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn stpcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __rcond: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stprfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
@@ -7486,43 +7418,47 @@ extern "C" {
                    __x: *mut __CLPK_real, __ldx: *mut __CLPK_integer,
                    __ferr: *mut __CLPK_real, __berr: *mut __CLPK_real,
                    __work: *mut __CLPK_real, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stptri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn stptrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_real,
                    __b: *mut __CLPK_real, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn stpttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_real,
-                   __arf: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __arf: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn stpttr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_real, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn strcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn strevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
                    __vl: *mut __CLPK_real, __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_real, __ldvr: *mut __CLPK_integer,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn strexc_(__compq: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_real, __ldq: *mut __CLPK_integer,
                    __ifst: *mut __CLPK_integer, __ilst: *mut __CLPK_integer,
-                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __work: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen);
     pub fn strrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
@@ -7530,8 +7466,9 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __x: *mut __CLPK_real,
                    __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_real,
                    __berr: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn strsen_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
@@ -7540,8 +7477,8 @@ extern "C" {
                    __m: *mut __CLPK_integer, __s: *mut __CLPK_real,
                    __sep: *mut __CLPK_real, __work: *mut __CLPK_real,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen);
     pub fn strsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_real, __ldt: *mut __CLPK_integer,
@@ -7550,94 +7487,91 @@ extern "C" {
                    __s: *mut __CLPK_real, __sep: *mut __CLPK_real,
                    __mm: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __work: *mut __CLPK_real, __ldwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __howmny_len: __CLPK_ftnlen);
     pub fn strsyl_(__trana: *mut ::libc::c_char, __tranb: *mut ::libc::c_char,
                    __isgn: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
                    __ldb: *mut __CLPK_integer, __c__: *mut __CLPK_real,
                    __ldc: *mut __CLPK_integer, __scale: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trana_len: __CLPK_ftnlen,
+                   __tranb_len: __CLPK_ftnlen);
     pub fn strti2_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn strtri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn strtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_real,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn strttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_real,
                    __lda: *mut __CLPK_integer, __arf: *mut __CLPK_real,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn strttp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ap: *mut __CLPK_real, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn stzrqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_real, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __tau: *mut __CLPK_real, __info: *mut __CLPK_integer);
     pub fn stzrzf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_real, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_real, __work: *mut __CLPK_real,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zbdsqr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ncvt: *mut __CLPK_integer, __nru: *mut __CLPK_integer,
                    __ncc: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal,
                    __vt: *mut __CLPK_doublecomplex,
-                   __ldvt: *mut __CLPK_integer,
-                   __u: *mut __CLPK_doublecomplex, __ldu: *mut __CLPK_integer,
+                   __ldvt: *mut __CLPK_integer, __u: *mut __CLPK_doublecomplex,
+                   __ldu: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
-                   __ldc: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __rwork: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zcgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __swork: *mut __CLPK_complex,
                    __rwork: *mut __CLPK_doublereal,
-                   __iter: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iter: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zcposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __swork: *mut __CLPK_complex,
                    __rwork: *mut __CLPK_doublereal,
-                   __iter: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iter: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zdrscl_(__n: *mut __CLPK_integer, __sa: *mut __CLPK_doublereal,
-                   __sx: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __sx: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer);
     pub fn zgbbrd_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ncc: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal,
-                   __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
-                   __pt: *mut __CLPK_doublecomplex,
+                   __e: *mut __CLPK_doublereal, __q: *mut __CLPK_doublecomplex,
+                   __ldq: *mut __CLPK_integer, __pt: *mut __CLPK_doublecomplex,
                    __ldpt: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen);
     pub fn zgbcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
@@ -7646,7 +7580,7 @@ extern "C" {
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn zgbequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
@@ -7654,18 +7588,15 @@ extern "C" {
                    __c__: *mut __CLPK_doublereal,
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgbequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                     __ab: *mut __CLPK_doublecomplex,
-                    __ldab: *mut __CLPK_integer,
-                    __r__: *mut __CLPK_doublereal,
+                    __ldab: *mut __CLPK_integer, __r__: *mut __CLPK_doublereal,
                     __c__: *mut __CLPK_doublereal,
                     __rowcnd: *mut __CLPK_doublereal,
                     __colcnd: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgbrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
@@ -7679,13 +7610,12 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn zgbsv_(__n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                   __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
-                  __ab: *mut __CLPK_doublecomplex,
-                  __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __ab: *mut __CLPK_doublecomplex, __ldab: *mut __CLPK_integer,
+                  __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgbsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
@@ -7693,8 +7623,7 @@ extern "C" {
                    __ldab: *mut __CLPK_integer,
                    __afb: *mut __CLPK_doublecomplex,
                    __ldafb: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __equed: *mut ::libc::c_char,
-                   __r__: *mut __CLPK_doublereal,
+                   __equed: *mut ::libc::c_char, __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
@@ -7703,84 +7632,81 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn zgbtf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgbtrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgbtrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn zgebak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __v: *mut __CLPK_doublecomplex, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
+                   __ldv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen);
     pub fn zgebal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __scale: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen);
     pub fn zgebd2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tauq: *mut __CLPK_doublecomplex,
                    __taup: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgebrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tauq: *mut __CLPK_doublecomplex,
                    __taup: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgecon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn zgeequ_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgeequb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                    __a: *mut __CLPK_doublecomplex,
-                    __lda: *mut __CLPK_integer, __r__: *mut __CLPK_doublereal,
+                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
+                    __r__: *mut __CLPK_doublereal,
                     __c__: *mut __CLPK_doublereal,
                     __rowcnd: *mut __CLPK_doublereal,
                     __colcnd: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgees_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                   __select: __CLPK_L_fp, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                   __sdim: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
-                  __vs: *mut __CLPK_doublecomplex,
-                  __ldvs: *mut __CLPK_integer,
+                  __vs: *mut __CLPK_doublecomplex, __ldvs: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
                   __rwork: *mut __CLPK_doublereal,
-                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                  __jobvs_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen);
     pub fn zgeesx_(__jobvs: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __select: __CLPK_L_fp, __sense: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -7793,41 +7719,40 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                   __jobvs_len: __CLPK_ftnlen, __sort_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn zgeev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
-                  __vl: *mut __CLPK_doublecomplex,
-                  __ldvl: *mut __CLPK_integer,
-                  __vr: *mut __CLPK_doublecomplex,
-                  __ldvr: *mut __CLPK_integer,
+                  __vl: *mut __CLPK_doublecomplex, __ldvl: *mut __CLPK_integer,
+                  __vr: *mut __CLPK_doublecomplex, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn zgeevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublecomplex,
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn zgeevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
                    __vl: *mut __CLPK_doublecomplex,
                    __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
-                   __ihi: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
+                   __ihi: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
                    __abnrm: *mut __CLPK_doublereal,
                    __rconde: *mut __CLPK_doublereal,
                    __rcondv: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn zgegs_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
+    pub fn zgegs_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                  __ldb: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __beta: *mut __CLPK_doublecomplex,
                   __vsl: *mut __CLPK_doublecomplex,
@@ -7836,138 +7761,121 @@ extern "C" {
                   __ldvsr: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobvsl_len: __CLPK_ftnlen, __jobvsr_len: __CLPK_ftnlen);
     pub fn zgegv_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
                   __ldb: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __beta: *mut __CLPK_doublecomplex,
-                  __vl: *mut __CLPK_doublecomplex,
-                  __ldvl: *mut __CLPK_integer,
-                  __vr: *mut __CLPK_doublecomplex,
-                  __ldvr: *mut __CLPK_integer,
+                  __vl: *mut __CLPK_doublecomplex, __ldvl: *mut __CLPK_integer,
+                  __vr: *mut __CLPK_doublecomplex, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
     pub fn zgehd2_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgehrd_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgelq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgelqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgels_(__trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __trans_len: __CLPK_ftnlen);
     pub fn zgelsd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __s: *mut __CLPK_doublereal,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgelss_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __s: *mut __CLPK_doublereal,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgelsx_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __jpvt: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
                    __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgelsy_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __jpvt: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __jpvt: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
                    __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgeql2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgeqlf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgeqp3_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgeqpf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __jpvt: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zgeqr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgeqrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgerfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __af: *mut __CLPK_doublecomplex,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublecomplex,
                    __ldaf: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
@@ -7975,23 +7883,22 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn zgerq2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zgerqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgesc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __rhs: *mut __CLPK_doublecomplex,
                    __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __scale: *mut __CLPK_doublereal);
     pub fn zgesdd_(__jobz: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
@@ -8001,31 +7908,29 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen);
     pub fn zgesv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgesvd_(__jobu: *mut ::libc::c_char, __jobvt: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __s: *mut __CLPK_doublereal,
-                   __u: *mut __CLPK_doublecomplex, __ldu: *mut __CLPK_integer,
-                   __vt: *mut __CLPK_doublecomplex,
+                   __s: *mut __CLPK_doublereal, __u: *mut __CLPK_doublecomplex,
+                   __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublecomplex,
                    __ldvt: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobvt_len: __CLPK_ftnlen);
     pub fn zgesvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __af: *mut __CLPK_doublecomplex,
                    __ldaf: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __equed: *mut ::libc::c_char,
-                   __r__: *mut __CLPK_doublereal,
+                   __equed: *mut ::libc::c_char, __r__: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
@@ -8034,51 +7939,47 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn zgetc2_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __jpiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgetf2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgetrf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgetri_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgetrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn zggbak_(__job: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_doublereal,
                    __rscale: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __v: *mut __CLPK_doublecomplex, __ldv: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __side_len: __CLPK_ftnlen);
     pub fn zggbal_(__job: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
                    __lscale: *mut __CLPK_doublereal,
                    __rscale: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn zgges_(__jobvsl: *mut ::libc::c_char,
-                  __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
-                  __selctg: __CLPK_L_fp, __n: *mut __CLPK_integer,
-                  __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                  __sdim: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen);
+    pub fn zgges_(__jobvsl: *mut ::libc::c_char, __jobvsr: *mut ::libc::c_char,
+                  __sort: *mut ::libc::c_char, __selctg: __CLPK_L_fp,
+                  __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                  __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                  __ldb: *mut __CLPK_integer, __sdim: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __beta: *mut __CLPK_doublecomplex,
                   __vsl: *mut __CLPK_doublecomplex,
@@ -8088,8 +7989,9 @@ extern "C" {
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
                   __rwork: *mut __CLPK_doublereal,
-                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                  __jobvsl_len: __CLPK_ftnlen, __jobvsr_len: __CLPK_ftnlen,
+                  __sort_len: __CLPK_ftnlen);
     pub fn zggesx_(__jobvsl: *mut ::libc::c_char,
                    __jobvsr: *mut ::libc::c_char, __sort: *mut ::libc::c_char,
                    __selctg: __CLPK_L_fp, __sense: *mut ::libc::c_char,
@@ -8107,29 +8009,27 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer,
-                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __liwork: *mut __CLPK_integer,
+                   __bwork: *mut __CLPK_logical, __info: *mut __CLPK_integer,
+                   __jobvsl_len: __CLPK_ftnlen, __jobvsr_len: __CLPK_ftnlen,
+                   __sort_len: __CLPK_ftnlen, __sense_len: __CLPK_ftnlen);
     pub fn zggev_(__jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
                   __ldb: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __beta: *mut __CLPK_doublecomplex,
-                  __vl: *mut __CLPK_doublecomplex,
-                  __ldvl: *mut __CLPK_integer,
-                  __vr: *mut __CLPK_doublecomplex,
-                  __ldvr: *mut __CLPK_integer,
+                  __vl: *mut __CLPK_doublecomplex, __ldvl: *mut __CLPK_integer,
+                  __vr: *mut __CLPK_doublecomplex, __ldvr: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
-    pub fn zggevx_(__balanc: *mut ::libc::c_char,
-                   __jobvl: *mut ::libc::c_char, __jobvr: *mut ::libc::c_char,
-                   __sense: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen);
+    pub fn zggevx_(__balanc: *mut ::libc::c_char, __jobvl: *mut ::libc::c_char,
+                   __jobvr: *mut ::libc::c_char, __sense: *mut ::libc::c_char,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublecomplex,
                    __beta: *mut __CLPK_doublecomplex,
                    __vl: *mut __CLPK_doublecomplex,
@@ -8147,7 +8047,9 @@ extern "C" {
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __bwork: *mut __CLPK_logical,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __balanc_len: __CLPK_ftnlen,
+                   __jobvl_len: __CLPK_ftnlen, __jobvr_len: __CLPK_ftnlen,
+                   __sense_len: __CLPK_ftnlen);
     pub fn zggglm_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
@@ -8156,8 +8058,7 @@ extern "C" {
                    __x: *mut __CLPK_doublecomplex,
                    __y: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgghrd_(__compq: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -8165,8 +8066,8 @@ extern "C" {
                    __ldb: *mut __CLPK_integer, __q: *mut __CLPK_doublecomplex,
                    __ldq: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublecomplex,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compq_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn zgglse_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
@@ -8175,8 +8076,7 @@ extern "C" {
                    __d__: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zggqrf_(__n: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
@@ -8184,8 +8084,7 @@ extern "C" {
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zggrqf_(__m: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
@@ -8193,8 +8092,7 @@ extern "C" {
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __taub: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zggsvd_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __p: *mut __CLPK_integer,
@@ -8208,8 +8106,9 @@ extern "C" {
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn zggsvp_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -8224,7 +8123,8 @@ extern "C" {
                    __rwork: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobu_len: __CLPK_ftnlen,
+                   __jobv_len: __CLPK_ftnlen, __jobq_len: __CLPK_ftnlen);
     pub fn zgtcon_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublecomplex,
@@ -8234,7 +8134,7 @@ extern "C" {
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen);
     pub fn zgtrfs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
@@ -8244,20 +8144,19 @@ extern "C" {
                    __df: *mut __CLPK_doublecomplex,
                    __duf: *mut __CLPK_doublecomplex,
                    __du2: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn zgtsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __dl: *mut __CLPK_doublecomplex,
                   __d__: *mut __CLPK_doublecomplex,
                   __du: *mut __CLPK_doublecomplex,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer);
     pub fn zgtsvx_(__fact: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
@@ -8267,48 +8166,45 @@ extern "C" {
                    __df: *mut __CLPK_doublecomplex,
                    __duf: *mut __CLPK_doublecomplex,
                    __du2: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zgttrf_(__n: *mut __CLPK_integer, __dl: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublecomplex,
                    __du: *mut __CLPK_doublecomplex,
                    __du2: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zgttrs_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublecomplex,
                    __du: *mut __CLPK_doublecomplex,
                    __du2: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zgtts2_(__itrans: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublecomplex,
                    __du: *mut __CLPK_doublecomplex,
                    __du2: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer);
     pub fn zhbev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
-                  __ab: *mut __CLPK_doublecomplex,
-                  __ldab: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                  __z__: *mut __CLPK_doublecomplex,
-                  __ldz: *mut __CLPK_integer,
+                  __ab: *mut __CLPK_doublecomplex, __ldab: *mut __CLPK_integer,
+                  __w: *mut __CLPK_doublereal,
+                  __z__: *mut __CLPK_doublecomplex, __ldz: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
@@ -8318,46 +8214,44 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
-                   __vl: *mut __CLPK_doublereal, __vu: *mut __CLPK_doublereal,
-                   __il: *mut __CLPK_integer, __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
+                   __ldab: *mut __CLPK_integer, __q: *mut __CLPK_doublecomplex,
+                   __ldq: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
+                   __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbgst_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer,
                    __bb: *mut __CLPK_doublecomplex,
-                   __ldbb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
+                   __ldbb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhbgv_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                   __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                  __ldab: *mut __CLPK_integer,
-                  __bb: *mut __CLPK_doublecomplex,
+                  __ldab: *mut __CLPK_integer, __bb: *mut __CLPK_doublecomplex,
                   __ldbb: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
-                  __z__: *mut __CLPK_doublecomplex,
-                  __ldz: *mut __CLPK_integer,
+                  __z__: *mut __CLPK_doublecomplex, __ldz: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbgvd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ka: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
@@ -8369,67 +8263,66 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbgvx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ka: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer,
                    __bb: *mut __CLPK_doublecomplex,
-                   __ldbb: *mut __CLPK_integer,
-                   __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
-                   __vl: *mut __CLPK_doublereal, __vu: *mut __CLPK_doublereal,
-                   __il: *mut __CLPK_integer, __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
+                   __ldbb: *mut __CLPK_integer, __q: *mut __CLPK_doublecomplex,
+                   __ldq: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
+                   __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhbtrd_(__vect: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublereal,
-                   __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
+                   __e: *mut __CLPK_doublereal, __q: *mut __CLPK_doublecomplex,
+                   __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __vect_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhecon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zheequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                    __a: *mut __CLPK_doublecomplex,
-                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
+                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
+                    __s: *mut __CLPK_doublereal,
                     __scond: *mut __CLPK_doublereal,
                     __amax: *mut __CLPK_doublereal,
                     __work: *mut __CLPK_doublecomplex,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zheev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zheevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zheevr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -8442,10 +8335,10 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zheevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -8459,17 +8352,18 @@ extern "C" {
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhegs2_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhegst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhegv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -8477,8 +8371,8 @@ extern "C" {
                   __w: *mut __CLPK_doublereal,
                   __work: *mut __CLPK_doublecomplex,
                   __lwork: *mut __CLPK_integer,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhegvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -8487,30 +8381,28 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhegvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
                    __ldb: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zherfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __af: *mut __CLPK_doublecomplex,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublecomplex,
                    __ldaf: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
@@ -8518,14 +8410,14 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhesv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zhesvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -8539,46 +8431,48 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhetd2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhetf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhetrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhetrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhetri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhetrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhfrk_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __k: *mut __CLPK_integer, __alpha: *mut __CLPK_doublereal,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                   __beta: *mut __CLPK_doublereal,
-                  __c__: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                  __c__: *mut __CLPK_doublecomplex,
+                  __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                  __trans_len: __CLPK_ftnlen);
     pub fn zhgeqz_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -8593,22 +8487,22 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __compq_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn zhpcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhpev_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                   __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                   __w: *mut __CLPK_doublereal,
-                  __z__: *mut __CLPK_doublecomplex,
-                  __ldz: *mut __CLPK_integer,
+                  __z__: *mut __CLPK_doublecomplex, __ldz: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhpevd_(__jobz: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                    __w: *mut __CLPK_doublereal,
@@ -8617,10 +8511,9 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhpevx_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
@@ -8633,21 +8526,20 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhpgst_(__itype: *mut __CLPK_integer, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                    __bp: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhpgv_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __ap: *mut __CLPK_doublecomplex,
-                  __bp: *mut __CLPK_doublecomplex,
-                  __w: *mut __CLPK_doublereal,
-                  __z__: *mut __CLPK_doublecomplex,
-                  __ldz: *mut __CLPK_integer,
+                  __bp: *mut __CLPK_doublecomplex, __w: *mut __CLPK_doublereal,
+                  __z__: *mut __CLPK_doublecomplex, __ldz: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __rwork: *mut __CLPK_doublereal,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                  __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhpgvd_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
@@ -8658,10 +8550,9 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhpgvx_(__itype: *mut __CLPK_integer, __jobz: *mut ::libc::c_char,
                    __range: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
@@ -8675,73 +8566,71 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __jobz_len: __CLPK_ftnlen,
+                   __range_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen);
     pub fn zhprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __afp: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhpsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __nrhs: *mut __CLPK_integer,
-                  __ap: *mut __CLPK_doublecomplex,
+                  __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zhpsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __afp: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhptrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zhptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zhsein_(__side: *mut ::libc::c_char, __eigsrc: *mut ::libc::c_char,
-                   __initv: *mut ::libc::c_char,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __h__: *mut __CLPK_doublecomplex,
+                   __initv: *mut ::libc::c_char, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __h__: *mut __CLPK_doublecomplex,
                    __ldh: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
                    __vl: *mut __CLPK_doublecomplex,
                    __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
                    __ifaill: *mut __CLPK_integer,
-                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ifailr: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __eigsrc_len: __CLPK_ftnlen,
+                   __initv_len: __CLPK_ftnlen);
     pub fn zhseqr_(__job: *mut ::libc::c_char, __compz: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
@@ -8750,8 +8639,8 @@ extern "C" {
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compz_len: __CLPK_ftnlen);
     pub fn zlabrd_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
@@ -8759,57 +8648,56 @@ extern "C" {
                    __tauq: *mut __CLPK_doublecomplex,
                    __taup: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer);
     pub fn zlacgv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn zlacn2_(__n: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex,
                    __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer,
-                   __isave: *mut __CLPK_integer) -> ::libc::c_int;
+                   __isave: *mut __CLPK_integer);
     pub fn zlacon_(__n: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex,
-                   __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __est: *mut __CLPK_doublereal, __kase: *mut __CLPK_integer);
     pub fn zlacp2_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublereal,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zlacpy_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                   __ldb: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldb: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zlacrm_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublereal, __ldb: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
-                   __ldc: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __rwork: *mut __CLPK_doublereal);
     pub fn zlacrt_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_doublecomplex,
                    __incx: *mut __CLPK_integer,
                    __cy: *mut __CLPK_doublecomplex,
                    __incy: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
-                   __s: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __s: *mut __CLPK_doublecomplex);
+    #[cfg(lapack_abi = "f2c")]
     pub fn zladiv_(__ret_val: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex,
-                   __y: *mut __CLPK_doublecomplex) -> ();
+                   __y: *mut __CLPK_doublecomplex);
+    #[cfg(not(lapack_abi = "f2c"))]
+    pub fn zladiv_(__x: *mut __CLPK_doublecomplex,
+                   __y: *mut __CLPK_doublecomplex) -> __CLPK_doublecomplex;
     pub fn zlaed0_(__qsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
                    __qstore: *mut __CLPK_doublecomplex,
                    __ldqs: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlaed7_(__n: *mut __CLPK_integer, __cutpnt: *mut __CLPK_integer,
                    __qsiz: *mut __CLPK_integer, __tlvls: *mut __CLPK_integer,
                    __curlvl: *mut __CLPK_integer,
                    __curpbm: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
-                   __rho: *mut __CLPK_doublereal,
-                   __indxq: *mut __CLPK_integer,
+                   __rho: *mut __CLPK_doublereal, __indxq: *mut __CLPK_integer,
                    __qstore: *mut __CLPK_doublereal,
                    __qptr: *mut __CLPK_integer, __prmptr: *mut __CLPK_integer,
                    __perm: *mut __CLPK_integer, __givptr: *mut __CLPK_integer,
@@ -8817,12 +8705,10 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlaed8_(__k: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __qsiz: *mut __CLPK_integer,
-                   __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
-                   __d__: *mut __CLPK_doublereal,
+                   __qsiz: *mut __CLPK_integer, __q: *mut __CLPK_doublecomplex,
+                   __ldq: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __rho: *mut __CLPK_doublereal,
                    __cutpnt: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublereal,
@@ -8834,7 +8720,7 @@ extern "C" {
                    __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer,
                    __givnum: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zlaein_(__rightv: *mut __CLPK_logical,
                    __noinit: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __h__: *mut __CLPK_doublecomplex,
@@ -8844,7 +8730,7 @@ extern "C" {
                    __rwork: *mut __CLPK_doublereal,
                    __eps3: *mut __CLPK_doublereal,
                    __smlnum: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zlaesy_(__a: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex,
                    __c__: *mut __CLPK_doublecomplex,
@@ -8852,29 +8738,28 @@ extern "C" {
                    __rt2: *mut __CLPK_doublecomplex,
                    __evscal: *mut __CLPK_doublecomplex,
                    __cs1: *mut __CLPK_doublecomplex,
-                   __sn1: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __sn1: *mut __CLPK_doublecomplex);
     pub fn zlaev2_(__a: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex,
                    __c__: *mut __CLPK_doublecomplex,
                    __rt1: *mut __CLPK_doublereal,
                    __rt2: *mut __CLPK_doublereal,
                    __cs1: *mut __CLPK_doublereal,
-                   __sn1: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __sn1: *mut __CLPK_doublecomplex);
     pub fn zlag2c_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __sa: *mut __CLPK_complex, __ldsa: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zlags2_(__upper: *mut __CLPK_logical, __a1: *mut __CLPK_doublereal,
                    __a2: *mut __CLPK_doublecomplex,
                    __a3: *mut __CLPK_doublereal, __b1: *mut __CLPK_doublereal,
                    __b2: *mut __CLPK_doublecomplex,
-                   __b3: *mut __CLPK_doublereal,
-                   __csu: *mut __CLPK_doublereal,
+                   __b3: *mut __CLPK_doublereal, __csu: *mut __CLPK_doublereal,
                    __snu: *mut __CLPK_doublecomplex,
                    __csv: *mut __CLPK_doublereal,
                    __snv: *mut __CLPK_doublecomplex,
                    __csq: *mut __CLPK_doublereal,
-                   __snq: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __snq: *mut __CLPK_doublecomplex);
     pub fn zlagtm_(__trans: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublereal,
@@ -8883,14 +8768,14 @@ extern "C" {
                    __du: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
                    __beta: *mut __CLPK_doublereal,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zlahef_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublecomplex, __ldw: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zlahqr_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
@@ -8898,22 +8783,19 @@ extern "C" {
                    __ldh: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
                    __iloz: *mut __CLPK_integer, __ihiz: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublecomplex,
-                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldz: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlahr2_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer);
     pub fn zlahrd_(__n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __y: *mut __CLPK_doublecomplex, __ldy: *mut __CLPK_integer);
     pub fn zlaic1_(__job: *mut __CLPK_integer, __j: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex,
                    __sest: *mut __CLPK_doublereal,
@@ -8921,12 +8803,11 @@ extern "C" {
                    __gamma: *mut __CLPK_doublecomplex,
                    __sestpr: *mut __CLPK_doublereal,
                    __s: *mut __CLPK_doublecomplex,
-                   __c__: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __c__: *mut __CLPK_doublecomplex);
     pub fn zlals0_(__icompq: *mut __CLPK_integer, __nl: *mut __CLPK_integer,
                    __nr: *mut __CLPK_integer, __sqre: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __bx: *mut __CLPK_doublecomplex,
+                   __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __bx: *mut __CLPK_doublecomplex,
                    __ldbx: *mut __CLPK_integer, __perm: *mut __CLPK_integer,
                    __givptr: *mut __CLPK_integer,
                    __givcol: *mut __CLPK_integer,
@@ -8938,13 +8819,11 @@ extern "C" {
                    __difr: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublereal, __k: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zlalsa_(__icompq: *mut __CLPK_integer,
                    __smlsiz: *mut __CLPK_integer, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __bx: *mut __CLPK_doublecomplex,
+                   __nrhs: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __bx: *mut __CLPK_doublecomplex,
                    __ldbx: *mut __CLPK_integer, __u: *mut __CLPK_doublereal,
                    __ldu: *mut __CLPK_integer, __vt: *mut __CLPK_doublereal,
                    __k: *mut __CLPK_integer, __difl: *mut __CLPK_doublereal,
@@ -8957,8 +8836,7 @@ extern "C" {
                    __givnum: *mut __CLPK_doublereal,
                    __c__: *mut __CLPK_doublereal, __s: *mut __CLPK_doublereal,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlalsd_(__uplo: *mut ::libc::c_char, __smlsiz: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
@@ -8967,78 +8845,90 @@ extern "C" {
                    __rank: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zlangb_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlange_(__norm: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlangt_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __dl: *mut __CLPK_doublecomplex,
                    __d__: *mut __CLPK_doublecomplex,
-                   __du: *mut __CLPK_doublecomplex) -> __CLPK_doublereal;
+                   __du: *mut __CLPK_doublecomplex, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlanhb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlanhe_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
      -> __CLPK_doublereal;
     pub fn zlanhf_(__norm: *mut ::libc::c_char, __transr: *mut ::libc::c_char,
                    __uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlanhp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlanhs_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlanht_(__norm: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublecomplex) -> __CLPK_doublereal;
+                   __e: *mut __CLPK_doublecomplex, __norm_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlansb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlansp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlansy_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen)
      -> __CLPK_doublereal;
     pub fn zlantb_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __ldab: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlantp_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __work: *mut __CLPK_doublereal) -> __CLPK_doublereal;
+                   __work: *mut __CLPK_doublereal, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn zlantr_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal)
-     -> __CLPK_doublereal;
+                   __lda: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
+                   __norm_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen) -> __CLPK_doublereal;
     pub fn zlapll_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex,
-                   __incy: *mut __CLPK_integer,
-                   __ssmin: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublecomplex,
+                   __incy: *mut __CLPK_integer, __ssmin: *mut __CLPK_doublereal);
     pub fn zlapmt_(__forwrd: *mut __CLPK_logical, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
-                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldx: *mut __CLPK_integer, __k: *mut __CLPK_integer);
     pub fn zlaqgb_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __kl: *mut __CLPK_integer, __ku: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
@@ -9047,7 +8937,7 @@ extern "C" {
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __equed_len: __CLPK_ftnlen);
     pub fn zlaqge_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __r__: *mut __CLPK_doublereal,
@@ -9055,25 +8945,28 @@ extern "C" {
                    __rowcnd: *mut __CLPK_doublereal,
                    __colcnd: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __equed_len: __CLPK_ftnlen);
     pub fn zlaqhb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlaqhe_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlaqhp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlaqp2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -9081,7 +8974,7 @@ extern "C" {
                    __tau: *mut __CLPK_doublecomplex,
                    __vn1: *mut __CLPK_doublereal,
                    __vn2: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublecomplex);
     pub fn zlaqps_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __offset: *mut __CLPK_integer, __nb: *mut __CLPK_integer,
                    __kb: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -9090,8 +8983,7 @@ extern "C" {
                    __vn1: *mut __CLPK_doublereal,
                    __vn2: *mut __CLPK_doublereal,
                    __auxv: *mut __CLPK_doublecomplex,
-                   __f: *mut __CLPK_doublecomplex, __ldf: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __f: *mut __CLPK_doublecomplex, __ldf: *mut __CLPK_integer);
     pub fn zlaqr0_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
@@ -9101,13 +8993,11 @@ extern "C" {
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlaqr1_(__n: *mut __CLPK_integer, __h__: *mut __CLPK_doublecomplex,
-                   __ldh: *mut __CLPK_integer,
-                   __s1: *mut __CLPK_doublecomplex,
+                   __ldh: *mut __CLPK_integer, __s1: *mut __CLPK_doublecomplex,
                    __s2: *mut __CLPK_doublecomplex,
-                   __v: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __v: *mut __CLPK_doublecomplex);
     pub fn zlaqr2_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -9123,7 +9013,7 @@ extern "C" {
                    __wv: *mut __CLPK_doublecomplex,
                    __ldwv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer);
     pub fn zlaqr3_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ktop: *mut __CLPK_integer,
                    __kbot: *mut __CLPK_integer, __nw: *mut __CLPK_integer,
@@ -9139,7 +9029,7 @@ extern "C" {
                    __wv: *mut __CLPK_doublecomplex,
                    __ldwv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer);
     pub fn zlaqr4_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer,
@@ -9149,8 +9039,7 @@ extern "C" {
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlaqr5_(__wantt: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __kacc22: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ktop: *mut __CLPK_integer, __kbot: *mut __CLPK_integer,
@@ -9165,32 +9054,32 @@ extern "C" {
                    __ldu: *mut __CLPK_integer, __nv: *mut __CLPK_integer,
                    __wv: *mut __CLPK_doublecomplex,
                    __ldwv: *mut __CLPK_integer, __nh: *mut __CLPK_integer,
-                   __wh: *mut __CLPK_doublecomplex,
-                   __ldwh: *mut __CLPK_integer) -> ::libc::c_int;
+                   __wh: *mut __CLPK_doublecomplex, __ldwh: *mut __CLPK_integer);
     pub fn zlaqsb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlaqsp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlaqsy_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
                    __amax: *mut __CLPK_doublereal,
-                   __equed: *mut ::libc::c_char) -> ::libc::c_int;
+                   __equed: *mut ::libc::c_char, __uplo_len: __CLPK_ftnlen,
+                   __equed_len: __CLPK_ftnlen);
     pub fn zlar1v_(__n: *mut __CLPK_integer, __b1: *mut __CLPK_integer,
-                   __bn: *mut __CLPK_integer,
-                   __lambda: *mut __CLPK_doublereal,
+                   __bn: *mut __CLPK_integer, __lambda: *mut __CLPK_doublereal,
                    __d__: *mut __CLPK_doublereal, __l: *mut __CLPK_doublereal,
-                   __ld: *mut __CLPK_doublereal,
-                   __lld: *mut __CLPK_doublereal,
+                   __ld: *mut __CLPK_doublereal, __lld: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __gaptol: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
@@ -9202,26 +9091,23 @@ extern "C" {
                    __nrminv: *mut __CLPK_doublereal,
                    __resid: *mut __CLPK_doublereal,
                    __rqcorr: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal);
     pub fn zlar2v_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
                    __y: *mut __CLPK_doublecomplex,
                    __z__: *mut __CLPK_doublecomplex,
                    __incx: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublecomplex,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __s: *mut __CLPK_doublecomplex, __incc: *mut __CLPK_integer);
     pub fn zlarcm_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublereal, __lda: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
-                   __ldc: *mut __CLPK_integer,
-                   __rwork: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __rwork: *mut __CLPK_doublereal);
     pub fn zlarf_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                   __incv: *mut __CLPK_integer,
                   __tau: *mut __CLPK_doublecomplex,
-                  __c__: *mut __CLPK_doublecomplex,
-                  __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                  __c__: *mut __CLPK_doublecomplex, __ldc: *mut __CLPK_integer,
+                  __work: *mut __CLPK_doublecomplex, __side_len: __CLPK_ftnlen);
     pub fn zlarfb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -9231,49 +9117,46 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn zlarfg_(__n: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublecomplex,
-                   __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
+                   __tau: *mut __CLPK_doublecomplex);
     pub fn zlarfp_(__n: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublecomplex,
-                   __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer,
-                   __tau: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
+                   __tau: *mut __CLPK_doublecomplex);
     pub fn zlarft_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __ldv: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
-                   __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn zlarfx_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __tau: *mut __CLPK_doublecomplex,
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublecomplex, __side_len: __CLPK_ftnlen);
     pub fn zlargv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex,
+                   __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublecomplex,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incc: *mut __CLPK_integer);
     pub fn zlarnv_(__idist: *mut __CLPK_integer, __iseed: *mut __CLPK_integer,
-                   __n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex)
-     -> ::libc::c_int;
+                   __n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex);
     pub fn zlarrv_(__n: *mut __CLPK_integer, __vl: *mut __CLPK_doublereal,
-                   __vu: *mut __CLPK_doublereal,
-                   __d__: *mut __CLPK_doublereal, __l: *mut __CLPK_doublereal,
+                   __vu: *mut __CLPK_doublereal, __d__: *mut __CLPK_doublereal,
+                   __l: *mut __CLPK_doublereal,
                    __pivmin: *mut __CLPK_doublereal,
                    __isplit: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __dol: *mut __CLPK_integer, __dou: *mut __CLPK_integer,
                    __minrgp: *mut __CLPK_doublereal,
                    __rtol1: *mut __CLPK_doublereal,
                    __rtol2: *mut __CLPK_doublereal,
-                   __w: *mut __CLPK_doublereal,
-                   __werr: *mut __CLPK_doublereal,
+                   __w: *mut __CLPK_doublereal, __werr: *mut __CLPK_doublereal,
                    __wgap: *mut __CLPK_doublereal,
                    __iblock: *mut __CLPK_integer,
                    __indexw: *mut __CLPK_integer,
@@ -9281,30 +9164,25 @@ extern "C" {
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
-                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __iwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zlarscl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                      __d__: *mut __CLPK_doublereal,
-                     __x: *mut __CLPK_doublecomplex,
-                     __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                     __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer);
     pub fn zlartg_(__f: *mut __CLPK_doublecomplex,
                    __g: *mut __CLPK_doublecomplex,
                    __cs: *mut __CLPK_doublereal,
                    __sn: *mut __CLPK_doublecomplex,
-                   __r__: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __r__: *mut __CLPK_doublecomplex);
     pub fn zlartv_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
-                   __incx: *mut __CLPK_integer,
-                   __y: *mut __CLPK_doublecomplex,
+                   __incx: *mut __CLPK_integer, __y: *mut __CLPK_doublecomplex,
                    __incy: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                   __s: *mut __CLPK_doublecomplex,
-                   __incc: *mut __CLPK_integer) -> ::libc::c_int;
+                   __s: *mut __CLPK_doublecomplex, __incc: *mut __CLPK_integer);
     pub fn zlarz_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __l: *mut __CLPK_integer,
                   __v: *mut __CLPK_doublecomplex, __incv: *mut __CLPK_integer,
                   __tau: *mut __CLPK_doublecomplex,
-                  __c__: *mut __CLPK_doublecomplex,
-                  __ldc: *mut __CLPK_integer,
-                  __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                  __c__: *mut __CLPK_doublecomplex, __ldc: *mut __CLPK_integer,
+                  __work: *mut __CLPK_doublecomplex, __side_len: __CLPK_ftnlen);
     pub fn zlarzb_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __m: *mut __CLPK_integer,
@@ -9315,109 +9193,114 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __ldwork: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldwork: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen,
+                   __storev_len: __CLPK_ftnlen);
     pub fn zlarzt_(__direct: *mut ::libc::c_char,
                    __storev: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __ldv: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
-                   __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
+                   __direct_len: __CLPK_ftnlen, __storev_len: __CLPK_ftnlen);
     pub fn zlascl_(__type__: *mut ::libc::c_char, __kl: *mut __CLPK_integer,
                    __ku: *mut __CLPK_integer, __cfrom: *mut __CLPK_doublereal,
                    __cto: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __type_len: __CLPK_ftnlen);
     pub fn zlascl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                     __d__: *mut __CLPK_doublereal,
-                    __x: *mut __CLPK_doublecomplex,
-                    __ldx: *mut __CLPK_integer) -> ::libc::c_int;
+                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer);
     pub fn zlaset_(__uplo: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublecomplex,
                    __beta: *mut __CLPK_doublecomplex,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zlasr_(__side: *mut ::libc::c_char, __pivot: *mut ::libc::c_char,
                   __direct: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                   __n: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
                   __s: *mut __CLPK_doublereal, __a: *mut __CLPK_doublecomplex,
-                  __lda: *mut __CLPK_integer) -> ::libc::c_int;
+                  __lda: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                  __pivot_len: __CLPK_ftnlen, __direct_len: __CLPK_ftnlen);
     pub fn zlassq_(__n: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
                    __incx: *mut __CLPK_integer,
                    __scale: *mut __CLPK_doublereal,
-                   __sumsq: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __sumsq: *mut __CLPK_doublereal);
     pub fn zlaswp_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __k1: *mut __CLPK_integer,
                    __k2: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
-                   __incx: *mut __CLPK_integer) -> ::libc::c_int;
+                   __incx: *mut __CLPK_integer);
     pub fn zlasyf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __kb: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublecomplex, __ldw: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __w: *mut __CLPK_doublecomplex,
+                   __ldw: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zlat2c_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __sa: *mut __CLPK_complex, __ldsa: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zlatbs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex,
+                   __ldab: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn zlatdf_(__ijob: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer,
                    __rhs: *mut __CLPK_doublecomplex,
                    __rdsum: *mut __CLPK_doublereal,
                    __rdscal: *mut __CLPK_doublereal,
-                   __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __jpiv: *mut __CLPK_integer);
     pub fn zlatps_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                    __x: *mut __CLPK_doublecomplex,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn zlatrd_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nb: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __e: *mut __CLPK_doublereal,
                    __tau: *mut __CLPK_doublecomplex,
-                   __w: *mut __CLPK_doublecomplex, __ldw: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __w: *mut __CLPK_doublecomplex, __ldw: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zlatrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __normin: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
                    __scale: *mut __CLPK_doublereal,
                    __cnorm: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen,
+                   __normin_len: __CLPK_ftnlen);
     pub fn zlatrz_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __l: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
-                   __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublecomplex);
     pub fn zlatzm_(__side: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __v: *mut __CLPK_doublecomplex,
                    __incv: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __c1: *mut __CLPK_doublecomplex,
-                   __c2: *mut __CLPK_doublecomplex,
-                   __ldc: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                   __c2: *mut __CLPK_doublecomplex, __ldc: *mut __CLPK_integer,
+                   __work: *mut __CLPK_doublecomplex, __side_len: __CLPK_ftnlen);
     pub fn zlauu2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zlauum_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpbcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer,
@@ -9425,13 +9308,13 @@ extern "C" {
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpbequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
                    __ldab: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpbrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
@@ -9444,17 +9327,16 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpbstf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpbsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
-                  __ab: *mut __CLPK_doublecomplex,
-                  __ldab: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ab: *mut __CLPK_doublecomplex, __ldab: *mut __CLPK_integer,
+                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpbsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __kd: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
@@ -9462,115 +9344,114 @@ extern "C" {
                    __ldab: *mut __CLPK_integer,
                    __afb: *mut __CLPK_doublecomplex,
                    __ldafb: *mut __CLPK_integer, __equed: *mut ::libc::c_char,
-                   __s: *mut __CLPK_doublereal,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __s: *mut __CLPK_doublereal, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn zpbtf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpbtrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpbtrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpftrf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpftrs_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpocon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpoequ_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zpoequb_(__n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                     __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                     __scond: *mut __CLPK_doublereal,
-                    __amax: *mut __CLPK_doublereal,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zporfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __af: *mut __CLPK_doublecomplex,
-                   __ldaf: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublecomplex,
+                   __ldaf: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zposv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zposvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __af: *mut __CLPK_doublecomplex,
                    __ldaf: *mut __CLPK_integer, __equed: *mut ::libc::c_char,
-                   __s: *mut __CLPK_doublereal,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __s: *mut __CLPK_doublereal, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn zpotf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpotrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpotri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpotrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zppcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zppequ_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __s: *mut __CLPK_doublereal,
                    __scond: *mut __CLPK_doublereal,
-                   __amax: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __amax: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
@@ -9581,12 +9462,11 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zppsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __nrhs: *mut __CLPK_integer,
-                  __ap: *mut __CLPK_doublecomplex,
+                  __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zppsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
@@ -9599,41 +9479,41 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __equed_len: __CLPK_ftnlen);
     pub fn zpptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zpstf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zpstrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __piv: *mut __CLPK_integer, __rank: *mut __CLPK_integer,
                    __tol: *mut __CLPK_doublereal,
-                   __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __work: *mut __CLPK_doublereal, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zptcon_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublecomplex,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
-                   __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __rwork: *mut __CLPK_doublereal, __info: *mut __CLPK_integer);
     pub fn zpteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __compz_len: __CLPK_ftnlen);
     pub fn zptrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublecomplex,
@@ -9645,12 +9525,12 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zptsv_(__n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                   __d__: *mut __CLPK_doublereal,
                   __e: *mut __CLPK_doublecomplex,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                  __info: *mut __CLPK_integer) -> ::libc::c_int;
+                  __info: *mut __CLPK_integer);
     pub fn zptsvx_(__fact: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublecomplex,
@@ -9663,88 +9543,84 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen);
     pub fn zpttrf_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
-                   __e: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __e: *mut __CLPK_doublecomplex, __info: *mut __CLPK_integer);
     pub fn zpttrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zptts2_(__iuplo: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublecomplex,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer);
     pub fn zrot_(__n: *mut __CLPK_integer, __cx: *mut __CLPK_doublecomplex,
                  __incx: *mut __CLPK_integer, __cy: *mut __CLPK_doublecomplex,
                  __incy: *mut __CLPK_integer, __c__: *mut __CLPK_doublereal,
-                 __s: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                 __s: *mut __CLPK_doublecomplex);
     pub fn zspcon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zspmv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __ap: *mut __CLPK_doublecomplex,
                   __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
                   __beta: *mut __CLPK_doublecomplex,
-                  __y: *mut __CLPK_doublecomplex, __incy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __y: *mut __CLPK_doublecomplex, __incy: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zspr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                  __alpha: *mut __CLPK_doublecomplex,
                  __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
-                 __ap: *mut __CLPK_doublecomplex) -> ::libc::c_int;
+                 __ap: *mut __CLPK_doublecomplex, __uplo_len: __CLPK_ftnlen);
     pub fn zsprfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __afp: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zspsv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                  __nrhs: *mut __CLPK_integer,
-                  __ap: *mut __CLPK_doublecomplex,
+                  __nrhs: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
-                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zspsvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __afp: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __rcond: *mut __CLPK_doublereal,
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __rcond: *mut __CLPK_doublereal,
                    __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zsptrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zsptri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zsptrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __ipiv: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zstedc_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
@@ -9752,23 +9628,21 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __lrwork: *mut __CLPK_integer,
-                   __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lrwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __compz_len: __CLPK_ftnlen);
     pub fn zstegr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
                    __vu: *mut __CLPK_doublereal, __il: *mut __CLPK_integer,
-                   __iu: *mut __CLPK_integer,
-                   __abstol: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
-                   __w: *mut __CLPK_doublereal,
+                   __iu: *mut __CLPK_integer, __abstol: *mut __CLPK_doublereal,
+                   __m: *mut __CLPK_integer, __w: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __isuppz: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn zstein_(__n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __m: *mut __CLPK_integer,
                    __w: *mut __CLPK_doublereal, __iblock: *mut __CLPK_integer,
@@ -9776,7 +9650,7 @@ extern "C" {
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
                    __iwork: *mut __CLPK_integer, __ifail: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zstemr_(__jobz: *mut ::libc::c_char, __range: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __d__: *mut __CLPK_doublereal,
                    __e: *mut __CLPK_doublereal, __vl: *mut __CLPK_doublereal,
@@ -9789,43 +9663,42 @@ extern "C" {
                    __tryrac: *mut __CLPK_logical,
                    __work: *mut __CLPK_doublereal,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobz_len: __CLPK_ftnlen, __range_len: __CLPK_ftnlen);
     pub fn zsteqr_(__compz: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __d__: *mut __CLPK_doublereal, __e: *mut __CLPK_doublereal,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __work: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __compz_len: __CLPK_ftnlen);
     pub fn zsycon_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __anorm: *mut __CLPK_doublereal,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zsyequb_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                    __a: *mut __CLPK_doublecomplex,
-                    __lda: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
+                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
+                    __s: *mut __CLPK_doublereal,
                     __scond: *mut __CLPK_doublereal,
                     __amax: *mut __CLPK_doublereal,
                     __work: *mut __CLPK_doublecomplex,
-                    __info: *mut __CLPK_integer) -> ::libc::c_int;
+                    __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zsymv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __alpha: *mut __CLPK_doublecomplex,
                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                   __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
                   __beta: *mut __CLPK_doublecomplex,
-                  __y: *mut __CLPK_doublecomplex, __incy: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __y: *mut __CLPK_doublecomplex, __incy: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zsyr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                  __alpha: *mut __CLPK_doublecomplex,
                  __x: *mut __CLPK_doublecomplex, __incx: *mut __CLPK_integer,
-                 __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                 __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
+                 __uplo_len: __CLPK_ftnlen);
     pub fn zsyrfs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __af: *mut __CLPK_doublecomplex,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __af: *mut __CLPK_doublecomplex,
                    __ldaf: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                    __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
@@ -9833,14 +9706,14 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zsysv_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
                   __work: *mut __CLPK_doublecomplex,
-                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                  __uplo_len: __CLPK_ftnlen);
     pub fn zsysvx_(__fact: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -9854,28 +9727,28 @@ extern "C" {
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __fact_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zsytf2_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ipiv: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zsytrf_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zsytri_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ipiv: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zsytrs_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __ipiv: *mut __CLPK_integer,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __ipiv: *mut __CLPK_integer,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ztbcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __ab: *mut __CLPK_doublecomplex,
@@ -9883,46 +9756,52 @@ extern "C" {
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztbrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __ldab: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztbtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __kd: *mut __CLPK_integer, __nrhs: *mut __CLPK_integer,
                    __ab: *mut __CLPK_doublecomplex,
-                   __ldab: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldab: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ztfsm_(__transr: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                   __uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                   __diag: *mut ::libc::c_char, __m: *mut __CLPK_integer,
-                  __n: *mut __CLPK_integer,
-                  __alpha: *mut __CLPK_doublecomplex,
+                  __n: *mut __CLPK_integer, __alpha: *mut __CLPK_doublecomplex,
                   __a: *mut __CLPK_doublecomplex,
-                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                  __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                  __transr_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen,
+                  __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                  __diag_len: __CLPK_ftnlen);
     pub fn ztftri_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __a: *mut __CLPK_doublecomplex, __info: *mut __CLPK_integer,
+                   __transr_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ztfttp_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_doublecomplex,
                    __ap: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ztfttr_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __arf: *mut __CLPK_doublecomplex,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ztgevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublecomplex, __lds: *mut __CLPK_integer,
@@ -9931,10 +9810,10 @@ extern "C" {
                    __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ztgex2_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
@@ -9942,7 +9821,7 @@ extern "C" {
                    __ldq: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __j1: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn ztgexc_(__wantq: *mut __CLPK_logical, __wantz: *mut __CLPK_logical,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
@@ -9950,13 +9829,12 @@ extern "C" {
                    __ldq: *mut __CLPK_integer,
                    __z__: *mut __CLPK_doublecomplex,
                    __ldz: *mut __CLPK_integer, __ifst: *mut __CLPK_integer,
-                   __ilst: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ilst: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn ztgsen_(__ijob: *mut __CLPK_integer, __wantq: *mut __CLPK_logical,
-                   __wantz: *mut __CLPK_logical,
-                   __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
+                   __wantz: *mut __CLPK_logical, __select: *mut __CLPK_logical,
+                   __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer,
                    __alpha: *mut __CLPK_doublecomplex,
                    __beta: *mut __CLPK_doublecomplex,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
@@ -9966,8 +9844,7 @@ extern "C" {
                    __dif: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __liwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn ztgsja_(__jobu: *mut ::libc::c_char, __jobv: *mut ::libc::c_char,
                    __jobq: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __p: *mut __CLPK_integer, __n: *mut __CLPK_integer,
@@ -9982,8 +9859,9 @@ extern "C" {
                    __v: *mut __CLPK_doublecomplex, __ldv: *mut __CLPK_integer,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __ncycle: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __ncycle: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __jobu_len: __CLPK_ftnlen, __jobv_len: __CLPK_ftnlen,
+                   __jobq_len: __CLPK_ftnlen);
     pub fn ztgsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -9993,10 +9871,10 @@ extern "C" {
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __dif: *mut __CLPK_doublereal, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ztgsy2_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -10006,11 +9884,10 @@ extern "C" {
                    __d__: *mut __CLPK_doublecomplex,
                    __ldd: *mut __CLPK_integer, __e: *mut __CLPK_doublecomplex,
                    __lde: *mut __CLPK_integer, __f: *mut __CLPK_doublecomplex,
-                   __ldf: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
+                   __ldf: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
                    __rdsum: *mut __CLPK_doublereal,
                    __rdscal: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn ztgsyl_(__trans: *mut ::libc::c_char, __ijob: *mut __CLPK_integer,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
@@ -10020,19 +9897,19 @@ extern "C" {
                    __d__: *mut __CLPK_doublecomplex,
                    __ldd: *mut __CLPK_integer, __e: *mut __CLPK_doublecomplex,
                    __lde: *mut __CLPK_integer, __f: *mut __CLPK_doublecomplex,
-                   __ldf: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
+                   __ldf: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
                    __dif: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __lwork: *mut __CLPK_integer, __iwork: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __trans_len: __CLPK_ftnlen);
     pub fn ztpcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztprfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
@@ -10043,31 +9920,36 @@ extern "C" {
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztptri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ztptrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __nrhs: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztpttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
                    __arf: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ztpttr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ztrcon_(__norm: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __rcond: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __norm_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztrevc_(__side: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
@@ -10075,26 +9957,26 @@ extern "C" {
                    __ldvl: *mut __CLPK_integer,
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ztrexc_(__compq: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
                    __ifst: *mut __CLPK_integer, __ilst: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __compq_len: __CLPK_ftnlen);
     pub fn ztrrfs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __x: *mut __CLPK_doublecomplex, __ldx: *mut __CLPK_integer,
-                   __ferr: *mut __CLPK_doublereal,
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __x: *mut __CLPK_doublecomplex,
+                   __ldx: *mut __CLPK_integer, __ferr: *mut __CLPK_doublereal,
                    __berr: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztrsen_(__job: *mut ::libc::c_char, __compq: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
@@ -10102,8 +9984,8 @@ extern "C" {
                    __w: *mut __CLPK_doublecomplex, __m: *mut __CLPK_integer,
                    __s: *mut __CLPK_doublereal, __sep: *mut __CLPK_doublereal,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __job_len: __CLPK_ftnlen, __compq_len: __CLPK_ftnlen);
     pub fn ztrsna_(__job: *mut ::libc::c_char, __howmny: *mut ::libc::c_char,
                    __select: *mut __CLPK_logical, __n: *mut __CLPK_integer,
                    __t: *mut __CLPK_doublecomplex, __ldt: *mut __CLPK_integer,
@@ -10112,125 +9994,121 @@ extern "C" {
                    __vr: *mut __CLPK_doublecomplex,
                    __ldvr: *mut __CLPK_integer, __s: *mut __CLPK_doublereal,
                    __sep: *mut __CLPK_doublereal, __mm: *mut __CLPK_integer,
-                   __m: *mut __CLPK_integer,
-                   __work: *mut __CLPK_doublecomplex,
+                   __m: *mut __CLPK_integer, __work: *mut __CLPK_doublecomplex,
                    __ldwork: *mut __CLPK_integer,
                    __rwork: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __job_len: __CLPK_ftnlen,
+                   __howmny_len: __CLPK_ftnlen);
     pub fn ztrsyl_(__trana: *mut ::libc::c_char, __tranb: *mut ::libc::c_char,
                    __isgn: *mut __CLPK_integer, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
                    __ldb: *mut __CLPK_integer,
                    __c__: *mut __CLPK_doublecomplex,
-                   __ldc: *mut __CLPK_integer,
-                   __scale: *mut __CLPK_doublereal,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __ldc: *mut __CLPK_integer, __scale: *mut __CLPK_doublereal,
+                   __info: *mut __CLPK_integer, __trana_len: __CLPK_ftnlen,
+                   __tranb_len: __CLPK_ftnlen);
     pub fn ztrti2_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztrtri_(__uplo: *mut ::libc::c_char, __diag: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
-                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lda: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __diag_len: __CLPK_ftnlen);
     pub fn ztrtrs_(__uplo: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __diag: *mut ::libc::c_char, __n: *mut __CLPK_integer,
-                   __nrhs: *mut __CLPK_integer,
-                   __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
-                   __b: *mut __CLPK_doublecomplex, __ldb: *mut __CLPK_integer,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __nrhs: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
+                   __lda: *mut __CLPK_integer, __b: *mut __CLPK_doublecomplex,
+                   __ldb: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen,
+                   __diag_len: __CLPK_ftnlen);
     pub fn ztrttf_(__transr: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __arf: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __transr_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn ztrttp_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn ztzrqf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn ztzrzf_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zung2l_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zung2r_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zungbr_(__vect: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen);
     pub fn zunghr_(__n: *mut __CLPK_integer, __ilo: *mut __CLPK_integer,
                    __ihi: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zungl2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zunglq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zungql_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zungqr_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zungr2_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer);
     pub fn zungrq_(__m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
                    __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer);
     pub fn zungtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __a: *mut __CLPK_doublecomplex, __lda: *mut __CLPK_integer,
                    __tau: *mut __CLPK_doublecomplex,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __uplo_len: __CLPK_ftnlen);
     pub fn zunm2l_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10239,7 +10117,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunm2r_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10248,7 +10127,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunmbr_(__vect: *mut ::libc::c_char, __side: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __k: *mut __CLPK_integer,
@@ -10257,8 +10137,9 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __vect_len: __CLPK_ftnlen, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunmhr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __ilo: *mut __CLPK_integer, __ihi: *mut __CLPK_integer,
@@ -10267,8 +10148,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunml2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10277,7 +10158,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunmlq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10286,8 +10168,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunmql_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10296,8 +10178,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunmqr_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10306,8 +10188,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunmr2_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10316,7 +10198,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunmr3_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
@@ -10325,7 +10208,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zunmrq_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10334,8 +10218,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunmrz_(__side: *mut ::libc::c_char, __trans: *mut ::libc::c_char,
                    __m: *mut __CLPK_integer, __n: *mut __CLPK_integer,
                    __k: *mut __CLPK_integer, __l: *mut __CLPK_integer,
@@ -10344,8 +10228,8 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn zunmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __a: *mut __CLPK_doublecomplex,
@@ -10354,14 +10238,15 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer)
-     -> ::libc::c_int;
+                   __lwork: *mut __CLPK_integer, __info: *mut __CLPK_integer,
+                   __side_len: __CLPK_ftnlen, __uplo_len: __CLPK_ftnlen,
+                   __trans_len: __CLPK_ftnlen);
     pub fn zupgtr_(__uplo: *mut ::libc::c_char, __n: *mut __CLPK_integer,
                    __ap: *mut __CLPK_doublecomplex,
                    __tau: *mut __CLPK_doublecomplex,
                    __q: *mut __CLPK_doublecomplex, __ldq: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __uplo_len: __CLPK_ftnlen);
     pub fn zupmtr_(__side: *mut ::libc::c_char, __uplo: *mut ::libc::c_char,
                    __trans: *mut ::libc::c_char, __m: *mut __CLPK_integer,
                    __n: *mut __CLPK_integer, __ap: *mut __CLPK_doublecomplex,
@@ -10369,41 +10254,101 @@ extern "C" {
                    __c__: *mut __CLPK_doublecomplex,
                    __ldc: *mut __CLPK_integer,
                    __work: *mut __CLPK_doublecomplex,
-                   __info: *mut __CLPK_integer) -> ::libc::c_int;
+                   __info: *mut __CLPK_integer, __side_len: __CLPK_ftnlen,
+                   __uplo_len: __CLPK_ftnlen, __trans_len: __CLPK_ftnlen);
     pub fn dlamc1_(__beta: *mut __CLPK_integer, __t: *mut __CLPK_integer,
-                   __rnd: *mut __CLPK_logical, __ieee1: *mut __CLPK_logical)
-     -> ::libc::c_int;
-    pub fn slamch_(__cmach: *mut ::libc::c_char) -> __CLPK_doublereal;
+                   __rnd: *mut __CLPK_logical, __ieee1: *mut __CLPK_logical);
+    pub fn slamch_(__cmach: *mut ::libc::c_char, __cmach_len: __CLPK_ftnlen)
+     -> __CLPK_real_return;
     pub fn slamc1_(__beta: *mut __CLPK_integer, __t: *mut __CLPK_integer,
-                   __rnd: *mut __CLPK_logical, __ieee1: *mut __CLPK_logical)
-     -> ::libc::c_int;
+                   __rnd: *mut __CLPK_logical, __ieee1: *mut __CLPK_logical);
     pub fn slamc2_(__beta: *mut __CLPK_integer, __t: *mut __CLPK_integer,
                    __rnd: *mut __CLPK_logical, __eps: *mut __CLPK_real,
                    __emin: *mut __CLPK_integer, __rmin: *mut __CLPK_real,
-                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_real)
-     -> ::libc::c_int;
+                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_real);
     pub fn slamc3_(__a: *mut __CLPK_real, __b: *mut __CLPK_real)
-     -> __CLPK_doublereal;
+     -> __CLPK_real_return;
     pub fn slamc4_(__emin: *mut __CLPK_integer, __start: *mut __CLPK_real,
-                   __base: *mut __CLPK_integer) -> ::libc::c_int;
+                   __base: *mut __CLPK_integer);
     pub fn slamc5_(__beta: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __emin: *mut __CLPK_integer, __ieee: *mut __CLPK_logical,
-                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_real)
-     -> ::libc::c_int;
-    pub fn dlamch_(__cmach: *mut ::libc::c_char) -> __CLPK_doublereal;
+                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_real);
+    pub fn dlamch_(__cmach: *mut ::libc::c_char, __cmach_len: __CLPK_ftnlen)
+     -> __CLPK_doublereal;
     pub fn dlamc2_(__beta: *mut __CLPK_integer, __t: *mut __CLPK_integer,
                    __rnd: *mut __CLPK_logical, __eps: *mut __CLPK_doublereal,
-                   __emin: *mut __CLPK_integer,
-                   __rmin: *mut __CLPK_doublereal,
-                   __emax: *mut __CLPK_integer,
-                   __rmax: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __emin: *mut __CLPK_integer, __rmin: *mut __CLPK_doublereal,
+                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_doublereal);
     pub fn dlamc3_(__a: *mut __CLPK_doublereal, __b: *mut __CLPK_doublereal)
      -> __CLPK_doublereal;
     pub fn dlamc4_(__emin: *mut __CLPK_integer,
-                   __start: *mut __CLPK_doublereal,
-                   __base: *mut __CLPK_integer) -> ::libc::c_int;
+                   __start: *mut __CLPK_doublereal, __base: *mut __CLPK_integer);
     pub fn dlamc5_(__beta: *mut __CLPK_integer, __p: *mut __CLPK_integer,
                    __emin: *mut __CLPK_integer, __ieee: *mut __CLPK_logical,
-                   __emax: *mut __CLPK_integer,
-                   __rmax: *mut __CLPK_doublereal) -> ::libc::c_int;
+                   __emax: *mut __CLPK_integer, __rmax: *mut __CLPK_doublereal);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32, f64};
+    use libc::c_char;
+    use num::complex::Complex64;
+    use ll::*;
+
+    #[test]
+    fn lamch() {
+        unsafe {
+            assert_eq!(dlamch_(&mut (b'E' as c_char), 1), f64::EPSILON * 0.5);
+            assert_eq!(dlamch_(&mut (b'B' as c_char), 1), 2.0);
+            assert_eq!(slamch_(&mut (b'P' as c_char), 1) as f32, f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn lange() {
+        let mut a = vec![1.0f64, 3.0, -2.0, 4.0];
+        let mut s = vec![1.0f32, 3.0, -2.0, 4.0];
+        let mut work = vec![0.0f64; 2];
+        let mut s_work = vec![0.0f32; 2];
+
+        unsafe {
+            assert_eq!(dlange_(&mut (b'M' as c_char), &mut 2, &mut 2,
+                a.as_mut_ptr(), &mut 2, work.as_mut_ptr(), 1), 4.0);
+            assert_eq!(dlange_(&mut (b'1' as c_char), &mut 2, &mut 2,
+                a.as_mut_ptr(), &mut 2, work.as_mut_ptr(), 1), 6.0);
+            assert_eq!(dlange_(&mut (b'I' as c_char), &mut 2, &mut 2,
+                a.as_mut_ptr(), &mut 2, work.as_mut_ptr(), 1), 7.0);
+            assert_eq!(slange_(&mut (b'I' as c_char), &mut 2, &mut 2,
+                s.as_mut_ptr(), &mut 2, s_work.as_mut_ptr(), 1) as f32, 7.0);
+        }
+    }
+
+    #[test]
+    fn lapy2() {
+        unsafe {
+            assert_eq!(dlapy2_(&mut 3.0, &mut 4.0), 5.0);
+            assert_eq!(slapy2_(&mut 3.0, &mut 4.0) as f32, 5.0);
+        }
+    }
+
+    #[test]
+    fn ladiv() {
+        let mut x = Complex64::new(-5.0, 10.0);
+        let mut y = Complex64::new(1.0, 2.0);
+
+        let z = unsafe { zladiv(&mut x, &mut y) };
+        assert_eq!(z, Complex64::new(3.0, 4.0));
+    }
+
+    #[cfg(lapack_abi = "f2c")]
+    unsafe fn zladiv(x: &mut Complex64, y: &mut Complex64) -> Complex64 {
+        let mut z = Complex64::new(0.0, 0.0);
+        zladiv_(&mut z, x, y);
+        z
+    }
+
+    #[cfg(not(lapack_abi = "f2c"))]
+    unsafe fn zladiv(x: &mut Complex64, y: &mut Complex64) -> Complex64 {
+        zladiv_(x, y)
+    }
 }
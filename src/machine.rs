@@ -0,0 +1,124 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use ll::*;
+use scalar::Scalar;
+
+/// Whether floating point addition rounds or chops its result.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Chop,
+    Nearest,
+}
+
+/// Floating point parameters as seen by the linked LAPACK (`lamch`).
+///
+/// Complex types report the parameters of their real component type.
+pub trait MachineParams {
+    type Real;
+
+    /// Relative machine epsilon, the unit roundoff used by LAPACK.
+    fn lapack_eps() -> Self::Real;
+    /// Smallest number whose reciprocal does not overflow.
+    fn safe_min() -> Self::Real;
+    /// Base of the floating point representation.
+    fn base() -> Self::Real;
+    /// `lapack_eps() * base()`.
+    fn precision() -> Self::Real;
+    /// Number of base digits in the mantissa.
+    fn mantissa_digits() -> Self::Real;
+    /// Whether addition rounds to nearest or chops, from `lamch('R')`, which
+    /// reports one for rounding and zero for chopping.
+    fn rounding_mode() -> Rounding;
+    /// Minimum exponent before gradual underflow.
+    fn min_exponent() -> Self::Real;
+    /// Underflow threshold, `base() ^ (min_exponent() - 1)`.
+    fn underflow() -> Self::Real;
+    /// Largest exponent before overflow.
+    fn max_exponent() -> Self::Real;
+    /// Overflow threshold, `(base() ^ max_exponent()) * (1 - lapack_eps())`.
+    fn overflow() -> Self::Real;
+}
+
+macro_rules! machine_impl(($($t: ident), +) => ($(
+    impl MachineParams for $t {
+        type Real = $t;
+
+        fn lapack_eps() -> $t { $t::lamch(b'E') }
+        fn safe_min() -> $t { $t::lamch(b'S') }
+        fn base() -> $t { $t::lamch(b'B') }
+        fn precision() -> $t { $t::lamch(b'P') }
+        fn mantissa_digits() -> $t { $t::lamch(b'N') }
+
+        fn rounding_mode() -> Rounding {
+            match $t::lamch(b'R') {
+                x if x == 0.0 => Rounding::Chop,
+                _ => Rounding::Nearest,
+            }
+        }
+
+        fn min_exponent() -> $t { $t::lamch(b'M') }
+        fn underflow() -> $t { $t::lamch(b'U') }
+        fn max_exponent() -> $t { $t::lamch(b'L') }
+        fn overflow() -> $t { $t::lamch(b'O') }
+    }
+
+    impl Lamch for $t {
+        fn lamch(cmach: u8) -> $t {
+            unsafe {
+                prefix!($t, lamch_)((cmach as i8).as_mut(), 1) as $t
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_machine_impl(($($t: ident: $r: ident), +) => ($(
+    impl MachineParams for $t {
+        type Real = $r;
+
+        fn lapack_eps() -> $r { $r::lapack_eps() }
+        fn safe_min() -> $r { $r::safe_min() }
+        fn base() -> $r { $r::base() }
+        fn precision() -> $r { $r::precision() }
+        fn mantissa_digits() -> $r { $r::mantissa_digits() }
+        fn rounding_mode() -> Rounding { $r::rounding_mode() }
+        fn min_exponent() -> $r { $r::min_exponent() }
+        fn underflow() -> $r { $r::underflow() }
+        fn max_exponent() -> $r { $r::max_exponent() }
+        fn overflow() -> $r { $r::overflow() }
+    }
+)+));
+
+trait Lamch {
+    fn lamch(cmach: u8) -> Self;
+}
+
+machine_impl!(f32, f64);
+complex_machine_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod machine_tests {
+    use std::{f32, f64};
+    use num::complex::Complex64;
+    use machine::{MachineParams, Rounding};
+
+    #[test]
+    fn real() {
+        assert_eq!(f64::lapack_eps(), f64::EPSILON * 0.5);
+        assert_eq!(f64::precision(), f64::EPSILON);
+        assert_eq!(f64::base(), 2.0);
+        assert_eq!(f64::mantissa_digits(), 53.0);
+        assert_eq!(f64::rounding_mode(), Rounding::Nearest);
+        assert_eq!(f32::safe_min(), f32::MIN_POSITIVE);
+        assert_eq!(f32::overflow(), f32::MAX);
+    }
+
+    #[test]
+    fn complex() {
+        assert_eq!(Complex64::lapack_eps(), f64::lapack_eps());
+    }
+}
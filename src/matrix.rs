@@ -5,6 +5,7 @@
 #[cfg(not(feature = "default"))]
 use libc::c_int;
 use types::{
+    Diagonal,
     Symmetry,
 };
 #[cfg(not(feature = "default"))]
@@ -28,20 +29,22 @@ pub trait BandMatrix<T>: Matrix<T> {
     fn sup_diagonals(&self) -> c_int;
 }
 
-pub trait TridiagonalMatrix<T>: Matrix<T> {
-    fn as_ptrs(&self) -> (*const T, *const T, *const T);
-    fn as_mut_ptrs(&self) -> (*mut T, *mut T, *mut T);
+pub trait SymmetricMatrix<T>: Matrix<T> {
+    fn symmetry(&self) -> Symmetry;
 }
 
-pub trait SymmetricMatrix<T>: Matrix<T> {
+/// A matrix of which only the upper or lower triangle is referenced. With
+/// `Diagonal::Unit` the diagonal is taken to be all ones and is not read.
+pub trait TriangularMatrix<T>: Matrix<T> {
     fn symmetry(&self) -> Symmetry;
+    fn diagonal(&self) -> Diagonal;
 }
 
 #[cfg(test)]
 pub mod tests {
     use libc::c_int;
-    use matrix::Matrix;
-    use types::Order;
+    use matrix::{Matrix, SymmetricMatrix, TriangularMatrix};
+    use types::{Diagonal, Order, Symmetry};
 
     pub struct M<T>(pub Order, pub c_int, pub c_int, pub Vec<T>);
 
@@ -66,4 +69,68 @@ pub mod tests {
             (&mut self.3[..]).as_mut_ptr()
         }
     }
+
+    pub struct S<T>(pub Symmetry, pub M<T>);
+
+    impl<T> Matrix<T> for S<T> {
+        fn rows(&self) -> c_int {
+            self.1.rows()
+        }
+
+        fn cols(&self) -> c_int {
+            self.1.cols()
+        }
+
+        fn order(&self) -> Order {
+            self.1.order()
+        }
+
+        fn as_ptr(&self) -> *const T {
+            self.1.as_ptr()
+        }
+
+        fn as_mut_ptr(&mut self) -> *mut T {
+            self.1.as_mut_ptr()
+        }
+    }
+
+    impl<T> SymmetricMatrix<T> for S<T> {
+        fn symmetry(&self) -> Symmetry {
+            self.0
+        }
+    }
+
+    pub struct Tr<T>(pub Symmetry, pub Diagonal, pub M<T>);
+
+    impl<T> Matrix<T> for Tr<T> {
+        fn rows(&self) -> c_int {
+            self.2.rows()
+        }
+
+        fn cols(&self) -> c_int {
+            self.2.cols()
+        }
+
+        fn order(&self) -> Order {
+            self.2.order()
+        }
+
+        fn as_ptr(&self) -> *const T {
+            self.2.as_ptr()
+        }
+
+        fn as_mut_ptr(&mut self) -> *mut T {
+            self.2.as_mut_ptr()
+        }
+    }
+
+    impl<T> TriangularMatrix<T> for Tr<T> {
+        fn symmetry(&self) -> Symmetry {
+            self.0
+        }
+
+        fn diagonal(&self) -> Diagonal {
+            self.1
+        }
+    }
 }
@@ -0,0 +1,352 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use band::BandStorage;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+    TriangularMatrix,
+};
+use packed::PackedSymmetric;
+use rfp::RfpMatrix;
+use scalar::Scalar;
+use tridiagonal::Tridiagonal;
+use types::Norm;
+use util::{col_dims, col_norm, col_uplo, rfp_trans};
+
+/// Norm of a general `m × n` matrix.
+pub trait Lange<Real>: Sized {
+    fn lange(norm: Norm, a: &Matrix<Self>) -> Real;
+}
+
+/// Norm of a symmetric matrix, reading only the triangle given by its
+/// symmetry.
+pub trait Lansy<Real>: Sized {
+    fn lansy(norm: Norm, a: &SymmetricMatrix<Self>) -> Real;
+}
+
+/// Norm of a complex Hermitian matrix, reading only the triangle given by its
+/// symmetry.
+pub trait Lanhe<Real>: Sized {
+    fn lanhe(norm: Norm, a: &SymmetricMatrix<Self>) -> Real;
+}
+
+/// Norm of a square band matrix. Panics if `a` is not square.
+pub trait Langb<Real>: Sized {
+    fn langb(norm: Norm, a: &BandStorage<Self>) -> Real;
+}
+
+/// Norm of a tridiagonal matrix.
+pub trait Langt<Real>: Sized {
+    fn langt(norm: Norm, a: &Tridiagonal<Self>) -> Real;
+}
+
+/// Norm of an upper or lower trapezoidal matrix.
+pub trait Lantr<Real>: Sized {
+    fn lantr(norm: Norm, a: &TriangularMatrix<Self>) -> Real;
+}
+
+/// Norm of a symmetric matrix in packed storage.
+pub trait Lansp<Real>: Sized {
+    fn lansp(norm: Norm, a: &PackedSymmetric<Self>) -> Real;
+}
+
+/// Norm of a real symmetric matrix in RFP format.
+pub trait Lansf<Real>: Sized {
+    fn lansf(norm: Norm, a: &RfpMatrix<Self>) -> Real;
+}
+
+/// Norm of a complex Hermitian matrix in RFP format.
+pub trait Lanhf<Real>: Sized {
+    fn lanhf(norm: Norm, a: &RfpMatrix<Self>) -> Real;
+}
+
+/// `norm()` for the packed, band, tridiagonal and RFP storage classes,
+/// dispatching to their `lan*` routine.
+pub trait MatrixNorm<Real> {
+    fn norm(&self, norm: Norm) -> Real;
+}
+
+/// `norm()` for any `Matrix`, read in full as a general matrix.
+pub trait GeneralNorm<T, Real> {
+    fn norm(&self, norm: Norm) -> Real;
+}
+
+/// `symmetric_norm()` for a `SymmetricMatrix`, reading only the triangle
+/// given by its symmetry.
+pub trait SymmetricNorm<T, Real> {
+    fn symmetric_norm(&self, norm: Norm) -> Real;
+}
+
+/// `triangular_norm()` for a `TriangularMatrix`, reading only its
+/// triangle.
+pub trait TriangularNorm<T, Real> {
+    fn triangular_norm(&self, norm: Norm) -> Real;
+}
+
+impl<T: Lange<Real>, Real, M: Matrix<T>> GeneralNorm<T, Real> for M {
+    fn norm(&self, norm: Norm) -> Real { T::lange(norm, self) }
+}
+
+impl<'a, T: Lange<Real>, Real> GeneralNorm<T, Real> for Matrix<T> + 'a {
+    fn norm(&self, norm: Norm) -> Real { T::lange(norm, self) }
+}
+
+impl<T: Lansy<Real>, Real, M: SymmetricMatrix<T>> SymmetricNorm<T, Real> for M {
+    fn symmetric_norm(&self, norm: Norm) -> Real { T::lansy(norm, self) }
+}
+
+impl<'a, T: Lansy<Real>, Real> SymmetricNorm<T, Real> for SymmetricMatrix<T> + 'a {
+    fn symmetric_norm(&self, norm: Norm) -> Real { T::lansy(norm, self) }
+}
+
+impl<T: Lantr<Real>, Real, M: TriangularMatrix<T>> TriangularNorm<T, Real> for M {
+    fn triangular_norm(&self, norm: Norm) -> Real { T::lantr(norm, self) }
+}
+
+impl<'a, T: Lantr<Real>, Real> TriangularNorm<T, Real> for TriangularMatrix<T> + 'a {
+    fn triangular_norm(&self, norm: Norm) -> Real { T::lantr(norm, self) }
+}
+
+impl<T: Langb<Real>, Real> MatrixNorm<Real> for BandStorage<T> {
+    fn norm(&self, norm: Norm) -> Real { T::langb(norm, self) }
+}
+
+impl<T: Langt<Real>, Real> MatrixNorm<Real> for Tridiagonal<T> {
+    fn norm(&self, norm: Norm) -> Real { T::langt(norm, self) }
+}
+
+impl<T: Lansp<Real>, Real> MatrixNorm<Real> for PackedSymmetric<T> {
+    fn norm(&self, norm: Norm) -> Real { T::lansp(norm, self) }
+}
+
+macro_rules! norm_impl(($($t: ident: $r: ident), +) => ($(
+    impl Lange<$r> for $t {
+        fn lange(norm: Norm, a: &Matrix<Self>) -> $r {
+            let (m, n, lda) = col_dims(a);
+            let norm = col_norm(a.order(), norm);
+            let mut work: Vec<$r> = vec![0.0; m as usize];
+
+            unsafe {
+                prefix!($t, lange_)(norm.as_i8().as_mut(),
+                    m.as_mut(), n.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    work.as_mut_ptr(), 1) as $r
+            }
+        }
+    }
+
+    impl Lansy<$r> for $t {
+        fn lansy(norm: Norm, a: &SymmetricMatrix<Self>) -> $r {
+            let (_, n, lda) = col_dims(a);
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut work: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, lansy_)(norm.as_i8().as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    work.as_mut_ptr(), 1, 1) as $r
+            }
+        }
+    }
+
+    impl Langb<$r> for $t {
+        fn langb(norm: Norm, a: &BandStorage<Self>) -> $r {
+            let n = a.cols();
+            assert!(a.rows() == n, "band matrix is not square");
+            let mut work: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, langb_)(norm.as_i8().as_mut(),
+                    n.as_mut(), a.sub_diagonals().as_mut(), a.sup_diagonals().as_mut(),
+                    a.as_band_ptr() as *mut _, a.lead().as_mut(),
+                    work.as_mut_ptr(), 1) as $r
+            }
+        }
+    }
+
+    impl Langt<$r> for $t {
+        fn langt(norm: Norm, a: &Tridiagonal<Self>) -> $r {
+            let (dl, d, du) = a.as_ptrs();
+
+            unsafe {
+                prefix!($t, langt_)(norm.as_i8().as_mut(),
+                    a.dim().as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    1) as $r
+            }
+        }
+    }
+
+    impl Lantr<$r> for $t {
+        fn lantr(norm: Norm, a: &TriangularMatrix<Self>) -> $r {
+            let (m, n, lda) = col_dims(a);
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let norm = col_norm(a.order(), norm);
+            let mut work: Vec<$r> = vec![0.0; m as usize];
+
+            unsafe {
+                prefix!($t, lantr_)(norm.as_i8().as_mut(),
+                    uplo.as_i8().as_mut(), a.diagonal().as_i8().as_mut(),
+                    m.as_mut(), n.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    work.as_mut_ptr(), 1, 1, 1) as $r
+            }
+        }
+    }
+
+    impl Lansp<$r> for $t {
+        fn lansp(norm: Norm, a: &PackedSymmetric<Self>) -> $r {
+            let n = a.dim();
+            let mut work: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, lansp_)(norm.as_i8().as_mut(), a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), a.as_ptr() as *mut _,
+                    work.as_mut_ptr(), 1, 1) as $r
+            }
+        }
+    }
+)+));
+
+macro_rules! real_norm_impl(($($t: ident), +) => ($(
+    impl MatrixNorm<$t> for RfpMatrix<$t> {
+        fn norm(&self, norm: Norm) -> $t { $t::lansf(norm, self) }
+    }
+
+    impl Lansf<$t> for $t {
+        fn lansf(norm: Norm, a: &RfpMatrix<Self>) -> $t {
+            let n = a.dim();
+            let mut work: Vec<$t> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, lansf_)(norm.as_i8().as_mut(),
+                    rfp_trans(a.transr(), b'T').as_mut(), a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), a.as_ptr() as *mut _,
+                    work.as_mut_ptr(), 1, 1, 1) as $t
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_norm_impl(($($t: ident: $r: ident), +) => ($(
+    impl MatrixNorm<$r> for RfpMatrix<$t> {
+        fn norm(&self, norm: Norm) -> $r { $t::lanhf(norm, self) }
+    }
+
+    impl Lanhf<$r> for $t {
+        fn lanhf(norm: Norm, a: &RfpMatrix<Self>) -> $r {
+            let n = a.dim();
+            let mut work: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, lanhf_)(norm.as_i8().as_mut(),
+                    rfp_trans(a.transr(), b'C').as_mut(), a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), a.as_ptr() as *mut _,
+                    work.as_mut_ptr(), 1, 1, 1) as $r
+            }
+        }
+    }
+
+    impl Lanhe<$r> for $t {
+        fn lanhe(norm: Norm, a: &SymmetricMatrix<Self>) -> $r {
+            let (_, n, lda) = col_dims(a);
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut work: Vec<$r> = vec![0.0; n as usize];
+
+            unsafe {
+                prefix!($t, lanhe_)(norm.as_i8().as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    work.as_mut_ptr(), 1, 1) as $r
+            }
+        }
+    }
+)+));
+
+norm_impl!(f32: f32, f64: f64, Complex32: f32, Complex64: f64);
+real_norm_impl!(f32, f64);
+complex_norm_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod norm_tests {
+    use matrix::{Matrix, SymmetricMatrix};
+    use band::BandStorage;
+    use norms::{GeneralNorm, Langb, Lange, Lansy, Lansp, MatrixNorm, SymmetricNorm, TriangularNorm};
+    use matrix::tests::{M, S, Tr};
+    use packed::PackedSymmetric;
+    use tridiagonal::Tridiagonal;
+    use types::{Diagonal, Norm};
+    use types::Order::*;
+    use types::Symmetry;
+
+    #[test]
+    fn general() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 3.0, -2.0, 4.0]);
+
+        assert_eq!(Lange::lange(Norm::MaxAbs, &a), 4.0f64);
+        assert_eq!(Lange::lange(Norm::One, &a), 6.0f64);
+        assert_eq!(Lange::lange(Norm::Infinity, &a), 7.0f64);
+        assert_eq!(Lange::lange(Norm::Frobenius, &a), 30.0f64.sqrt());
+    }
+
+    #[test]
+    fn general_row_major() {
+        let a = M(RowMajor, 2i32, 3i32, vec![1.0f64, -2.0, 0.0, 3.0, 4.0, 5.0]);
+
+        assert_eq!(Lange::lange(Norm::One, &a), 6.0f64);
+        assert_eq!(Lange::lange(Norm::Infinity, &a), 12.0f64);
+    }
+
+    #[test]
+    fn symmetric() {
+        // Only the upper triangle is read; the -9.0 is ignored.
+        let a = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![1.0f64, -9.0, -2.0, 4.0]));
+
+        assert_eq!(Lansy::lansy(Norm::One, &a), 6.0f64);
+        assert_eq!(Lansy::lansy(Norm::MaxAbs, &a), 4.0f64);
+    }
+
+    #[test]
+    fn packed() {
+        let a = PackedSymmetric::from_packed(2, Symmetry::Upper, vec![1.0f64, -2.0, 4.0]).unwrap();
+
+        assert_eq!(Lansp::lansp(Norm::Infinity, &a), 6.0f64);
+        assert_eq!(Lansp::lansp(Norm::MaxAbs, &a), 4.0f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "not square")]
+    fn band_not_square() {
+        let a: BandStorage<f64> = BandStorage::new(3, 2, 1, 1);
+        Langb::<f64>::langb(Norm::One, &a);
+    }
+
+    #[test]
+    fn norm_method() {
+        let m = M(ColMajor, 2i32, 2i32, vec![1.0f64, -9.0, -2.0, 4.0]);
+        assert_eq!(m.norm(Norm::One), 13.0f64);
+        let a: &Matrix<f64> = &m;
+        assert_eq!(a.norm(Norm::One), 13.0f64);
+
+        let s = S(Symmetry::Upper, M(ColMajor, 2i32, 2i32, vec![1.0f64, -9.0, -2.0, 4.0]));
+        assert_eq!(s.symmetric_norm(Norm::One), 6.0f64);
+        let a: &SymmetricMatrix<f64> = &s;
+        assert_eq!(a.symmetric_norm(Norm::One), 6.0f64);
+
+        let t = Tr(Symmetry::Lower, Diagonal::Unit, M(ColMajor, 2i32, 2i32, vec![5.0f64, -9.0, -2.0, 4.0]));
+        assert_eq!(t.triangular_norm(Norm::MaxAbs), 9.0f64);
+
+        let p = PackedSymmetric::from_packed(2, Symmetry::Upper, vec![1.0f64, -2.0, 4.0]).unwrap();
+        assert_eq!(p.norm(Norm::Infinity), 6.0f64);
+
+        let g = Tridiagonal::new(vec![1.0f64], vec![2.0, 3.0], vec![-4.0]).unwrap();
+        assert_eq!(g.norm(Norm::One), 7.0f64);
+    }
+}
@@ -0,0 +1,461 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::ptr;
+use libc::c_int;
+use num::Zero;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::Matrix;
+use scalar::Scalar;
+use types::{
+    Diagonal,
+    Symmetry,
+};
+use util::{ColMem, col_copy};
+
+// Packed storage keeps one triangle of an `n × n` matrix column by column in
+// `n * (n + 1) / 2` elements. With `Upper`, element `(i, j)`, `i <= j`, is at
+// `i + j * (j + 1) / 2`; with `Lower`, element `(i, j)`, `i >= j`, is at
+// `i + j * (2 * n - j - 1) / 2`.
+
+fn packed_len(n: c_int) -> usize {
+    (n * (n + 1) / 2) as usize
+}
+
+fn packed_index(n: c_int, uplo: Symmetry, i: c_int, j: c_int) -> Option<usize> {
+    assert!(i >= 0 && i < n && j >= 0 && j < n, "index out of bounds");
+
+    match uplo {
+        Symmetry::Upper if i <= j => Some((i + j * (j + 1) / 2) as usize),
+        Symmetry::Lower if i >= j => Some((i + j * (2 * n - j - 1) / 2) as usize),
+        _ => None,
+    }
+}
+
+/// An `n × n` symmetric or Hermitian matrix in packed storage.
+#[derive(Clone, Debug)]
+pub struct PackedSymmetric<T> {
+    n: c_int,
+    uplo: Symmetry,
+    data: Vec<T>,
+}
+
+/// An `n × n` triangular matrix in packed storage.
+#[derive(Clone, Debug)]
+pub struct PackedTriangular<T> {
+    n: c_int,
+    uplo: Symmetry,
+    diag: Diagonal,
+    data: Vec<T>,
+}
+
+impl<T> PackedSymmetric<T> {
+    /// Wraps `data` already in packed layout for the `uplo` triangle.
+    pub fn from_packed(n: c_int, uplo: Symmetry, data: Vec<T>) -> Result<PackedSymmetric<T>, Error> {
+        if n < 0 || data.len() != packed_len(n) {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(PackedSymmetric {
+            n: n,
+            uplo: uplo,
+            data: data,
+        })
+    }
+
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+    pub fn as_slice(&self) -> &[T] { &self.data[..] }
+    pub fn as_ptr(&self) -> *const T { self.data.as_ptr() }
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() }
+    pub fn into_vec(self) -> Vec<T> { self.data }
+}
+
+impl<T: Copy> PackedSymmetric<T> {
+    /// Element `(i, j)` if it lies in the stored triangle.
+    pub fn get(&self, i: c_int, j: c_int) -> Option<T> {
+        packed_index(self.n, self.uplo, i, j).map(|k| self.data[k])
+    }
+}
+
+impl<T: Trttp> PackedSymmetric<T> {
+    /// Packs the `uplo` triangle of a square dense matrix.
+    pub fn from_dense(a: &Matrix<T>, uplo: Symmetry) -> Result<PackedSymmetric<T>, Error> {
+        let data = try!(Trttp::trttp(uplo, a));
+
+        Ok(PackedSymmetric {
+            n: a.rows(),
+            uplo: uplo,
+            data: data,
+        })
+    }
+}
+
+impl<T: Tpttr> PackedSymmetric<T> {
+    /// The stored triangle as a dense column major vector. The other
+    /// triangle is zero.
+    pub fn to_dense(&self) -> Vec<T> {
+        Tpttr::tpttr(self.uplo, self.n, &self.data[..]).unwrap()
+    }
+}
+
+impl<T> PackedTriangular<T> {
+    /// Wraps `data` already in packed layout for the `uplo` triangle. With
+    /// `Diagonal::Unit` the stored diagonal is not referenced.
+    pub fn from_packed(n: c_int, uplo: Symmetry, diag: Diagonal, data: Vec<T>)
+        -> Result<PackedTriangular<T>, Error> {
+
+        if n < 0 || data.len() != packed_len(n) {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(PackedTriangular {
+            n: n,
+            uplo: uplo,
+            diag: diag,
+            data: data,
+        })
+    }
+
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+    pub fn diagonal(&self) -> Diagonal { self.diag }
+    pub fn as_slice(&self) -> &[T] { &self.data[..] }
+    pub fn as_ptr(&self) -> *const T { self.data.as_ptr() }
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() }
+    pub fn into_vec(self) -> Vec<T> { self.data }
+}
+
+impl<T: Copy + Zero> PackedTriangular<T> {
+    /// Element `(i, j)`, which is zero outside the triangle.
+    pub fn get(&self, i: c_int, j: c_int) -> T {
+        packed_index(self.n, self.uplo, i, j).map(|k| self.data[k]).unwrap_or(T::zero())
+    }
+}
+
+impl<T: Trttp> PackedTriangular<T> {
+    /// Packs the `uplo` triangle of a square dense matrix.
+    pub fn from_dense(a: &Matrix<T>, uplo: Symmetry, diag: Diagonal) -> Result<PackedTriangular<T>, Error> {
+        let data = try!(Trttp::trttp(uplo, a));
+
+        Ok(PackedTriangular {
+            n: a.rows(),
+            uplo: uplo,
+            diag: diag,
+            data: data,
+        })
+    }
+}
+
+impl<T: Tpttr> PackedTriangular<T> {
+    /// The matrix as a dense column major vector. A unit diagonal is
+    /// returned as stored.
+    pub fn to_dense(&self) -> Vec<T> {
+        Tpttr::tpttr(self.uplo, self.n, &self.data[..]).unwrap()
+    }
+}
+
+/// Packs the `uplo` triangle of a square dense matrix.
+pub trait Trttp: Sized {
+    fn trttp(uplo: Symmetry, a: &Matrix<Self>) -> Result<Vec<Self>, Error>;
+}
+
+/// Unpacks a triangle into a dense column major `n × n` matrix whose other
+/// triangle is zero.
+pub trait Tpttr: Sized {
+    fn tpttr(uplo: Symmetry, n: c_int, ap: &[Self]) -> Result<Vec<Self>, Error>;
+}
+
+/// Cholesky factorization of a packed symmetric (Hermitian) positive
+/// definite matrix, in place.
+pub trait Pptrf: Sized {
+    fn pptrf(a: &mut PackedSymmetric<Self>) -> Result<(), Error>;
+}
+
+/// Solves `A X = B` with the factorization from `Pptrf`, overwriting `b`.
+pub trait Pptrs: Sized {
+    fn pptrs(factor: &PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Eigenvalues, in ascending order, and optionally eigenvectors of a packed
+/// real symmetric matrix. `a` is destroyed. The eigenvectors are written to
+/// the columns of `z`, which must be `n × n`.
+pub trait Spev: Sized {
+    fn spev(a: PackedSymmetric<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Self>, Error>;
+}
+
+/// The complex Hermitian counterpart of `Spev`.
+pub trait Hpev<Real>: Sized {
+    fn hpev(a: PackedSymmetric<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Real>, Error>;
+}
+
+macro_rules! packed_impl(($($t: ident: $zero: expr), +) => ($(
+    impl Trttp for $t {
+        fn trttp(uplo: Symmetry, a: &Matrix<Self>) -> Result<Vec<Self>, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut ap: Vec<$t> = vec![$zero; packed_len(n)];
+            let copy = col_copy(a);
+            let ptr = copy.as_ref().map_or(a.as_ptr(), |v| v.as_ptr());
+
+            unsafe {
+                prefix!($t, trttp_)(uplo.as_i8().as_mut(), n.as_mut(),
+                    ptr as *mut _, n.as_mut(),
+                    ap.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(ap),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Tpttr for $t {
+        fn tpttr(uplo: Symmetry, n: c_int, ap: &[Self]) -> Result<Vec<Self>, Error> {
+            if n < 0 || ap.len() != packed_len(n) {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut a: Vec<$t> = vec![$zero; (n * n) as usize];
+
+            unsafe {
+                prefix!($t, tpttr_)(uplo.as_i8().as_mut(), n.as_mut(),
+                    ap.as_ptr() as *mut _,
+                    a.as_mut_ptr(), n.as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(a),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Pptrf for $t {
+        fn pptrf(a: &mut PackedSymmetric<Self>) -> Result<(), Error> {
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, pptrf_)(a.uplo.as_i8().as_mut(), a.n.as_mut(),
+                    a.data.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
+            }
+        }
+    }
+
+    impl Pptrs for $t {
+        fn pptrs(factor: &PackedSymmetric<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = factor.n;
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, pptrs_)(factor.uplo.as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    factor.data.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! real_packed_impl(($($t: ident), +) => ($(
+    impl Spev for $t {
+        fn spev(mut a: PackedSymmetric<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Self>, Error> {
+            let mut info: c_int = 0;
+            let n = a.n;
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() != n {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz) = match z_mem {
+                Some(ref mut z) => (b'V', z.as_mut_ptr(), z.lead()),
+                None => (b'N', ptr::null::<$t>() as *mut _, 1),
+            };
+
+            let mut w: Vec<$t> = vec![0.0; n as usize];
+            let mut work: Vec<$t> = vec![0.0; 3 * n as usize];
+
+            unsafe {
+                prefix!($t, spev_)((jobz as i8).as_mut(), a.uplo.as_i8().as_mut(),
+                    n.as_mut(), a.data.as_mut_ptr(),
+                    w.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(w),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_packed_impl(($($t: ident: $r: ident), +) => ($(
+    impl Hpev<$r> for $t {
+        fn hpev(mut a: PackedSymmetric<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<$r>, Error> {
+            let mut info: c_int = 0;
+            let n = a.n;
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() != n {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz) = match z_mem {
+                Some(ref mut z) => (b'V', z.as_mut_ptr(), z.lead()),
+                None => (b'N', ptr::null::<$t>() as *mut _, 1),
+            };
+
+            let len = if n > 0 { n as usize } else { 1 };
+            let mut w: Vec<$r> = vec![0.0; n as usize];
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * len - 1];
+            let mut rwork: Vec<$r> = vec![0.0; 3 * len - 2];
+
+            unsafe {
+                prefix!($t, hpev_)((jobz as i8).as_mut(), a.uplo.as_i8().as_mut(),
+                    n.as_mut(), a.data.as_mut_ptr(),
+                    w.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(w),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+)+));
+
+packed_impl!(
+    f32: 0.0,
+    f64: 0.0,
+    Complex32: Complex32::new(0.0, 0.0),
+    Complex64: Complex64::new(0.0, 0.0)
+);
+real_packed_impl!(f32, f64);
+complex_packed_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod packed_tests {
+    use num::complex::Complex64;
+    use packed::{
+        Hpev,
+        PackedSymmetric,
+        PackedTriangular,
+        Pptrf,
+        Pptrs,
+        Spev,
+    };
+    use matrix::tests::M;
+    use types::{Diagonal, Symmetry};
+    use types::Order::*;
+
+    fn spd() -> M<f64> {
+        M(RowMajor, 3i32, 3i32, vec![
+            4.0f64, 1.0, 0.0,
+            1.0, 3.0, 1.0,
+            0.0, 1.0, 2.0,
+        ])
+    }
+
+    #[test]
+    fn layout() {
+        let upper = PackedSymmetric::from_dense(&spd(), Symmetry::Upper).unwrap();
+        let lower = PackedSymmetric::from_dense(&spd(), Symmetry::Lower).unwrap();
+
+        assert_eq!(upper.as_slice(), &[4.0, 1.0, 3.0, 0.0, 1.0, 2.0]);
+        assert_eq!(lower.as_slice(), &[4.0, 1.0, 0.0, 3.0, 1.0, 2.0]);
+        assert_eq!(upper.get(1, 2), Some(1.0));
+        assert_eq!(lower.get(1, 2), None);
+        assert_eq!(lower.to_dense(), vec![4.0, 1.0, 0.0, 0.0, 3.0, 1.0, 0.0, 0.0, 2.0]);
+        assert!(PackedSymmetric::from_packed(3, Symmetry::Upper, vec![1.0f64; 5]).is_err());
+    }
+
+    #[test]
+    fn triangular() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 2.0, 3.0, 4.0]);
+        let t = PackedTriangular::from_dense(&a, Symmetry::Upper, Diagonal::NonUnit).unwrap();
+
+        assert_eq!(t.as_slice(), &[1.0, 3.0, 4.0]);
+        assert_eq!(t.get(1, 0), 0.0);
+        assert_eq!(t.to_dense(), vec![1.0, 0.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn factor_solve() {
+        let mut a = PackedSymmetric::from_dense(&spd(), Symmetry::Lower).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![5.0f64, 5.0, 3.0]);
+
+        Pptrf::pptrf(&mut a).unwrap();
+        Pptrs::pptrs(&a, &mut b).unwrap();
+
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn eigenvalues() {
+        let a = PackedSymmetric::from_packed(2, Symmetry::Upper, vec![2.0f64, 1.0, 2.0]).unwrap();
+        let w = Spev::spev(a, None).unwrap();
+
+        assert!((w[0] - 1.0).abs() < 1e-12);
+        assert!((w[1] - 3.0).abs() < 1e-12);
+
+        let a = PackedSymmetric::from_packed(2, Symmetry::Upper, vec![
+            Complex64::new(2.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(2.0, 0.0),
+        ]).unwrap();
+        let mut z = M(ColMajor, 2i32, 2i32, vec![Complex64::new(0.0, 0.0); 4]);
+        let w = Hpev::hpev(a, Some(&mut z)).unwrap();
+
+        assert!((w[0] - 1.0f64).abs() < 1e-12);
+        assert!((w[1] - 3.0f64).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,492 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use num::Zero;
+use band::BandStorage;
+use error::Error;
+use ll::*;
+use matrix::{
+    Matrix,
+    SymmetricMatrix,
+    TriangularMatrix,
+};
+use scalar::Scalar;
+use tridiagonal::{Tridiagonal, TridiagonalLU};
+use types::Equilibration;
+use util::{ColMem, col_dims, col_trans, col_uplo, col_vec};
+
+/// Error bounds of a refined solution, one entry per right hand side.
+#[derive(Clone, Debug)]
+pub struct Refinement<Real> {
+    /// Estimated forward error bound relative to the largest element of
+    /// the solution.
+    pub ferr: Vec<Real>,
+    /// Componentwise relative backward error.
+    pub berr: Vec<Real>,
+}
+
+/// Result of an expert driver (`*svx`).
+#[derive(Clone, Debug)]
+pub struct ExpertSolution<Real> {
+    /// Reciprocal condition number of the (equilibrated) matrix. The
+    /// solution is unreliable if this is below machine precision.
+    pub rcond: Real,
+    pub bounds: Refinement<Real>,
+    /// How the matrix was scaled before factoring.
+    pub equilibration: Equilibration,
+}
+
+// The refinement routines take the original matrix `a`, its factorization,
+// the right hand sides `b` and a computed solution `x`, which is improved in
+// place.
+
+/// Refines the solution of a general system from its `LU` factorization.
+///
+/// `Gesv` leaves the factors in a plain matrix rather than a factorization
+/// type, so there is no `refine()` method for them; pass them here.
+pub trait Gerfs<Real>: Sized {
+    fn gerfs(a: &Matrix<Self>, lu: &Matrix<Self>, ipiv: &[c_int],
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Refines the solution of a symmetric (Hermitian) positive definite system
+/// from its Cholesky factorization.
+///
+/// As with `Gerfs`, the factor from `Posv` is a plain matrix, so there is no
+/// `refine()` method for it.
+pub trait Porfs<Real>: Sized {
+    fn porfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>,
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Refines the solution of a band system from its `LU` factorization.
+pub trait Gbrfs<Real>: Sized {
+    fn gbrfs(a: &BandStorage<Self>, lu: &BandStorage<Self>, ipiv: &[c_int],
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Refines the solution of a tridiagonal system from its `LU`
+/// factorization.
+pub trait Gtrfs<Real>: Sized {
+    fn gtrfs(a: &Tridiagonal<Self>, lu: &TridiagonalLU<Self>,
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Refines the solution of a symmetric indefinite system from its
+/// Bunch-Kaufman factorization.
+pub trait Syrfs<Real>: Sized {
+    fn syrfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>, ipiv: &[c_int],
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Refines the solution of a Hermitian indefinite system from its
+/// Bunch-Kaufman factorization.
+pub trait Herfs<Real>: Sized {
+    fn herfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>, ipiv: &[c_int],
+        b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<Real>, Error>;
+}
+
+/// Error bounds for the solution of a triangular system. `x` is not
+/// changed; there is no factorization to refine with.
+pub trait Trrfs<Real>: Sized {
+    fn trrfs(a: &TriangularMatrix<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+        -> Result<Refinement<Real>, Error>;
+}
+
+fn check_rhs<T>(n: c_int, b: &Matrix<T>, x: &Matrix<T>) -> Result<c_int, Error> {
+    if b.rows() != n || x.rows() != n || b.cols() != x.cols() {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(b.cols())
+}
+
+/// The order of `a`, and column major copies of it and its `factor`. Both
+/// must use the same triangle, but may be stored in either order.
+fn symmetric_pair<T: Copy + Zero>(a: &SymmetricMatrix<T>, factor: &SymmetricMatrix<T>)
+    -> Result<(c_int, Vec<T>, Vec<T>), Error> {
+
+    let n = a.rows();
+    if n != a.cols() || factor.rows() != n || factor.cols() != n {
+        return Err(Error::DimensionMismatch);
+    }
+
+    if a.symmetry() != factor.symmetry() {
+        return Err(Error::IllegalParameter(2));
+    }
+
+    Ok((n, col_vec(a), col_vec(factor)))
+}
+
+fn rfs_result<R>(ferr: Vec<R>, berr: Vec<R>, info: c_int) -> Result<Refinement<R>, Error> {
+    match info {
+        0 => Ok(Refinement { ferr: ferr, berr: berr }),
+        x => Err(Error::IllegalParameter(-x as usize)),
+    }
+}
+
+macro_rules! rfs_impl(($($t: ident: $r: ident, $zero: expr, $work: expr, $extra: ty, $extra_zero: expr), +) => ($(
+    impl Gerfs<$r> for $t {
+        fn gerfs(a: &Matrix<Self>, lu: &Matrix<Self>, ipiv: &[c_int],
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let n = a.rows();
+            if n != a.cols() || lu.rows() != n || lu.cols() != n || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let lda = if n > 0 { n } else { 1 };
+            let a = col_vec(a);
+            let lu = col_vec(lu);
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, gerfs_)((b'N' as i8).as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    lu.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+
+    impl Porfs<$r> for $t {
+        fn porfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>,
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let (n, a_vec, factor_vec) = try!(symmetric_pair(a, factor));
+            let lda = if n > 0 { n } else { 1 };
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, porfs_)(a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_vec.as_ptr() as *mut _, lda.as_mut(),
+                    factor_vec.as_ptr() as *mut _, lda.as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+
+    impl Gbrfs<$r> for $t {
+        fn gbrfs(a: &BandStorage<Self>, lu: &BandStorage<Self>, ipiv: &[c_int],
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let n = a.cols();
+            if a.rows() != n || lu.rows() != n || lu.cols() != n || ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, gbrfs_)((b'N' as i8).as_mut(),
+                    n.as_mut(), a.sub_diagonals().as_mut(), a.sup_diagonals().as_mut(),
+                    nrhs.as_mut(),
+                    a.as_band_ptr() as *mut _, a.lead().as_mut(),
+                    lu.as_ptr() as *mut _, lu.lead().as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+
+    impl Gtrfs<$r> for $t {
+        fn gtrfs(a: &Tridiagonal<Self>, lu: &TridiagonalLU<Self>,
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let n = a.dim() as usize;
+            if lu.dim() as usize != n {
+                return Err(Error::DimensionMismatch);
+            }
+            let (dl, d, du) = a.as_ptrs();
+            let (dlf, df, duf) = lu.factors().as_ptrs();
+            let nrhs = try!(check_rhs(n as c_int, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, gtrfs_)((b'N' as i8).as_mut(),
+                    (n as c_int).as_mut(), nrhs.as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    dlf as *mut _, df as *mut _, duf as *mut _,
+                    lu.du2().as_ptr() as *mut _, lu.pivots().as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+
+    impl Trrfs<$r> for $t {
+        fn trrfs(a: &TriangularMatrix<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+            -> Result<Refinement<$r>, Error> {
+
+            let (n, cols, lda) = col_dims(a);
+            if n != cols {
+                return Err(Error::DimensionMismatch);
+            }
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, trrfs_)(uplo.as_i8().as_mut(), col_trans(a.order()).as_mut(),
+                    a.diagonal().as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+
+    impl Syrfs<$r> for $t {
+        fn syrfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>, ipiv: &[c_int],
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let (n, a_vec, factor_vec) = try!(symmetric_pair(a, factor));
+            if ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+            let lda = if n > 0 { n } else { 1 };
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, syrfs_)(a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_vec.as_ptr() as *mut _, lda.as_mut(),
+                    factor_vec.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+)+));
+
+macro_rules! complex_rfs_impl(($($t: ident: $r: ident), +) => ($(
+    impl Herfs<$r> for $t {
+        fn herfs(a: &SymmetricMatrix<Self>, factor: &SymmetricMatrix<Self>, ipiv: &[c_int],
+            b: &mut Matrix<Self>, x: &mut Matrix<Self>) -> Result<Refinement<$r>, Error> {
+
+            let (n, a_vec, factor_vec) = try!(symmetric_pair(a, factor));
+            if ipiv.len() < n as usize {
+                return Err(Error::DimensionMismatch);
+            }
+            let lda = if n > 0 { n } else { 1 };
+            let nrhs = try!(check_rhs(n, b, x));
+
+            let mut info: c_int = 0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); 2 * n as usize];
+            let mut rwork: Vec<$r> = vec![0.0; n as usize];
+
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                prefix!($t, herfs_)(a.symmetry().as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a_vec.as_ptr() as *mut _, lda.as_mut(),
+                    factor_vec.as_ptr() as *mut _, lda.as_mut(),
+                    ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            rfs_result(ferr, berr, info)
+        }
+    }
+)+));
+
+rfs_impl!(
+    f32: f32, 0.0, 3, c_int, 0,
+    f64: f64, 0.0, 3, c_int, 0,
+    Complex32: f32, Complex32::new(0.0, 0.0), 2, f32, 0.0,
+    Complex64: f64, Complex64::new(0.0, 0.0), 2, f64, 0.0
+);
+complex_rfs_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod rfs_tests {
+    use libc::c_int;
+    use num::complex::Complex64;
+    use linear_equations::{Gesv, Hesv, Sysv};
+    use matrix::tests::{M, S};
+    use refinement::{Gerfs, Herfs, Syrfs};
+    use types::Symmetry;
+    use types::Order::*;
+
+    #[test]
+    fn general() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 4.0, 1.0, 2.0]);
+        let mut lu = M(ColMajor, 2i32, 2i32, vec![1.0f64, 4.0, 1.0, 2.0]);
+        let mut b = M(ColMajor, 2i32, 1i32, vec![-2.0f64, 2.0]);
+        let mut x = M(ColMajor, 2i32, 1i32, vec![-2.0f64, 2.0]);
+
+        let ipiv: Vec<c_int> = Gesv::gesv(&mut lu, &mut x).unwrap()
+            .into_iter().map(|p| p as c_int).collect();
+
+        // Perturb the solution so there is something to refine.
+        x.3[0] += 1e-6;
+        let r = Gerfs::<f64>::gerfs(&a, &lu, &ipiv, &mut b, &mut x).unwrap();
+
+        assert!((x.3[0] - 3.0).abs() < 1e-12);
+        assert!((x.3[1] + 5.0).abs() < 1e-12);
+        assert_eq!(r.berr.len(), 1);
+    }
+
+    #[test]
+    fn symmetric_indefinite() {
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                // A x = b for x = (1, -1).
+                let data = vec![1.0f64, 2.0, 2.0, 1.0];
+                let a = S(uplo, M(order, 2i32, 2i32, data.clone()));
+                let mut factor = S(uplo, M(order, 2i32, 2i32, data));
+                let mut b = M(order, 2i32, 1i32, vec![-1.0f64, 1.0]);
+                let mut x = M(order, 2i32, 1i32, vec![-1.0f64, 1.0]);
+
+                let ipiv = Sysv::sysv(&mut factor, &mut x).unwrap();
+                x.3[0] += 1e-6;
+                Syrfs::<f64>::syrfs(&a, &factor, &ipiv, &mut b, &mut x).unwrap();
+
+                assert!((x.3[0] - 1.0).abs() < 1e-12);
+                assert!((x.3[1] + 1.0).abs() < 1e-12);
+
+                // `a` may be stored differently from its factor.
+                let other = S(uplo, M(match order { ColMajor => RowMajor, RowMajor => ColMajor },
+                    2i32, 2i32, vec![1.0f64, 2.0, 2.0, 1.0]));
+                Syrfs::<f64>::syrfs(&other, &factor, &ipiv, &mut b, &mut x).unwrap();
+                assert!((x.3[0] - 1.0).abs() < 1e-12);
+
+                let flipped = match uplo { Symmetry::Upper => Symmetry::Lower, Symmetry::Lower => Symmetry::Upper };
+                let other = S(flipped, M(order, 2i32, 2i32, vec![1.0f64, 2.0, 2.0, 1.0]));
+                assert!(Syrfs::<f64>::syrfs(&other, &factor, &ipiv, &mut b, &mut x).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn hermitian_indefinite() {
+        let c = |re, im| Complex64::new(re, im);
+
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                // A x = b for x = (1, i), with A = [1 2i; -2i 1] either way
+                // the data is read.
+                let data = match order {
+                    ColMajor => vec![c(1.0, 0.0), c(0.0, -2.0), c(0.0, 2.0), c(1.0, 0.0)],
+                    RowMajor => vec![c(1.0, 0.0), c(0.0, 2.0), c(0.0, -2.0), c(1.0, 0.0)],
+                };
+                let a = S(uplo, M(order, 2i32, 2i32, data.clone()));
+                let mut factor = S(uplo, M(order, 2i32, 2i32, data));
+                let mut b = M(order, 2i32, 1i32, vec![c(-1.0, 0.0), c(0.0, -1.0)]);
+                let mut x = M(order, 2i32, 1i32, vec![c(-1.0, 0.0), c(0.0, -1.0)]);
+
+                let ipiv = Hesv::hesv(&mut factor, &mut x).unwrap();
+                x.3[0] = x.3[0] + c(1e-6, 0.0);
+                Herfs::<f64>::herfs(&a, &factor, &ipiv, &mut b, &mut x).unwrap();
+
+                assert!((x.3[0] - c(1.0, 0.0)).norm() < 1e-12);
+                assert!((x.3[1] - c(0.0, 1.0)).norm() < 1e-12);
+
+                let flipped = match uplo { Symmetry::Upper => Symmetry::Lower, Symmetry::Lower => Symmetry::Upper };
+                let other = S(flipped, M(order, 2i32, 2i32, vec![c(1.0, 0.0); 4]));
+                assert!(Herfs::<f64>::herfs(&other, &factor, &ipiv, &mut b, &mut x).is_err());
+            }
+        }
+    }
+}
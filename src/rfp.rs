@@ -0,0 +1,404 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::cmp;
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::Matrix;
+use scalar::Scalar;
+use types::{
+    Diagonal,
+    Symmetry,
+    Transpose,
+};
+use util::{ColMem, col_copy, rfp_trans};
+
+/// An `n × n` symmetric, Hermitian or triangular matrix in Rectangular Full
+/// Packed format.
+///
+/// RFP keeps one triangle in `n * (n + 1) / 2` elements, like packed
+/// storage, but arranges it as a full rectangle so the routines can use
+/// blocked level 3 operations. `transr` selects whether that rectangle is
+/// stored normally or (conjugate) transposed; both describe the same matrix.
+#[derive(Clone, Debug)]
+pub struct RfpMatrix<T> {
+    n: c_int,
+    transr: Transpose,
+    uplo: Symmetry,
+    data: Vec<T>,
+}
+
+impl<T> RfpMatrix<T> {
+    /// Wraps `data` already in RFP layout.
+    pub fn from_rfp(n: c_int, transr: Transpose, uplo: Symmetry, data: Vec<T>)
+        -> Result<RfpMatrix<T>, Error> {
+
+        if n < 0 || data.len() != (n * (n + 1) / 2) as usize {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(RfpMatrix {
+            n: n,
+            transr: transr,
+            uplo: uplo,
+            data: data,
+        })
+    }
+
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn transr(&self) -> Transpose { self.transr }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+    pub fn as_slice(&self) -> &[T] { &self.data[..] }
+    pub fn as_ptr(&self) -> *const T { self.data.as_ptr() }
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() }
+    pub fn into_vec(self) -> Vec<T> { self.data }
+}
+
+impl<T: Trttf> RfpMatrix<T> {
+    /// Copies the `uplo` triangle of a square dense matrix into RFP format.
+    pub fn from_dense(a: &Matrix<T>, transr: Transpose, uplo: Symmetry) -> Result<RfpMatrix<T>, Error> {
+        let data = try!(Trttf::trttf(transr, uplo, a));
+
+        Ok(RfpMatrix {
+            n: a.rows(),
+            transr: transr,
+            uplo: uplo,
+            data: data,
+        })
+    }
+}
+
+impl<T: Tfttr> RfpMatrix<T> {
+    /// The stored triangle as a dense column major vector. The other
+    /// triangle is zero.
+    pub fn to_dense(&self) -> Vec<T> {
+        Tfttr::tfttr(self.transr, self.uplo, self.n, &self.data[..]).unwrap()
+    }
+}
+
+/// Copies the `uplo` triangle of a square dense matrix into RFP format.
+pub trait Trttf: Sized {
+    fn trttf(transr: Transpose, uplo: Symmetry, a: &Matrix<Self>) -> Result<Vec<Self>, Error>;
+}
+
+/// Unpacks an RFP triangle into a dense column major `n × n` matrix whose
+/// other triangle is zero.
+pub trait Tfttr: Sized {
+    fn tfttr(transr: Transpose, uplo: Symmetry, n: c_int, arf: &[Self]) -> Result<Vec<Self>, Error>;
+}
+
+/// Cholesky factorization of a symmetric (Hermitian) positive definite RFP
+/// matrix, in place.
+pub trait Pftrf: Sized {
+    fn pftrf(a: &mut RfpMatrix<Self>) -> Result<(), Error>;
+}
+
+/// Solves `A X = B` with the factorization from `Pftrf`, overwriting `b`.
+pub trait Pftrs: Sized {
+    fn pftrs(factor: &RfpMatrix<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Inverts a symmetric (Hermitian) positive definite matrix from its
+/// factorization by `Pftrf`, in place.
+pub trait Pftri: Sized {
+    fn pftri(factor: &mut RfpMatrix<Self>) -> Result<(), Error>;
+}
+
+/// Symmetric rank-k update `C = alpha A Aᵀ + beta C` of an RFP matrix with
+/// an `n × k` matrix `A`.
+pub trait Sfrk: Sized {
+    fn sfrk(alpha: Self, a: &Matrix<Self>, beta: Self, c: &mut RfpMatrix<Self>) -> Result<(), Error>;
+}
+
+/// Hermitian rank-k update `C = alpha A Aᴴ + beta C` of an RFP matrix with
+/// an `n × k` matrix `A`.
+pub trait Hfrk<Real>: Sized {
+    fn hfrk(alpha: Real, a: &Matrix<Self>, beta: Real, c: &mut RfpMatrix<Self>) -> Result<(), Error>;
+}
+
+/// Solves `op(A) X = alpha B` for a triangular RFP matrix `A`, overwriting
+/// `b` with `X`. `Transpose::Conjugate` solves with `Aᵀ` for real and `Aᴴ`
+/// for complex matrices.
+pub trait Tfsm: Sized {
+    fn tfsm(trans: Transpose, diag: Diagonal, alpha: Self, a: &RfpMatrix<Self>, b: &mut Matrix<Self>)
+        -> Result<(), Error>;
+}
+
+macro_rules! rfp_impl(($($t: ident: $zero: expr, $conj: expr), +) => ($(
+    impl Trttf for $t {
+        fn trttf(transr: Transpose, uplo: Symmetry, a: &Matrix<Self>) -> Result<Vec<Self>, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut arf: Vec<$t> = vec![$zero; (n * (n + 1) / 2) as usize];
+            let copy = col_copy(a);
+            let ptr = copy.as_ref().map_or(a.as_ptr(), |v| v.as_ptr());
+
+            unsafe {
+                prefix!($t, trttf_)(rfp_trans(transr, $conj).as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(),
+                    ptr as *mut _, cmp::max(1, n).as_mut(),
+                    arf.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(arf),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Tfttr for $t {
+        fn tfttr(transr: Transpose, uplo: Symmetry, n: c_int, arf: &[Self]) -> Result<Vec<Self>, Error> {
+            if n < 0 || arf.len() != (n * (n + 1) / 2) as usize {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut a: Vec<$t> = vec![$zero; (n * n) as usize];
+
+            unsafe {
+                prefix!($t, tfttr_)(rfp_trans(transr, $conj).as_mut(), uplo.as_i8().as_mut(),
+                    n.as_mut(), arf.as_ptr() as *mut _,
+                    a.as_mut_ptr(), cmp::max(1, n).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(a),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Pftrf for $t {
+        fn pftrf(a: &mut RfpMatrix<Self>) -> Result<(), Error> {
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, pftrf_)(rfp_trans(a.transr, $conj).as_mut(), a.uplo.as_i8().as_mut(),
+                    a.n.as_mut(), a.data.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
+            }
+        }
+    }
+
+    impl Pftrs for $t {
+        fn pftrs(factor: &RfpMatrix<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = factor.n;
+            if b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let nrhs = b.cols();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, pftrs_)(rfp_trans(factor.transr, $conj).as_mut(),
+                    factor.uplo.as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    factor.data.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Pftri for $t {
+        fn pftri(factor: &mut RfpMatrix<Self>) -> Result<(), Error> {
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, pftri_)(rfp_trans(factor.transr, $conj).as_mut(),
+                    factor.uplo.as_i8().as_mut(),
+                    factor.n.as_mut(), factor.data.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl Tfsm for $t {
+        fn tfsm(trans: Transpose, diag: Diagonal, alpha: Self, a: &RfpMatrix<Self>, b: &mut Matrix<Self>)
+            -> Result<(), Error> {
+
+            let m = a.n;
+            if b.rows() != m {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let n = b.cols();
+            let mut alpha = alpha;
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, tfsm_)(rfp_trans(a.transr, $conj).as_mut(), (b'L' as i8).as_mut(),
+                    a.uplo.as_i8().as_mut(), rfp_trans(trans, $conj).as_mut(),
+                    diag.as_i8().as_mut(),
+                    m.as_mut(), n.as_mut(),
+                    &mut alpha, a.data.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    1, 1, 1, 1, 1);
+            }
+
+            Ok(())
+        }
+    }
+)+));
+
+macro_rules! real_rfp_impl(($($t: ident), +) => ($(
+    impl Sfrk for $t {
+        fn sfrk(alpha: Self, a: &Matrix<Self>, beta: Self, c: &mut RfpMatrix<Self>) -> Result<(), Error> {
+            let n = c.n;
+            if a.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let k = a.cols();
+            let copy = col_copy(a);
+            let ptr = copy.as_ref().map_or(a.as_ptr(), |v| v.as_ptr());
+
+            unsafe {
+                prefix!($t, sfrk_)(rfp_trans(c.transr, b'T').as_mut(), c.uplo.as_i8().as_mut(),
+                    (b'N' as i8).as_mut(),
+                    n.as_mut(), k.as_mut(),
+                    alpha.as_mut(), ptr as *mut _, cmp::max(1, n).as_mut(),
+                    beta.as_mut(), c.data.as_mut_ptr(),
+                    1, 1, 1);
+            }
+
+            Ok(())
+        }
+    }
+)+));
+
+macro_rules! complex_rfp_impl(($($t: ident: $r: ident), +) => ($(
+    impl Hfrk<$r> for $t {
+        fn hfrk(alpha: $r, a: &Matrix<Self>, beta: $r, c: &mut RfpMatrix<Self>) -> Result<(), Error> {
+            let n = c.n;
+            if a.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let k = a.cols();
+            let copy = col_copy(a);
+            let ptr = copy.as_ref().map_or(a.as_ptr(), |v| v.as_ptr());
+
+            unsafe {
+                prefix!($t, hfrk_)(rfp_trans(c.transr, b'C').as_mut(), c.uplo.as_i8().as_mut(),
+                    (b'N' as i8).as_mut(),
+                    n.as_mut(), k.as_mut(),
+                    alpha.as_mut(), ptr as *mut _, cmp::max(1, n).as_mut(),
+                    beta.as_mut(), c.data.as_mut_ptr(),
+                    1, 1, 1);
+            }
+
+            Ok(())
+        }
+    }
+)+));
+
+rfp_impl!(
+    f32: 0.0, b'T',
+    f64: 0.0, b'T',
+    Complex32: Complex32::new(0.0, 0.0), b'C',
+    Complex64: Complex64::new(0.0, 0.0), b'C'
+);
+real_rfp_impl!(f32, f64);
+complex_rfp_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod rfp_tests {
+    use norms::Lansf;
+    use rfp::{
+        Pftrf,
+        Pftri,
+        Pftrs,
+        RfpMatrix,
+        Sfrk,
+        Tfsm,
+    };
+    use matrix::tests::M;
+    use types::{Diagonal, Norm, Symmetry, Transpose};
+    use types::Order::*;
+
+    fn spd() -> M<f64> {
+        M(RowMajor, 3i32, 3i32, vec![
+            4.0f64, 1.0, 0.0,
+            1.0, 3.0, 1.0,
+            0.0, 1.0, 2.0,
+        ])
+    }
+
+    #[test]
+    fn round_trip() {
+        for &transr in &[Transpose::None, Transpose::Conjugate] {
+            let a = RfpMatrix::from_dense(&spd(), transr, Symmetry::Lower).unwrap();
+
+            assert_eq!(a.as_slice().len(), 6);
+            assert_eq!(a.to_dense(), vec![4.0, 1.0, 0.0, 0.0, 3.0, 1.0, 0.0, 0.0, 2.0]);
+            assert_eq!(Lansf::lansf(Norm::One, &a), 5.0f64);
+        }
+    }
+
+    #[test]
+    fn factor_solve() {
+        let mut a = RfpMatrix::from_dense(&spd(), Transpose::None, Symmetry::Upper).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![5.0f64, 5.0, 3.0]);
+
+        Pftrf::pftrf(&mut a).unwrap();
+        Pftrs::pftrs(&a, &mut b).unwrap();
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+
+        // A = Uᵀ U, so solving with Uᵀ then U gives the same solution.
+        let mut b = M(ColMajor, 3i32, 1i32, vec![5.0f64, 5.0, 3.0]);
+        Tfsm::tfsm(Transpose::Conjugate, Diagonal::NonUnit, 1.0, &a, &mut b).unwrap();
+        Tfsm::tfsm(Transpose::None, Diagonal::NonUnit, 1.0, &a, &mut b).unwrap();
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+
+        Pftri::pftri(&mut a).unwrap();
+        let inv = a.to_dense();
+        // (A⁻¹)₀₀ = (3 * 2 - 1) / det(A), det(A) = 18.
+        assert!((inv[0] - 5.0 / 18.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rank_k() {
+        let a = M(RowMajor, 2i32, 1i32, vec![1.0f64, 2.0]);
+        let mut c = RfpMatrix::from_rfp(2, Transpose::None, Symmetry::Lower, vec![0.0f64; 3]).unwrap();
+
+        Sfrk::sfrk(2.0, &a, 0.0, &mut c).unwrap();
+
+        assert_eq!(c.to_dense(), vec![2.0, 4.0, 0.0, 8.0]);
+    }
+}
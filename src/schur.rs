@@ -0,0 +1,1254 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::mem;
+use std::ptr;
+use libc::c_int;
+use num::Num;
+use num::complex::{
+    Complex,
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use general_eigenvalues::GeneralizedEigenvalue;
+use ll::*;
+use matrix::Matrix;
+use scalar::Scalar;
+use types::{Compute, Sense};
+use util::{ColMem, ColSlice, call_selector, col_vec, complex_values, mem_job, real_values,
+    square_mem, with_selector};
+
+/// Schur decomposition `A = Z T Zᴴ`, with `T` upper triangular (quasi upper
+/// triangular with 2 × 2 blocks for complex pairs, for real matrices) and
+/// `Z` unitary. Both are column major.
+#[derive(Clone, Debug)]
+pub struct Schur<T, Real> {
+    n: c_int,
+    t: Vec<T>,
+    z: Option<Vec<T>>,
+    values: Vec<Complex<Real>>,
+    sdim: usize,
+}
+
+impl<T, Real> Schur<T, Real> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn t(&self) -> &[T] { &self.t[..] }
+
+    /// The Schur vectors, if they were computed.
+    pub fn z(&self) -> Option<&[T]> { self.z.as_ref().map(|z| &z[..]) }
+
+    /// The eigenvalues in the order they appear on the diagonal of `T`.
+    pub fn eigenvalues(&self) -> &[Complex<Real>] { &self.values[..] }
+
+    /// The number of eigenvalues chosen by the selector, which lead `T`.
+    /// The first `selected()` columns of `Z` span their invariant subspace.
+    pub fn selected(&self) -> usize { self.sdim }
+
+    pub fn into_parts(self) -> (Vec<T>, Option<Vec<T>>, Vec<Complex<Real>>) {
+        (self.t, self.z, self.values)
+    }
+}
+
+/// Reciprocal condition numbers from `Geesx` for the selected eigenvalues,
+/// present if requested by `Sense`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SchurCondition<Real> {
+    /// For the average of the selected eigenvalues.
+    pub eigenvalues: Option<Real>,
+    /// For their right invariant subspace.
+    pub subspace: Option<Real>,
+}
+
+/// Schur decomposition of a general matrix. `a` is not changed.
+///
+/// With a selector, eigenvalues for which it returns `true` are moved to the
+/// leading block of `T`. For real matrices a complex pair is moved together
+/// if either is selected. The selector is called from LAPACK; a panic in it
+/// is resumed once LAPACK returns.
+///
+/// If rounding after reordering changes a complex eigenvalue so that it no
+/// longer satisfies the selector, the decomposition is still returned.
+pub trait Gees<Real>: Sized {
+    fn gees(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<Real>) -> bool>)
+        -> Result<Schur<Self, Real>, Error>;
+}
+
+/// `Gees` with condition numbers for the selected cluster, which needs a
+/// selector unless `sense` is `Sense::None`.
+pub trait Geesx<Real>: Sized {
+    fn geesx(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<Real>) -> bool>,
+        sense: Sense) -> Result<(Schur<Self, Real>, SchurCondition<Real>), Error>;
+}
+
+extern "C" fn select_real<Real: Copy + Num>(re: *const Real, im: *const Real) -> c_int {
+    call_selector(unsafe { Complex::new(*re, *im) })
+}
+
+extern "C" fn select_complex<Real: Copy>(w: *const Complex<Real>) -> c_int {
+    call_selector(unsafe { *w })
+}
+
+fn real_trampoline<Real: Copy + Num>() -> __CLPK_L_fp {
+    let f: extern "C" fn(*const Real, *const Real) -> c_int = select_real::<Real>;
+    Some(unsafe { mem::transmute(f) })
+}
+
+fn complex_trampoline<Real: Copy>() -> __CLPK_L_fp {
+    let f: extern "C" fn(*const Complex<Real>) -> c_int = select_complex::<Real>;
+    Some(unsafe { mem::transmute(f) })
+}
+
+// `n + 2` only means rounding changed which eigenvalues satisfy the
+// selector after reordering; the decomposition is still valid.
+fn gees_result(n: c_int, info: c_int) -> Result<(), Error> {
+    match info {
+        0 => Ok(()),
+        x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        x if x == n + 1 => Err(Error::ReorderFailed),
+        x if x == n + 2 => Ok(()),
+        x => Err(Error::NoConvergence(x as usize)),
+    }
+}
+
+fn condition<Real>(sense: Sense, rconde: Real, rcondv: Real) -> SchurCondition<Real> {
+    match sense {
+        Sense::None => SchurCondition { eigenvalues: None, subspace: None },
+        Sense::Eigenvalues => SchurCondition { eigenvalues: Some(rconde), subspace: None },
+        Sense::Eigenvectors => SchurCondition { eigenvalues: None, subspace: Some(rcondv) },
+        Sense::Both => SchurCondition { eigenvalues: Some(rconde), subspace: Some(rcondv) },
+    }
+}
+
+// `geesx` needs `n + 2 sdim (n - sdim)` work and `sdim (n - sdim)` iwork
+// for condition numbers, at most `n + n² / 2` and `n² / 4`.
+macro_rules! real_schur_impl(($($t: ident), +) => ($(
+    // `geesx` without condition numbers is `gees`.
+    impl Gees<$t> for $t {
+        fn gees(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<$t>) -> bool>)
+            -> Result<Schur<Self, $t>, Error> {
+
+            let (schur, _) = try!(Geesx::geesx(a, jobvs, select, Sense::None));
+            Ok(schur)
+        }
+    }
+
+    impl Geesx<$t> for $t {
+        fn geesx(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<$t>) -> bool>,
+            sense: Sense) -> Result<(Schur<Self, $t>, SchurCondition<$t>), Error> {
+
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let lda = if n > 0 { n } else { 1 };
+            let mut t = col_vec(a);
+            let mut z: Vec<$t> = match jobvs {
+                Compute::Value => vec![0.0; len * len],
+                Compute::None => Vec::new(),
+            };
+            let (jobvs, z_ptr, ldz) = match jobvs {
+                Compute::Value => (b'V' as i8, z.as_mut_ptr(), lda),
+                Compute::None => (b'N' as i8, ptr::null_mut(), 1),
+            };
+
+            let mut info: c_int = 0;
+            let mut sdim: c_int = 0;
+            let mut rconde: $t = 0.0;
+            let mut rcondv: $t = 0.0;
+            let mut wr: Vec<$t> = vec![0.0; len];
+            let mut wi: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; 3 * len + len * len + 1];
+            let mut iwork: Vec<c_int> = vec![0; len * len / 4 + 1];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            with_selector(select, real_trampoline::<$t>(), |sort, select| unsafe {
+                prefix!($t, geesx_)(jobvs.as_mut(), sort.as_mut(), select,
+                    sense.as_i8().as_mut(),
+                    n.as_mut(), t.as_mut_ptr(), lda.as_mut(),
+                    &mut sdim, wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    &mut rconde, &mut rcondv,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    bwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            });
+
+            try!(gees_result(n, info));
+
+            let values = wr.into_iter().zip(wi.into_iter())
+                .map(|(r, i)| Complex::new(r, i)).collect();
+
+            Ok((Schur {
+                n: n,
+                t: t,
+                z: if jobvs == b'V' as i8 { Some(z) } else { None },
+                values: values,
+                sdim: sdim as usize,
+            }, condition(sense, rconde, rcondv)))
+        }
+    }
+)+));
+
+macro_rules! complex_schur_impl(($($t: ident: $r: ident), +) => ($(
+    // `geesx` without condition numbers is `gees`.
+    impl Gees<$r> for $t {
+        fn gees(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<$r>) -> bool>)
+            -> Result<Schur<Self, $r>, Error> {
+
+            let (schur, _) = try!(Geesx::geesx(a, jobvs, select, Sense::None));
+            Ok(schur)
+        }
+    }
+
+    impl Geesx<$r> for $t {
+        fn geesx(a: &Matrix<Self>, jobvs: Compute, select: Option<&mut FnMut(Complex<$r>) -> bool>,
+            sense: Sense) -> Result<(Schur<Self, $r>, SchurCondition<$r>), Error> {
+
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let lda = if n > 0 { n } else { 1 };
+            let zero = $t::new(0.0, 0.0);
+            let mut t = col_vec(a);
+            let mut z: Vec<$t> = match jobvs {
+                Compute::Value => vec![zero; len * len],
+                Compute::None => Vec::new(),
+            };
+            let (jobvs, z_ptr, ldz) = match jobvs {
+                Compute::Value => (b'V' as i8, z.as_mut_ptr(), lda),
+                Compute::None => (b'N' as i8, ptr::null_mut(), 1),
+            };
+
+            let mut info: c_int = 0;
+            let mut sdim: c_int = 0;
+            let mut rconde: $r = 0.0;
+            let mut rcondv: $r = 0.0;
+            let mut w: Vec<$t> = vec![zero; len];
+            let mut work: Vec<$t> = vec![zero; 2 * len + len * len + 1];
+            let mut rwork: Vec<$r> = vec![0.0; len];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            with_selector(select, complex_trampoline::<$r>(), |sort, select| unsafe {
+                prefix!($t, geesx_)(jobvs.as_mut(), sort.as_mut(), select,
+                    sense.as_i8().as_mut(),
+                    n.as_mut(), t.as_mut_ptr(), lda.as_mut(),
+                    &mut sdim, w.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(),
+                    &mut rconde, &mut rcondv,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    rwork.as_mut_ptr(), bwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            });
+
+            try!(gees_result(n, info));
+
+            Ok((Schur {
+                n: n,
+                t: t,
+                z: if jobvs == b'V' as i8 { Some(z) } else { None },
+                values: w,
+                sdim: sdim as usize,
+            }, condition(sense, rconde, rcondv)))
+        }
+    }
+)+));
+
+real_schur_impl!(f32, f64);
+complex_schur_impl!(Complex32: f32, Complex64: f64);
+
+/// Generalized Schur decomposition `A = Q S Zᴴ`, `B = Q T Zᴴ`, with `T`
+/// upper triangular, `S` upper triangular (quasi upper triangular, for real
+/// matrices) and `Q`, `Z` unitary. All are column major.
+#[derive(Clone, Debug)]
+pub struct GeneralizedSchur<T, Real> {
+    n: c_int,
+    s: Vec<T>,
+    t: Vec<T>,
+    q: Option<Vec<T>>,
+    z: Option<Vec<T>>,
+    values: Vec<GeneralizedEigenvalue<Real>>,
+    sdim: usize,
+}
+
+impl<T, Real> GeneralizedSchur<T, Real> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn s(&self) -> &[T] { &self.s[..] }
+    pub fn t(&self) -> &[T] { &self.t[..] }
+
+    /// The left Schur vectors, if they were computed.
+    pub fn q(&self) -> Option<&[T]> { self.q.as_ref().map(|q| &q[..]) }
+
+    /// The right Schur vectors, if they were computed.
+    pub fn z(&self) -> Option<&[T]> { self.z.as_ref().map(|z| &z[..]) }
+
+    /// The eigenvalues in the order they appear on the diagonals of `S`
+    /// and `T`.
+    pub fn eigenvalues(&self) -> &[GeneralizedEigenvalue<Real>] { &self.values[..] }
+
+    /// The number of selected eigenvalues, which lead `S` and `T`. The
+    /// first `selected()` columns of `Q` and `Z` span their left and right
+    /// deflating subspaces.
+    pub fn selected(&self) -> usize { self.sdim }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Option<Vec<T>>, Option<Vec<T>>, Vec<GeneralizedEigenvalue<Real>>) {
+        (self.s, self.t, self.q, self.z, self.values)
+    }
+}
+
+/// Reciprocal condition numbers for the selected cluster of a generalized
+/// Schur decomposition, present if requested by `Sense`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GeneralizedSchurCondition<Real> {
+    /// Of the projections onto the left and right deflating subspaces.
+    pub projections: Option<(Real, Real)>,
+    /// Estimates of the separations between the selected and the other
+    /// eigenvalues, `Difu` and `Difl`.
+    pub separations: Option<(Real, Real)>,
+}
+
+/// Generalized Schur decomposition of a pair of general matrices. `a` and
+/// `b` are not changed.
+///
+/// With a selector, eigenvalues for which it returns `true` are moved to the
+/// leading blocks, as in `Gees`. An eigenvalue that is infinite, or
+/// undefined when `alpha` and `beta` are both zero, is passed as is.
+pub trait Gges<Real>: Sized {
+    fn gges(a: &Matrix<Self>, b: &Matrix<Self>, jobvs: Compute,
+        select: Option<&mut FnMut(GeneralizedEigenvalue<Real>) -> bool>)
+        -> Result<GeneralizedSchur<Self, Real>, Error>;
+}
+
+/// `Gges` with condition numbers for the selected cluster, which needs a
+/// selector unless `sense` is `Sense::None`.
+pub trait Ggesx<Real>: Sized {
+    fn ggesx(a: &Matrix<Self>, b: &Matrix<Self>, jobvs: Compute,
+        select: Option<&mut FnMut(GeneralizedEigenvalue<Real>) -> bool>, sense: Sense)
+        -> Result<(GeneralizedSchur<Self, Real>, GeneralizedSchurCondition<Real>), Error>;
+}
+
+/// Reorders a generalized Schur decomposition so that the eigenvalues
+/// marked in `select` lead, updating `Q` and `Z` if present. For real
+/// matrices both eigenvalues of a complex pair are moved if either is
+/// selected. `Sense::Eigenvectors` estimates the separations in the
+/// Frobenius norm.
+pub trait Tgsen<Real>: Sized {
+    fn tgsen(schur: &mut GeneralizedSchur<Self, Real>, select: &[bool], sense: Sense)
+        -> Result<GeneralizedSchurCondition<Real>, Error>;
+}
+
+extern "C" fn select_real_pair<Real: Copy + Num>(re: *const Real, im: *const Real, beta: *const Real) -> c_int {
+    call_selector(unsafe {
+        GeneralizedEigenvalue {
+            alpha: Complex::new(*re, *im),
+            beta: Complex::new(*beta, Real::zero()),
+        }
+    })
+}
+
+extern "C" fn select_complex_pair<Real: Copy>(alpha: *const Complex<Real>, beta: *const Complex<Real>) -> c_int {
+    call_selector(unsafe { GeneralizedEigenvalue { alpha: *alpha, beta: *beta } })
+}
+
+fn real_pair_trampoline<Real: Copy + Num>() -> __CLPK_L_fp {
+    let f: extern "C" fn(*const Real, *const Real, *const Real) -> c_int = select_real_pair::<Real>;
+    Some(unsafe { mem::transmute(f) })
+}
+
+fn complex_pair_trampoline<Real: Copy>() -> __CLPK_L_fp {
+    let f: extern "C" fn(*const Complex<Real>, *const Complex<Real>) -> c_int = select_complex_pair::<Real>;
+    Some(unsafe { mem::transmute(f) })
+}
+
+// As for `gees`, `n + 2` leaves a valid decomposition.
+fn gges_result(n: c_int, info: c_int) -> Result<(), Error> {
+    match info {
+        0 => Ok(()),
+        x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+        x if x == n + 2 => Ok(()),
+        x if x == n + 3 => Err(Error::ReorderFailed),
+        x => Err(Error::NoConvergence(x as usize)),
+    }
+}
+
+fn generalized_condition<Real>(sense: Sense, rconde: [Real; 2], rcondv: [Real; 2]) -> GeneralizedSchurCondition<Real>
+    where Real: Copy {
+
+    let projections = Some((rconde[0], rconde[1]));
+    let separations = Some((rcondv[0], rcondv[1]));
+
+    match sense {
+        Sense::None => GeneralizedSchurCondition { projections: None, separations: None },
+        Sense::Eigenvalues => GeneralizedSchurCondition { projections: projections, separations: None },
+        Sense::Eigenvectors => GeneralizedSchurCondition { projections: None, separations: separations },
+        Sense::Both => GeneralizedSchurCondition { projections: projections, separations: separations },
+    }
+}
+
+fn tgsen_job(sense: Sense) -> c_int {
+    match sense {
+        Sense::None => 0,
+        Sense::Eigenvalues => 1,
+        Sense::Eigenvectors => 2,
+        Sense::Both => 4,
+    }
+}
+
+macro_rules! generalized_schur_impl(($($t: ident: $r: ident, $zero: expr), +) => ($(
+    // `ggesx` without condition numbers is `gges`.
+    impl Gges<$r> for $t {
+        fn gges(a: &Matrix<Self>, b: &Matrix<Self>, jobvs: Compute,
+            select: Option<&mut FnMut(GeneralizedEigenvalue<$r>) -> bool>)
+            -> Result<GeneralizedSchur<Self, $r>, Error> {
+
+            let (schur, _) = try!(Ggesx::ggesx(a, b, jobvs, select, Sense::None));
+            Ok(schur)
+        }
+    }
+
+    impl GeneralizedSchur<$t, $r> {
+        fn new(a: &Matrix<$t>, b: &Matrix<$t>, jobvs: Compute) -> Result<GeneralizedSchur<$t, $r>, Error> {
+            let n = a.rows();
+            if n != a.cols() || b.rows() != n || b.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let vectors = match jobvs {
+                Compute::Value => Some(vec![$zero; len * len]),
+                Compute::None => None,
+            };
+
+            Ok(GeneralizedSchur {
+                n: n,
+                s: col_vec(a),
+                t: col_vec(b),
+                q: vectors.clone(),
+                z: vectors,
+                values: Vec::new(),
+                sdim: 0,
+            })
+        }
+
+        fn vectors(&mut self) -> (i8, *mut $t, *mut $t, c_int) {
+            let ld = if self.n > 0 { self.n } else { 1 };
+            match (&mut self.q, &mut self.z) {
+                (&mut Some(ref mut q), &mut Some(ref mut z)) => (b'V' as i8, q.as_mut_ptr(), z.as_mut_ptr(), ld),
+                _ => (b'N' as i8, ptr::null_mut(), ptr::null_mut(), 1),
+            }
+        }
+    }
+)+));
+
+// `ggesx` needs `2 sdim (n - sdim)` work for condition numbers, at most
+// `n² / 2`, and `tgsen` up to twice that.
+macro_rules! real_generalized_schur_impl(($($t: ident), +) => ($(
+    impl Ggesx<$t> for $t {
+        fn ggesx(a: &Matrix<Self>, b: &Matrix<Self>, jobvs: Compute,
+            select: Option<&mut FnMut(GeneralizedEigenvalue<$t>) -> bool>, sense: Sense)
+            -> Result<(GeneralizedSchur<Self, $t>, GeneralizedSchurCondition<$t>), Error> {
+
+            let mut schur = try!(GeneralizedSchur::<$t, $t>::new(a, b, jobvs));
+            let n = schur.n;
+            let len = n as usize;
+            let lda = if n > 0 { n } else { 1 };
+            let (jobvs, q_ptr, z_ptr, ldq) = schur.vectors();
+
+            let mut info: c_int = 0;
+            let mut sdim: c_int = 0;
+            let mut rconde: [$t; 2] = [0.0; 2];
+            let mut rcondv: [$t; 2] = [0.0; 2];
+            let mut alphar: Vec<$t> = vec![0.0; len];
+            let mut alphai: Vec<$t> = vec![0.0; len];
+            let mut beta: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; 8 * len + 16 + len * len];
+            let mut iwork: Vec<c_int> = vec![0; len + 6];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            {
+                let s = &mut schur.s;
+                let t = &mut schur.t;
+
+                with_selector(select, real_pair_trampoline::<$t>(), |sort, select| unsafe {
+                    prefix!($t, ggesx_)(jobvs.as_mut(), jobvs.as_mut(), sort.as_mut(), select,
+                        sense.as_i8().as_mut(),
+                        n.as_mut(),
+                        s.as_mut_ptr(), lda.as_mut(),
+                        t.as_mut_ptr(), lda.as_mut(),
+                        &mut sdim,
+                        alphar.as_mut_ptr(), alphai.as_mut_ptr(), beta.as_mut_ptr(),
+                        q_ptr, ldq.as_mut(), z_ptr, ldq.as_mut(),
+                        rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                        work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                        iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                        bwork.as_mut_ptr(),
+                        &mut info as *mut c_int, 1, 1, 1, 1);
+                });
+            }
+
+            try!(gges_result(n, info));
+
+            schur.values = real_values(alphar, alphai, beta);
+            schur.sdim = sdim as usize;
+
+            Ok((schur, generalized_condition(sense, rconde, rcondv)))
+        }
+    }
+
+    impl Tgsen<$t> for $t {
+        fn tgsen(schur: &mut GeneralizedSchur<Self, $t>, select: &[bool], sense: Sense)
+            -> Result<GeneralizedSchurCondition<$t>, Error> {
+
+            let n = schur.n;
+            let len = n as usize;
+            if select.len() != len {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let (jobvs, q_ptr, z_ptr, ldq) = schur.vectors();
+            let want: c_int = if jobvs == b'V' as i8 { 1 } else { 0 };
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut pl: $t = 0.0;
+            let mut pr: $t = 0.0;
+            let mut dif: [$t; 2] = [0.0; 2];
+            let mut select: Vec<c_int> = select.iter().map(|&s| s as c_int).collect();
+            let mut alphar: Vec<$t> = vec![0.0; len];
+            let mut alphai: Vec<$t> = vec![0.0; len];
+            let mut beta: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; 4 * len + 16 + len * len];
+            let mut iwork: Vec<c_int> = vec![0; len + 6 + len * len];
+
+            unsafe {
+                prefix!($t, tgsen_)(tgsen_job(sense).as_mut(), want.as_mut(), want.as_mut(),
+                    select.as_mut_ptr(),
+                    n.as_mut(),
+                    schur.s.as_mut_ptr(), lda.as_mut(),
+                    schur.t.as_mut_ptr(), lda.as_mut(),
+                    alphar.as_mut_ptr(), alphai.as_mut_ptr(), beta.as_mut_ptr(),
+                    q_ptr, ldq.as_mut(), z_ptr, ldq.as_mut(),
+                    &mut m, &mut pl, &mut pr, dif.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => {},
+                x if x < 0 => return Err(Error::IllegalParameter(-x as usize)),
+                _ => return Err(Error::ReorderFailed),
+            }
+
+            schur.values = real_values(alphar, alphai, beta);
+            schur.sdim = m as usize;
+
+            Ok(generalized_condition(sense, [pl, pr], dif))
+        }
+    }
+)+));
+
+macro_rules! complex_generalized_schur_impl(($($t: ident: $r: ident), +) => ($(
+    impl Ggesx<$r> for $t {
+        fn ggesx(a: &Matrix<Self>, b: &Matrix<Self>, jobvs: Compute,
+            select: Option<&mut FnMut(GeneralizedEigenvalue<$r>) -> bool>, sense: Sense)
+            -> Result<(GeneralizedSchur<Self, $r>, GeneralizedSchurCondition<$r>), Error> {
+
+            let mut schur = try!(GeneralizedSchur::<$t, $r>::new(a, b, jobvs));
+            let n = schur.n;
+            let len = n as usize;
+            let lda = if n > 0 { n } else { 1 };
+            let zero = $t::new(0.0, 0.0);
+            let (jobvs, q_ptr, z_ptr, ldq) = schur.vectors();
+
+            let mut info: c_int = 0;
+            let mut sdim: c_int = 0;
+            let mut rconde: [$r; 2] = [0.0; 2];
+            let mut rcondv: [$r; 2] = [0.0; 2];
+            let mut alpha: Vec<$t> = vec![zero; len];
+            let mut beta: Vec<$t> = vec![zero; len];
+            let mut work: Vec<$t> = vec![zero; 2 * len + len * len + 1];
+            let mut rwork: Vec<$r> = vec![0.0; 8 * len];
+            let mut iwork: Vec<c_int> = vec![0; len + 2];
+            let mut bwork: Vec<c_int> = vec![0; len];
+
+            {
+                let s = &mut schur.s;
+                let t = &mut schur.t;
+
+                with_selector(select, complex_pair_trampoline::<$r>(), |sort, select| unsafe {
+                    prefix!($t, ggesx_)(jobvs.as_mut(), jobvs.as_mut(), sort.as_mut(), select,
+                        sense.as_i8().as_mut(),
+                        n.as_mut(),
+                        s.as_mut_ptr(), lda.as_mut(),
+                        t.as_mut_ptr(), lda.as_mut(),
+                        &mut sdim,
+                        alpha.as_mut_ptr(), beta.as_mut_ptr(),
+                        q_ptr, ldq.as_mut(), z_ptr, ldq.as_mut(),
+                        rconde.as_mut_ptr(), rcondv.as_mut_ptr(),
+                        work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                        rwork.as_mut_ptr(),
+                        iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                        bwork.as_mut_ptr(),
+                        &mut info as *mut c_int, 1, 1, 1, 1);
+                });
+            }
+
+            try!(gges_result(n, info));
+
+            schur.values = complex_values(alpha, beta);
+            schur.sdim = sdim as usize;
+
+            Ok((schur, generalized_condition(sense, rconde, rcondv)))
+        }
+    }
+
+    impl Tgsen<$r> for $t {
+        fn tgsen(schur: &mut GeneralizedSchur<Self, $r>, select: &[bool], sense: Sense)
+            -> Result<GeneralizedSchurCondition<$r>, Error> {
+
+            let n = schur.n;
+            let len = n as usize;
+            if select.len() != len {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let lda = if n > 0 { n } else { 1 };
+            let zero = $t::new(0.0, 0.0);
+            let (jobvs, q_ptr, z_ptr, ldq) = schur.vectors();
+            let want: c_int = if jobvs == b'V' as i8 { 1 } else { 0 };
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut pl: $r = 0.0;
+            let mut pr: $r = 0.0;
+            let mut dif: [$r; 2] = [0.0; 2];
+            let mut select: Vec<c_int> = select.iter().map(|&s| s as c_int).collect();
+            let mut alpha: Vec<$t> = vec![zero; len];
+            let mut beta: Vec<$t> = vec![zero; len];
+            let mut work: Vec<$t> = vec![zero; len * len + 1];
+            let mut iwork: Vec<c_int> = vec![0; len + 2 + len * len];
+
+            unsafe {
+                prefix!($t, tgsen_)(tgsen_job(sense).as_mut(), want.as_mut(), want.as_mut(),
+                    select.as_mut_ptr(),
+                    n.as_mut(),
+                    schur.s.as_mut_ptr(), lda.as_mut(),
+                    schur.t.as_mut_ptr(), lda.as_mut(),
+                    alpha.as_mut_ptr(), beta.as_mut_ptr(),
+                    q_ptr, ldq.as_mut(), z_ptr, ldq.as_mut(),
+                    &mut m, &mut pl, &mut pr, dif.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => {},
+                x if x < 0 => return Err(Error::IllegalParameter(-x as usize)),
+                _ => return Err(Error::ReorderFailed),
+            }
+
+            schur.values = complex_values(alpha, beta);
+            schur.sdim = m as usize;
+
+            Ok(generalized_condition(sense, [pl, pr], dif))
+        }
+    }
+)+));
+
+generalized_schur_impl!(
+    f32: f32, 0.0,
+    f64: f64, 0.0,
+    Complex32: f32, Complex32::new(0.0, 0.0),
+    Complex64: f64, Complex64::new(0.0, 0.0)
+);
+real_generalized_schur_impl!(f32, f64);
+complex_generalized_schur_impl!(Complex32: f32, Complex64: f64);
+
+/// The result of reordering a Schur form with `Trsen`.
+#[derive(Clone, Debug)]
+pub struct SchurReordering<Real> {
+    /// The dimension of the invariant subspace of the selected eigenvalues.
+    pub selected: usize,
+    /// The eigenvalues in their new order.
+    pub eigenvalues: Vec<Complex<Real>>,
+    pub condition: SchurCondition<Real>,
+}
+
+/// Condition numbers from `Trsna`, empty unless requested by `Sense`.
+#[derive(Clone, Debug)]
+pub struct EigenCondition<Real> {
+    /// Reciprocal condition numbers of the eigenvalues.
+    pub eigenvalues: Vec<Real>,
+    /// Estimated reciprocal condition numbers of the right eigenvectors.
+    pub eigenvectors: Vec<Real>,
+}
+
+/// Moves the diagonal block of the Schur form `t` starting at row `ifst` to
+/// row `ilst`, counting from one, updating the Schur vectors `q` if given.
+/// Returns the row the block ends up starting at.
+///
+/// For real matrices a 2 × 2 block moves as a unit, so `ifst` may point at
+/// either of its rows and the block may stop one row short of `ilst`.
+pub trait Trexc: Sized {
+    fn trexc(t: &mut Matrix<Self>, q: Option<&mut Matrix<Self>>, ifst: c_int, ilst: c_int)
+        -> Result<c_int, Error>;
+}
+
+/// Reorders the Schur form `t` so that the eigenvalues marked in `select`
+/// lead, updating the Schur vectors `q` if given. For real matrices both
+/// eigenvalues of a complex pair move if either is marked.
+pub trait Trsen<Real>: Sized {
+    fn trsen(t: &mut Matrix<Self>, q: Option<&mut Matrix<Self>>, select: &[bool], sense: Sense)
+        -> Result<SchurReordering<Real>, Error>;
+}
+
+/// Condition numbers of the eigenvalues and eigenvectors of the Schur form
+/// `t`. Those of the eigenvalues need the left and right eigenvectors of
+/// `t`, or of any matrix with `t` as its Schur form, such as from `Geev`.
+pub trait Trsna<Real>: Sized {
+    fn trsna(t: &Matrix<Self>, vectors: Option<(&Matrix<Self>, &Matrix<Self>)>, sense: Sense)
+        -> Result<EigenCondition<Real>, Error>;
+}
+
+fn check_schur<T>(t: &Matrix<T>, select: &[bool]) -> Result<c_int, Error> {
+    let n = t.rows();
+    if n != t.cols() || select.len() != n as usize {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(n)
+}
+
+fn reorder_error(info: c_int) -> Error {
+    match info {
+        x if x < 0 => Error::IllegalParameter(-x as usize),
+        _ => Error::ReorderFailed,
+    }
+}
+
+macro_rules! schur_reorder_impl(($($t: ident: $r: ident, $zero: expr, [$($work: ident)*]), +) => ($(
+    impl Trexc for $t {
+        fn trexc(t: &mut Matrix<Self>, q: Option<&mut Matrix<Self>>, ifst: c_int, ilst: c_int)
+            -> Result<c_int, Error> {
+
+            let n = t.rows();
+            if n != t.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut q_mem = try!(square_mem(n, q));
+            let (compq, q_ptr, ldq) = mem_job(&mut q_mem);
+            let mut t_mem = ColMem::new(t.order(), t);
+
+            let mut info: c_int = 0;
+            let mut ifst = ifst;
+            let mut ilst = ilst;
+            $(let mut $work: Vec<$t> = vec![$zero; n as usize];)*
+
+            unsafe {
+                prefix!($t, trexc_)(compq.as_mut(), n.as_mut(),
+                    t_mem.as_mut_ptr(), t_mem.lead().as_mut(),
+                    q_ptr, ldq.as_mut(),
+                    &mut ifst, &mut ilst,
+                    $($work.as_mut_ptr(),)*
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(ilst),
+                x => Err(reorder_error(x)),
+            }
+        }
+    }
+
+    impl Schur<$t, $r> {
+        /// Reorders the decomposition with `Trsen`.
+        pub fn reorder(&mut self, select: &[bool], sense: Sense) -> Result<SchurCondition<$r>, Error> {
+            let n = self.n;
+            let reordering = {
+                let mut t = ColSlice(n, n, &mut self.t[..]);
+                let mut z = self.z.as_mut().map(|z| ColSlice(n, n, &mut z[..]));
+                let q = z.as_mut().map(|z| z as &mut Matrix<$t>);
+                try!(Trsen::trsen(&mut t, q, select, sense))
+            };
+
+            self.values = reordering.eigenvalues;
+            self.sdim = reordering.selected;
+            Ok(reordering.condition)
+        }
+
+        /// Moves an eigenvalue with `Trexc`. `selected()` is left as is.
+        pub fn move_eigenvalue(&mut self, ifst: c_int, ilst: c_int) -> Result<c_int, Error> {
+            let n = self.n;
+            let ilst = {
+                let mut t = ColSlice(n, n, &mut self.t[..]);
+                let mut z = self.z.as_mut().map(|z| ColSlice(n, n, &mut z[..]));
+                let q = z.as_mut().map(|z| z as &mut Matrix<$t>);
+                try!(Trexc::trexc(&mut t, q, ifst, ilst))
+            };
+
+            self.values = Schur::<$t, $r>::diagonal_values(n as usize, &self.t);
+            Ok(ilst)
+        }
+    }
+)+));
+
+// Condition numbers need `n (n - 1) / 2` work and iwork, at most `n² / 4`
+// for `trsen` and `n (n + 6)` for `trsna`.
+macro_rules! real_schur_reorder_impl(($($t: ident), +) => ($(
+    impl Schur<$t, $t> {
+        // 2 × 2 blocks are in the standard form of `lanv2`, with equal
+        // diagonal elements and off-diagonal elements of opposite sign.
+        fn diagonal_values(n: usize, t: &[$t]) -> Vec<Complex<$t>> {
+            let mut values = Vec::with_capacity(n);
+            let mut k = 0;
+
+            while k < n {
+                let a = t[k + k * n];
+                if k + 1 < n && t[k + 1 + k * n] != 0.0 {
+                    let b = t[k + (k + 1) * n];
+                    let c = t[k + 1 + k * n];
+                    let im = b.abs().sqrt() * c.abs().sqrt();
+                    values.push(Complex::new(a, im));
+                    values.push(Complex::new(t[k + 1 + (k + 1) * n], -im));
+                    k += 2;
+                } else {
+                    values.push(Complex::new(a, 0.0));
+                    k += 1;
+                }
+            }
+
+            values
+        }
+    }
+
+    impl Trsen<$t> for $t {
+        fn trsen(t: &mut Matrix<Self>, q: Option<&mut Matrix<Self>>, select: &[bool], sense: Sense)
+            -> Result<SchurReordering<$t>, Error> {
+
+            let n = try!(check_schur(t, select));
+            let len = n as usize;
+            let mut q_mem = try!(square_mem(n, q));
+            let (compq, q_ptr, ldq) = mem_job(&mut q_mem);
+            let mut t_mem = ColMem::new(t.order(), t);
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut s: $t = 0.0;
+            let mut sep: $t = 0.0;
+            let mut select: Vec<c_int> = select.iter().map(|&x| x as c_int).collect();
+            let mut wr: Vec<$t> = vec![0.0; len];
+            let mut wi: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; len + len * len / 2 + 1];
+            let mut iwork: Vec<c_int> = vec![0; len * len / 4 + 1];
+
+            unsafe {
+                prefix!($t, trsen_)(sense.as_i8().as_mut(), compq.as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t_mem.as_mut_ptr(), t_mem.lead().as_mut(),
+                    q_ptr, ldq.as_mut(),
+                    wr.as_mut_ptr(), wi.as_mut_ptr(),
+                    &mut m, &mut s, &mut sep,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(SchurReordering {
+                    selected: m as usize,
+                    eigenvalues: wr.into_iter().zip(wi.into_iter())
+                        .map(|(r, i)| Complex::new(r, i)).collect(),
+                    condition: condition(sense, s, sep),
+                }),
+                x => Err(reorder_error(x)),
+            }
+        }
+    }
+
+    impl Trsna<$t> for $t {
+        fn trsna(t: &Matrix<Self>, vectors: Option<(&Matrix<Self>, &Matrix<Self>)>, sense: Sense)
+            -> Result<EigenCondition<$t>, Error> {
+
+            let n = t.rows();
+            let len = n as usize;
+            if n != t.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut t = col_vec(t);
+            let ld = if n > 0 { n } else { 1 };
+            let (mut vl, mut vr) = match vectors {
+                Some((vl, vr)) => {
+                    if vl.rows() != n || vl.cols() != n || vr.rows() != n || vr.cols() != n {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    (col_vec(vl), col_vec(vr))
+                },
+                None => match sense {
+                    Sense::Eigenvalues | Sense::Both => return Err(Error::IllegalParameter(7)),
+                    _ => (Vec::new(), Vec::new()),
+                },
+            };
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut select: Vec<c_int> = vec![0; len];
+            let mut s: Vec<$t> = vec![0.0; len];
+            let mut sep: Vec<$t> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![0.0; len * (len + 6) + 1];
+            let mut iwork: Vec<c_int> = vec![0; 2 * len + 1];
+
+            unsafe {
+                prefix!($t, trsna_)(sense.as_i8().as_mut(), (b'A' as i8).as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t.as_mut_ptr(), ld.as_mut(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    s.as_mut_ptr(), sep.as_mut_ptr(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(), ld.as_mut(),
+                    iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            let (s_len, sep_len) = sense.condition_lens(len);
+            s.truncate(s_len);
+            sep.truncate(sep_len);
+
+            match info {
+                0 => Ok(EigenCondition { eigenvalues: s, eigenvectors: sep }),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_schur_reorder_impl(($($t: ident: $r: ident), +) => ($(
+    impl Schur<$t, $r> {
+        fn diagonal_values(n: usize, t: &[$t]) -> Vec<Complex<$r>> {
+            (0..n).map(|k| t[k + k * n]).collect()
+        }
+    }
+
+    impl Trsen<$r> for $t {
+        fn trsen(t: &mut Matrix<Self>, q: Option<&mut Matrix<Self>>, select: &[bool], sense: Sense)
+            -> Result<SchurReordering<$r>, Error> {
+
+            let n = try!(check_schur(t, select));
+            let len = n as usize;
+            let zero = $t::new(0.0, 0.0);
+            let mut q_mem = try!(square_mem(n, q));
+            let (compq, q_ptr, ldq) = mem_job(&mut q_mem);
+            let mut t_mem = ColMem::new(t.order(), t);
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut s: $r = 0.0;
+            let mut sep: $r = 0.0;
+            let mut select: Vec<c_int> = select.iter().map(|&x| x as c_int).collect();
+            let mut w: Vec<$t> = vec![zero; len];
+            let mut work: Vec<$t> = vec![zero; len * len / 2 + 1];
+
+            unsafe {
+                prefix!($t, trsen_)(sense.as_i8().as_mut(), compq.as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t_mem.as_mut_ptr(), t_mem.lead().as_mut(),
+                    q_ptr, ldq.as_mut(),
+                    w.as_mut_ptr(),
+                    &mut m, &mut s, &mut sep,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(SchurReordering {
+                    selected: m as usize,
+                    eigenvalues: w,
+                    condition: condition(sense, s, sep),
+                }),
+                x => Err(reorder_error(x)),
+            }
+        }
+    }
+
+    impl Trsna<$r> for $t {
+        fn trsna(t: &Matrix<Self>, vectors: Option<(&Matrix<Self>, &Matrix<Self>)>, sense: Sense)
+            -> Result<EigenCondition<$r>, Error> {
+
+            let n = t.rows();
+            let len = n as usize;
+            if n != t.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let zero = $t::new(0.0, 0.0);
+            let mut t = col_vec(t);
+            let ld = if n > 0 { n } else { 1 };
+            let (mut vl, mut vr) = match vectors {
+                Some((vl, vr)) => {
+                    if vl.rows() != n || vl.cols() != n || vr.rows() != n || vr.cols() != n {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    (col_vec(vl), col_vec(vr))
+                },
+                None => match sense {
+                    Sense::Eigenvalues | Sense::Both => return Err(Error::IllegalParameter(7)),
+                    _ => (Vec::new(), Vec::new()),
+                },
+            };
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut select: Vec<c_int> = vec![0; len];
+            let mut s: Vec<$r> = vec![0.0; len];
+            let mut sep: Vec<$r> = vec![0.0; len];
+            let mut work: Vec<$t> = vec![zero; len * (len + 1) + 1];
+            let mut rwork: Vec<$r> = vec![0.0; len + 1];
+
+            unsafe {
+                prefix!($t, trsna_)(sense.as_i8().as_mut(), (b'A' as i8).as_mut(),
+                    select.as_mut_ptr(), n.as_mut(),
+                    t.as_mut_ptr(), ld.as_mut(),
+                    vl.as_mut_ptr(), ld.as_mut(),
+                    vr.as_mut_ptr(), ld.as_mut(),
+                    s.as_mut_ptr(), sep.as_mut_ptr(),
+                    n.as_mut(), &mut m,
+                    work.as_mut_ptr(), ld.as_mut(),
+                    rwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            let (s_len, sep_len) = sense.condition_lens(len);
+            s.truncate(s_len);
+            sep.truncate(sep_len);
+
+            match info {
+                0 => Ok(EigenCondition { eigenvalues: s, eigenvectors: sep }),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+schur_reorder_impl!(
+    f32: f32, 0.0, [work],
+    f64: f64, 0.0, [work],
+    Complex32: f32, Complex32::new(0.0, 0.0), [],
+    Complex64: f64, Complex64::new(0.0, 0.0), []
+);
+real_schur_reorder_impl!(f32, f64);
+complex_schur_reorder_impl!(Complex32: f32, Complex64: f64);
+
+#[cfg(test)]
+mod schur_tests {
+    use num::complex::{Complex, Complex64};
+    use matrix::tests::M;
+    use error::Error;
+    use schur::{Gees, Geesx, Trexc, Trsen, Trsna};
+    use types::{Compute, Sense};
+    use types::Order::*;
+
+    #[test]
+    fn select_negative() {
+        let data = vec![1.0f64, 2.0, 0.0, -3.0];
+        let a = M(RowMajor, 2i32, 2i32, data.clone());
+        let s = Gees::gees(&a, Compute::Value, Some(&mut |l: Complex<f64>| l.re < 0.0)).unwrap();
+
+        assert_eq!(s.selected(), 1);
+        assert!((s.eigenvalues()[0].re + 3.0).abs() < 1e-12);
+
+        // A Z = Z T, with A read back in column major.
+        let (t, z) = (s.t(), s.z().unwrap());
+        let a = [data[0], data[2], data[1], data[3]];
+        for i in 0..2 {
+            for j in 0..2 {
+                let az: f64 = (0..2).map(|k| a[i + k * 2] * z[k + j * 2]).sum();
+                let zt: f64 = (0..2).map(|k| z[i + k * 2] * t[k + j * 2]).sum();
+                assert!((az - zt).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn complex_pair() {
+        let a = M(ColMajor, 3i32, 3i32, vec![0.0f64, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0]);
+
+        let s = Gees::gees(&a, Compute::None, Some(&mut |l: Complex<f64>| l.im > 0.0)).unwrap();
+        assert_eq!(s.selected(), 2);
+        assert!(s.z().is_none());
+        assert!((s.eigenvalues()[0].im.abs() - 1.0).abs() < 1e-12);
+
+        let s = Gees::gees(&a, Compute::None, None).unwrap();
+        assert_eq!(s.selected(), 0);
+    }
+
+    #[test]
+    fn complex() {
+        let c = |re, im| Complex64::new(re, im);
+        let a = M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0), c(0.0, 2.0)]);
+        let (s, cond) = Geesx::geesx(&a, Compute::Value, Some(&mut |l: Complex<f64>| l.norm() > 1.5), Sense::Both).unwrap();
+
+        assert_eq!(s.selected(), 1);
+        assert!((s.eigenvalues()[0] - c(0.0, 2.0)).norm() < 1e-12);
+
+        let e = cond.eigenvalues.unwrap();
+        assert!(e > 0.0 && e <= 1.0);
+        assert!(cond.subspace.unwrap() > 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "selector")]
+    fn selector_panic() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 2.0]);
+        let _ = Gees::gees(&a, Compute::None, Some(&mut |_: Complex<f64>| -> bool { panic!("selector") }));
+    }
+
+    // Upper triangular with eigenvalues 1, 2 and 3.
+    fn triangular() -> Vec<f64> {
+        vec![1.0, 0.0, 0.0, 1.0, 2.0, 0.0, 1.0, 1.0, 3.0]
+    }
+
+    #[test]
+    fn exchange_block() {
+        // A 1 × 1 block for 5 ahead of a standard 2 × 2 block for 1 ± 2i.
+        let mut t = M(ColMajor, 3i32, 3i32, vec![5.0f64, 0.0, 0.0, 1.0, 1.0, -2.0, 1.0, 2.0, 1.0]);
+        let mut q = M(ColMajor, 3i32, 3i32, vec![1.0f64, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+
+        assert_eq!(Trexc::trexc(&mut t, Some(&mut q), 3, 1).unwrap(), 1);
+        assert!(t.3[1] != 0.0);
+        assert!((t.3[8] - 5.0).abs() < 1e-12);
+        assert!((t.3[0] - 1.0).abs() < 1e-12);
+        assert!((t.3[2]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reorder_selected() {
+        for &order in &[ColMajor, RowMajor] {
+            let d = triangular();
+            let data = match order {
+                ColMajor => d,
+                RowMajor => vec![d[0], d[3], d[6], d[1], d[4], d[7], d[2], d[5], d[8]],
+            };
+            let mut t = M(order, 3i32, 3i32, data);
+            let r = Trsen::trsen(&mut t, None, &[false, false, true], Sense::Both).unwrap();
+
+            assert_eq!(r.selected, 1);
+            assert!((r.eigenvalues[0].re - 3.0).abs() < 1e-12);
+            assert!((t.3[0] - 3.0).abs() < 1e-12);
+            assert!(r.condition.eigenvalues.unwrap() > 0.0);
+            assert!(r.condition.subspace.unwrap() > 0.0);
+        }
+    }
+
+    #[test]
+    fn condition_numbers() {
+        let t = M(ColMajor, 3i32, 3i32, triangular());
+        let c = Trsna::trsna(&t, None, Sense::Eigenvectors).unwrap();
+
+        assert!(c.eigenvalues.is_empty());
+        assert_eq!(c.eigenvectors.len(), 3);
+        assert!(c.eigenvectors.iter().all(|&x| x > 0.0));
+
+        match Trsna::trsna(&t, None, Sense::Eigenvalues) {
+            Err(Error::IllegalParameter(7)) => {},
+            _ => panic!("eigenvalue condition numbers need eigenvectors"),
+        }
+    }
+
+    #[test]
+    fn reorder_decomposition() {
+        let c = |re, im| Complex64::new(re, im);
+        let a = M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0), c(0.0, 2.0)]);
+        let mut s = Gees::gees(&a, Compute::Value, None).unwrap();
+        let select: Vec<bool> = s.eigenvalues().iter().map(|l| l.norm() > 1.5).collect();
+
+        s.reorder(&select, Sense::None).unwrap();
+        assert_eq!(s.selected(), 1);
+        assert!((s.eigenvalues()[0] - c(0.0, 2.0)).norm() < 1e-12);
+        assert!((s.t()[0] - c(0.0, 2.0)).norm() < 1e-12);
+
+        s.move_eigenvalue(1, 2).unwrap();
+        assert!((s.eigenvalues()[0] - c(1.0, 0.0)).norm() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod generalized_schur_tests {
+    use general_eigenvalues::GeneralizedEigenvalue;
+    use matrix::tests::M;
+    use schur::{Gges, Ggesx, Tgsen};
+    use types::{Compute, Sense};
+    use types::Order::*;
+
+    fn diagonal() -> (M<f64>, M<f64>) {
+        let mut a = vec![0.0f64; 9];
+        let mut b = vec![0.0f64; 9];
+        for (i, &(x, y)) in [(1.0, 1.0), (4.0, 2.0), (3.0, 0.0)].iter().enumerate() {
+            a[i * 4] = x;
+            b[i * 4] = y;
+        }
+        (M(ColMajor, 3i32, 3i32, a), M(ColMajor, 3i32, 3i32, b))
+    }
+
+    fn is(x: &GeneralizedEigenvalue<f64>, value: f64) -> bool {
+        x.value().map_or(false, |v| (v.re - value).abs() < 1e-12)
+    }
+
+    #[test]
+    fn select_infinite() {
+        let (a, b) = diagonal();
+        let s = Gges::gges(&a, &b, Compute::Value,
+            Some(&mut |x: GeneralizedEigenvalue<f64>| x.is_infinite())).unwrap();
+
+        assert_eq!(s.selected(), 1);
+        assert!(s.eigenvalues()[0].is_infinite());
+
+        // Q and Z are orthogonal.
+        let (q, z) = (s.q().unwrap(), s.z().unwrap());
+        for v in &[q, z] {
+            for i in 0..3 {
+                for j in 0..3 {
+                    let x: f64 = (0..3).map(|k| v[k + i * 3] * v[k + j * 3]).sum();
+                    assert!((x - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reorder() {
+        let (a, b) = diagonal();
+        let mut s = Gges::gges(&a, &b, Compute::Value, None).unwrap();
+        assert_eq!(s.selected(), 0);
+
+        let select: Vec<bool> = s.eigenvalues().iter().map(|x| is(x, 2.0)).collect();
+        let cond = Tgsen::tgsen(&mut s, &select, Sense::Both).unwrap();
+
+        assert_eq!(s.selected(), 1);
+        assert!(is(&s.eigenvalues()[0], 2.0));
+        assert!(cond.projections.unwrap().0 > 0.0);
+        assert!(cond.separations.is_some());
+    }
+
+    #[test]
+    fn expert() {
+        let (a, b) = diagonal();
+        let (s, cond) = Ggesx::ggesx(&a, &b, Compute::None,
+            Some(&mut |x: GeneralizedEigenvalue<f64>| is(&x, 1.0)), Sense::Eigenvalues).unwrap();
+
+        assert_eq!(s.selected(), 1);
+        assert!(s.q().is_none());
+        assert!(cond.projections.is_some());
+        assert!(cond.separations.is_none());
+    }
+}
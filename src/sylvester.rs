@@ -0,0 +1,430 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::slice;
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use linear_equations::Gesv;
+use ll::*;
+use matrix::Matrix;
+use scalar::Scalar;
+use schur::Gees;
+use types::{Compute, LyapunovForm, Sign, Transpose};
+use util::{ColMem, ColSlice, adjoint, check_square, col_vec, multiply};
+
+/// The `scale` a Sylvester equation was solved with, at most one and chosen
+/// to avoid overflow.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SylvesterScale<Real> {
+    pub scale: Real,
+    /// Whether LAPACK perturbed close eigenvalues of the coefficients. The
+    /// equation is then singular or nearly so, and `X` is only approximate.
+    pub perturbed: bool,
+}
+
+/// Solves `op(A) X ± X op(B) = scale C` for upper triangular `A` and `B`, or
+/// for real matrices quasi upper triangular in Schur canonical form, such as
+/// `T` from `Gees`. `C` is overwritten with `X`.
+///
+/// If `A` and `∓B` have close eigenvalues LAPACK perturbs them, and the
+/// approximate solution is still returned, flagged as `perturbed`.
+pub trait Trsyl<Real>: Sized {
+    fn trsyl(trana: Transpose, tranb: Transpose, sign: Sign,
+        a: &Matrix<Self>, b: &Matrix<Self>, c: &mut Matrix<Self>) -> Result<SylvesterScale<Real>, Error>;
+}
+
+/// Solves the Sylvester equation `A X + X B = scale C` for general square
+/// `A` and `B` by reducing both to Schur form. `C` is overwritten with `X`.
+pub trait Sylvester<Real>: Sized {
+    fn solve_sylvester(a: &Matrix<Self>, b: &Matrix<Self>, c: &mut Matrix<Self>)
+        -> Result<SylvesterScale<Real>, Error>;
+}
+
+/// Solves a Lyapunov equation with `scale Q` in place of `Q`, overwriting
+/// `Q` with `X`.
+///
+/// The discrete form is reduced to the continuous one by a Cayley
+/// transform, which needs `A + I` to be nonsingular. If it is not, the
+/// equation is singular too.
+pub trait Lyapunov<Real>: Sized {
+    fn solve_lyapunov(form: LyapunovForm, a: &Matrix<Self>, q: &mut Matrix<Self>)
+        -> Result<SylvesterScale<Real>, Error>;
+}
+
+/// The solution of a generalized Sylvester equation from `Tgsyl`.
+#[derive(Clone, Debug)]
+pub struct GeneralizedSylvester<T, Real> {
+    /// `R`, column major.
+    pub r: Vec<T>,
+    /// `L`, column major.
+    pub l: Vec<T>,
+    pub scale: Real,
+    /// Whether LAPACK perturbed close eigenvalues of the pairs, as for
+    /// `SylvesterScale`.
+    pub perturbed: bool,
+    /// An estimate of `Dif[(A, D), (B, E)]`, the separation of the pairs,
+    /// if requested.
+    pub dif: Option<Real>,
+}
+
+/// Solves the generalized Sylvester equation
+///
+/// ```text
+/// A R - L B = scale C
+/// D R - L E = scale F
+/// ```
+///
+/// for `m × m` `(A, D)` and `n × n` `(B, E)` in generalized Schur form, such
+/// as `S` and `T` from `Gges`. The returned `scale`, at most one, is chosen
+/// to avoid overflow. The inputs are not changed.
+///
+/// As with `Trsyl`, if the pairs have close eigenvalues LAPACK perturbs
+/// them, and the approximate solution is still returned, flagged as
+/// `perturbed`.
+pub trait Tgsyl<Real>: Sized {
+    fn tgsyl(ad: (&Matrix<Self>, &Matrix<Self>), be: (&Matrix<Self>, &Matrix<Self>),
+        cf: (&Matrix<Self>, &Matrix<Self>), dif: bool) -> Result<GeneralizedSylvester<Self, Real>, Error>;
+}
+
+macro_rules! sylvester_impl(($($t: ident: $r: ident, $zero: expr, $one: expr, $conj: expr), +) => ($(
+    impl Trsyl<$r> for $t {
+        fn trsyl(trana: Transpose, tranb: Transpose, sign: Sign,
+            a: &Matrix<Self>, b: &Matrix<Self>, c: &mut Matrix<Self>) -> Result<SylvesterScale<$r>, Error> {
+
+            let m = a.rows();
+            let n = b.rows();
+            if a.cols() != m || b.cols() != n || c.rows() != m || c.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut a = col_vec(a);
+            let mut b = col_vec(b);
+            let lda = if m > 0 { m } else { 1 };
+            let ldb = if n > 0 { n } else { 1 };
+            let mut c_mem = ColMem::new(c.order(), c);
+
+            let mut info: c_int = 0;
+            let mut scale: $r = 0.0;
+
+            unsafe {
+                prefix!($t, trsyl_)(trana.as_i8().as_mut(), tranb.as_i8().as_mut(),
+                    sign.as_c_int().as_mut(), m.as_mut(), n.as_mut(),
+                    a.as_mut_ptr(), lda.as_mut(),
+                    b.as_mut_ptr(), ldb.as_mut(),
+                    c_mem.as_mut_ptr(), c_mem.lead().as_mut(),
+                    &mut scale, &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Ok(SylvesterScale { scale: scale, perturbed: x > 0 }),
+            }
+        }
+    }
+
+    impl Sylvester<$r> for $t {
+        fn solve_sylvester(a: &Matrix<Self>, b: &Matrix<Self>, c: &mut Matrix<Self>)
+            -> Result<SylvesterScale<$r>, Error> {
+
+            let m = a.rows();
+            let n = b.rows();
+            if a.cols() != m || b.cols() != n || c.rows() != m || c.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let (mu, nu) = (m as usize, n as usize);
+            let (mut s, u, _) = try!(<$t as Gees<$r>>::gees(a, Compute::Value, None)).into_parts();
+            let (mut t, v, _) = try!(<$t as Gees<$r>>::gees(b, Compute::Value, None)).into_parts();
+            let (u, v) = (u.unwrap(), v.unwrap());
+
+            let mut c_mem = ColMem::new(c.order(), c);
+            let c = unsafe { slice::from_raw_parts_mut(c_mem.as_mut_ptr(), mu * nu) };
+
+            // With A = U S Uᴴ and B = V T Vᴴ, Y = Uᴴ X V solves S Y + Y T = Uᴴ C V.
+            let mut y = multiply(mu, mu, nu, &adjoint(mu, mu, &u, $conj), &multiply(mu, nu, nu, c, &v));
+            let scale = try!(Trsyl::trsyl(Transpose::None, Transpose::None, Sign::Plus,
+                &ColSlice(m, m, &mut s), &ColSlice(n, n, &mut t), &mut ColSlice(m, n, &mut y)));
+
+            let x = multiply(mu, mu, nu, &u, &multiply(mu, nu, nu, &y, &adjoint(nu, nu, &v, $conj)));
+            c.copy_from_slice(&x);
+            Ok(scale)
+        }
+    }
+
+    impl Lyapunov<$r> for $t {
+        fn solve_lyapunov(form: LyapunovForm, a: &Matrix<Self>, q: &mut Matrix<Self>)
+            -> Result<SylvesterScale<$r>, Error> {
+
+            let n = a.rows();
+            if a.cols() != n || q.rows() != n || q.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let nu = n as usize;
+            let mut q_mem = ColMem::new(q.order(), q);
+            let q = unsafe { slice::from_raw_parts_mut(q_mem.as_mut_ptr(), nu * nu) };
+
+            let (mut a, rhs) = match form {
+                LyapunovForm::Continuous => (col_vec(a), q.to_vec()),
+                LyapunovForm::Discrete => {
+                    // With F = A + I, A X Aᴴ - X = -Q is C X + X Cᴴ = -2 F⁻¹ Q F⁻ᴴ
+                    // for C = F⁻¹ (A - I).
+                    let one = $one;
+                    let mut f = col_vec(a);
+                    let mut c = f.clone();
+                    for k in 0..nu {
+                        f[k + k * nu] = f[k + k * nu] + one;
+                        c[k + k * nu] = c[k + k * nu] - one;
+                    }
+                    c.extend_from_slice(q);
+                    try!(Gesv::gesv(&mut ColSlice(n, n, &mut f.clone()), &mut ColSlice(n, 2 * n, &mut c)));
+
+                    let mut g = adjoint(nu, nu, &c[nu * nu..], $conj);
+                    try!(Gesv::gesv(&mut ColSlice(n, n, &mut f), &mut ColSlice(n, n, &mut g)));
+                    c.truncate(nu * nu);
+
+                    let two = one + one;
+                    (c, adjoint(nu, nu, &g, $conj).into_iter().map(|x| x * two).collect())
+                },
+            };
+
+            let (mut s, u, _) = try!(<$t as Gees<$r>>::gees(&ColSlice(n, n, &mut a), Compute::Value, None))
+                .into_parts();
+            let u = u.unwrap();
+            let uh = adjoint(nu, nu, &u, $conj);
+
+            // With A = U S Uᴴ, Y = Uᴴ X U solves S Y + Y Sᴴ = -Uᴴ Q U.
+            let mut y: Vec<$t> = multiply(nu, nu, nu, &uh, &multiply(nu, nu, nu, &rhs, &u))
+                .into_iter().map(|x| -x).collect();
+            let mut sh = s.clone();
+            let scale = try!(Trsyl::trsyl(Transpose::None, Transpose::Conjugate, Sign::Plus,
+                &ColSlice(n, n, &mut s), &ColSlice(n, n, &mut sh), &mut ColSlice(n, n, &mut y)));
+
+            let x = multiply(nu, nu, nu, &u, &multiply(nu, nu, nu, &y, &uh));
+            q.copy_from_slice(&x);
+            Ok(scale)
+        }
+    }
+
+    // `Dif` needs `2 m n` work; `iwork` is `m + n + 6` for real matrices and
+    // `m + n + 2` for complex ones.
+    impl Tgsyl<$r> for $t {
+        fn tgsyl(ad: (&Matrix<Self>, &Matrix<Self>), be: (&Matrix<Self>, &Matrix<Self>),
+            cf: (&Matrix<Self>, &Matrix<Self>), dif: bool) -> Result<GeneralizedSylvester<Self, $r>, Error> {
+
+            let (a, d) = ad;
+            let (b, e) = be;
+            let (c, f) = cf;
+            let m = try!(check_square(a));
+            let n = try!(check_square(b));
+            if try!(check_square(d)) != m || try!(check_square(e)) != n {
+                return Err(Error::DimensionMismatch);
+            }
+            if c.rows() != m || c.cols() != n || f.rows() != m || f.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let (mut a, mut b, mut d, mut e) = (col_vec(a), col_vec(b), col_vec(d), col_vec(e));
+            let mut r = col_vec(c);
+            let mut l = col_vec(f);
+            let ldm = if m > 0 { m } else { 1 };
+            let ldn = if n > 0 { n } else { 1 };
+
+            let mut info: c_int = 0;
+            let mut ijob: c_int = if dif { 1 } else { 0 };
+            let mut scale: $r = 0.0;
+            let mut dif_value: $r = 0.0;
+            let mut work: Vec<$t> = vec![$zero; 2 * (m * n) as usize + 1];
+            let mut iwork: Vec<c_int> = vec![0; (m + n + 6) as usize];
+
+            unsafe {
+                prefix!($t, tgsyl_)((b'N' as i8).as_mut(), &mut ijob,
+                    m.as_mut(), n.as_mut(),
+                    a.as_mut_ptr(), ldm.as_mut(),
+                    b.as_mut_ptr(), ldn.as_mut(),
+                    r.as_mut_ptr(), ldm.as_mut(),
+                    d.as_mut_ptr(), ldm.as_mut(),
+                    e.as_mut_ptr(), ldn.as_mut(),
+                    l.as_mut_ptr(), ldm.as_mut(),
+                    &mut scale, &mut dif_value,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Ok(GeneralizedSylvester {
+                    r: r,
+                    l: l,
+                    scale: scale,
+                    perturbed: x > 0,
+                    dif: if dif { Some(dif_value) } else { None },
+                }),
+            }
+        }
+    }
+)+));
+
+sylvester_impl!(
+    f32: f32, 0.0, 1.0, |x: f32| x,
+    f64: f64, 0.0, 1.0, |x: f64| x,
+    Complex32: f32, Complex32::new(0.0, 0.0), Complex32::new(1.0, 0.0), |x: Complex32| x.conj(),
+    Complex64: f64, Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0), |x: Complex64| x.conj()
+);
+
+#[cfg(test)]
+mod sylvester_tests {
+    use num::complex::Complex64;
+    use matrix::tests::M;
+    use error::Error;
+    use sylvester::{Lyapunov, Sylvester, Tgsyl, Trsyl};
+    use types::{LyapunovForm, Sign, Transpose};
+    use types::Order::*;
+    use util::{adjoint, multiply};
+
+    #[test]
+    fn triangular() {
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 2.0, 3.0]);
+        let b = M(ColMajor, 1i32, 1i32, vec![4.0f64]);
+        let mut c = M(ColMajor, 2i32, 1i32, vec![1.0f64, 2.0]);
+
+        // (A + 4 I) x = c.
+        let s = Trsyl::trsyl(Transpose::None, Transpose::None, Sign::Plus, &a, &b, &mut c).unwrap();
+        assert_eq!(s.scale, 1.0);
+        assert!(!s.perturbed);
+        assert!((c.3[0] - 3.0 / 35.0).abs() < 1e-12);
+        assert!((c.3[1] - 2.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sylvester() {
+        let a = vec![1.0f64, 3.0, 2.0, 4.0];
+        let b = vec![2.0f64, 1.0, 0.0, 3.0];
+        let c = vec![1.0f64, 0.0, -1.0, 2.0];
+
+        for &order in &[ColMajor, RowMajor] {
+            let f = |v: &Vec<f64>| match order {
+                ColMajor => v.clone(),
+                RowMajor => adjoint(2, 2, v, |x| x),
+            };
+            let mut x = M(order, 2i32, 2i32, f(&c));
+            let scale = Sylvester::solve_sylvester(&M(order, 2i32, 2i32, f(&a)), &M(order, 2i32, 2i32, f(&b)), &mut x)
+                .unwrap().scale;
+
+            let x = f(&x.3);
+            let (ax, xb) = (multiply(2, 2, 2, &a, &x), multiply(2, 2, 2, &x, &b));
+            for k in 0..4 {
+                assert!((ax[k] + xb[k] - scale * c[k]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn continuous() {
+        let a = vec![-1.0f64, 0.0, 1.0, -2.0];
+        let mut x = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let scale = Lyapunov::solve_lyapunov(LyapunovForm::Continuous, &M(ColMajor, 2i32, 2i32, a.clone()), &mut x)
+            .unwrap().scale;
+
+        let (ax, xa) = (multiply(2, 2, 2, &a, &x.3), multiply(2, 2, 2, &x.3, &adjoint(2, 2, &a, |x| x)));
+        for k in 0..4 {
+            let q = if k % 3 == 0 { 1.0 } else { 0.0 };
+            assert!((ax[k] + xa[k] + scale * q).abs() < 1e-12);
+        }
+        assert!((x.3[1] - x.3[2]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn discrete() {
+        let a = vec![0.5f64, 0.0, 1.0, -0.25];
+        let mut x = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let scale = Lyapunov::solve_lyapunov(LyapunovForm::Discrete, &M(ColMajor, 2i32, 2i32, a.clone()), &mut x)
+            .unwrap().scale;
+
+        let axa = multiply(2, 2, 2, &a, &multiply(2, 2, 2, &x.3, &adjoint(2, 2, &a, |x| x)));
+        for k in 0..4 {
+            let q = if k % 3 == 0 { 1.0 } else { 0.0 };
+            assert!((axa[k] - x.3[k] + scale * q).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn generalized() {
+        // (A, D) has eigenvalues 1 and 3 / 2, (B, E) has -1 and -2.
+        let a = vec![1.0f64, 0.0, 2.0, 3.0];
+        let d = vec![1.0f64, 0.0, 1.0, 2.0];
+        let b = vec![-1.0f64, 0.0, 1.0, -2.0];
+        let e = vec![1.0f64, 0.0, 0.0, 1.0];
+        let c = vec![1.0f64, 2.0, 3.0, 4.0];
+        let f = vec![0.0f64, 1.0, -1.0, 2.0];
+        let m = |v: &Vec<f64>| M(ColMajor, 2i32, 2i32, v.clone());
+
+        let s = Tgsyl::tgsyl((&m(&a), &m(&d)), (&m(&b), &m(&e)), (&m(&c), &m(&f)), true).unwrap();
+        assert!(s.dif.unwrap() > 0.0);
+        assert!(!s.perturbed);
+
+        let (ar, lb) = (multiply(2, 2, 2, &a, &s.r), multiply(2, 2, 2, &s.l, &b));
+        let (dr, le) = (multiply(2, 2, 2, &d, &s.r), multiply(2, 2, 2, &s.l, &e));
+        for k in 0..4 {
+            assert!((ar[k] - lb[k] - s.scale * c[k]).abs() < 1e-12);
+            assert!((dr[k] - le[k] - s.scale * f[k]).abs() < 1e-12);
+        }
+
+        let s = Tgsyl::tgsyl((&m(&a), &m(&d)), (&m(&b), &m(&e)), (&m(&c), &m(&f)), false).unwrap();
+        assert!(s.dif.is_none());
+
+        let g = M(ColMajor, 2i32, 1i32, vec![1.0f64, 2.0]);
+        match Tgsyl::tgsyl((&m(&a), &m(&d)), (&m(&b), &m(&e)), (&g, &m(&f)), false) {
+            Err(Error::DimensionMismatch) => {},
+            _ => panic!("mismatched right hand side"),
+        }
+    }
+
+    #[test]
+    fn generalized_common_eigenvalues() {
+        // Both pairs have the eigenvalue 1, so the equation is singular; an
+        // approximate solution is still returned, flagged as from `Trsyl`.
+        let one = M(ColMajor, 1i32, 1i32, vec![1.0f64]);
+        let c = M(ColMajor, 1i32, 1i32, vec![1.0f64]);
+        let f = M(ColMajor, 1i32, 1i32, vec![2.0f64]);
+
+        let s = Tgsyl::tgsyl((&one, &one), (&one, &one), (&c, &f), false).unwrap();
+        assert!(s.perturbed);
+    }
+
+    #[test]
+    fn complex_continuous() {
+        let c = |re, im| Complex64::new(re, im);
+        let a = vec![c(-1.0, 1.0), c(0.0, 0.0), c(1.0, 0.0), c(-2.0, 0.0)];
+        let mut x = M(ColMajor, 2i32, 2i32, vec![c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)]);
+        let scale = Lyapunov::solve_lyapunov(LyapunovForm::Continuous, &M(ColMajor, 2i32, 2i32, a.clone()), &mut x)
+            .unwrap().scale;
+
+        // A X + X Aᴴ + scale I = 0.
+        for i in 0..2 {
+            for j in 0..2 {
+                let r: Complex64 = (0..2).map(|k| a[i + k * 2] * x.3[k + j * 2] + x.3[i + k * 2] * a[j + k * 2].conj())
+                    .fold(c(0.0, 0.0), |s, x| s + x);
+                let q = if i == j { scale } else { 0.0 };
+                assert!((r + q).norm() < 1e-12);
+            }
+        }
+        assert!((x.3[2] - x.3[1].conj()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn singular_lyapunov() {
+        // The eigenvalues 1 and -1 of A are mirrored across the imaginary
+        // axis, so A X + X Aᵀ = -Q has no unique solution.
+        let a = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, -1.0]);
+        let mut x = M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 0.0, 1.0]);
+        let s = Lyapunov::solve_lyapunov(LyapunovForm::Continuous, &a, &mut x).unwrap();
+
+        assert!(s.perturbed);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use libc::c_int;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::{
+    Matrix,
+    TriangularMatrix,
+};
+use scalar::Scalar;
+use util::{ColMem, col_dims, col_trans, col_uplo};
+
+/// Solves `A X = B` for a triangular `A`, overwriting `b` with `X`. A zero
+/// on the diagonal is reported as `DiagonalElementZero` and `b` is left
+/// unchanged.
+pub trait Trtrs: Sized {
+    fn trtrs(a: &TriangularMatrix<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Inverts a triangular matrix in place. A zero on the diagonal is reported
+/// as `DiagonalElementZero`.
+pub trait Trtri: Sized {
+    fn trtri(a: &mut TriangularMatrix<Self>) -> Result<(), Error>;
+}
+
+macro_rules! triangular_impl(($($t: ident), +) => ($(
+    impl Trtrs for $t {
+        fn trtrs(a: &TriangularMatrix<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let (n, cols, lda) = col_dims(a);
+            if n != cols || b.rows() != n {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let nrhs = b.cols();
+            let uplo = col_uplo(a.order(), a.symmetry());
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, trtrs_)(uplo.as_i8().as_mut(), col_trans(a.order()).as_mut(),
+                    a.diagonal().as_i8().as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    a.as_ptr() as *mut _, lda.as_mut(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl Trtri for $t {
+        fn trtri(a: &mut TriangularMatrix<Self>) -> Result<(), Error> {
+            let (n, cols, lda) = col_dims(a);
+            if n != cols {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let uplo = col_uplo(a.order(), a.symmetry());
+
+            unsafe {
+                prefix!($t, trtri_)(uplo.as_i8().as_mut(), a.diagonal().as_i8().as_mut(),
+                    n.as_mut(),
+                    a.as_mut_ptr(), lda.as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+)+));
+
+triangular_impl!(f32, f64, Complex32, Complex64);
+
+#[cfg(test)]
+mod triangular_tests {
+    use matrix::tests::{M, Tr};
+    use norms::Lantr;
+    use refinement::Trrfs;
+    use triangular::{Trtri, Trtrs};
+    use types::{Diagonal, Norm, Symmetry};
+    use types::Order::*;
+
+    #[test]
+    fn solve() {
+        for &order in &[ColMajor, RowMajor] {
+            let data = match order {
+                ColMajor => vec![2.0f64, 0.0, 1.0, 4.0],
+                RowMajor => vec![2.0f64, 1.0, 0.0, 4.0],
+            };
+            let a = Tr(Symmetry::Upper, Diagonal::NonUnit, M(order, 2i32, 2i32, data));
+            let mut b = M(ColMajor, 2i32, 1i32, vec![3.0f64, 4.0]);
+
+            Trtrs::trtrs(&a, &mut b).unwrap();
+            assert_eq!(b.3, vec![1.0, 1.0]);
+
+            let mut x = M(ColMajor, 2i32, 1i32, b.3.clone());
+            let mut b = M(ColMajor, 2i32, 1i32, vec![3.0f64, 4.0]);
+            let r = Trrfs::<f64>::trrfs(&a, &mut b, &mut x).unwrap();
+            assert!(r.berr[0] < 1e-12);
+        }
+    }
+
+    #[test]
+    fn unit_diagonal() {
+        // The 9.0 on the diagonal is never read.
+        let a = Tr(Symmetry::Lower, Diagonal::Unit, M(ColMajor, 2i32, 2i32, vec![9.0f64, 2.0, 0.0, 9.0]));
+        let mut b = M(ColMajor, 2i32, 1i32, vec![1.0f64, 3.0]);
+
+        Trtrs::trtrs(&a, &mut b).unwrap();
+        assert_eq!(b.3, vec![1.0, 1.0]);
+        assert_eq!(Lantr::lantr(Norm::MaxAbs, &a), 2.0f64);
+    }
+
+    #[test]
+    fn singular() {
+        let a = Tr(Symmetry::Upper, Diagonal::NonUnit, M(ColMajor, 2i32, 2i32, vec![1.0f64, 0.0, 1.0, 0.0]));
+        let mut b = M(ColMajor, 2i32, 1i32, vec![1.0f64, 1.0]);
+
+        match Trtrs::trtrs(&a, &mut b) {
+            Err(::Error::DiagonalElementZero(2)) => {},
+            x => panic!("unexpected result: {:?}", x),
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let mut a = Tr(Symmetry::Upper, Diagonal::NonUnit, M(RowMajor, 2i32, 2i32, vec![2.0f64, 1.0, 0.0, 4.0]));
+        Trtri::trtri(&mut a).unwrap();
+        assert_eq!((a.2).3, vec![0.5, -0.125, 0.0, 0.25]);
+    }
+}
@@ -0,0 +1,1500 @@
+// Copyright 2015 Michael Yang. All rights reserved.
+// Use of this source code is governed by a MIT-style
+// license that can be found in the LICENSE file.
+use std::cmp;
+use std::ptr;
+use std::slice;
+use libc::c_int;
+use num::Zero;
+use num::complex::{
+    Complex32,
+    Complex64,
+};
+use error::Error;
+use ll::*;
+use matrix::{Matrix, SymmetricMatrix};
+use refinement::{ExpertSolution, Gtrfs, Refinement};
+use scalar::Scalar;
+use types::{
+    EigenRange,
+    Equilibration,
+    Side,
+    Symmetry,
+    Transpose,
+    TridiagonalAlgorithm,
+    VectorUpdate,
+};
+use util::{ColMem, ColSlice, col_vec, multiply, rfp_trans, square_mem};
+
+/// An `n × n` tridiagonal matrix, kept as its subdiagonal `dl`, diagonal `d`
+/// and superdiagonal `du`.
+#[derive(Clone, Debug)]
+pub struct Tridiagonal<T> {
+    dl: Vec<T>,
+    d: Vec<T>,
+    du: Vec<T>,
+}
+
+impl<T> Tridiagonal<T> {
+    /// `dl` and `du` must hold one element less than `d`.
+    pub fn new(dl: Vec<T>, d: Vec<T>, du: Vec<T>) -> Result<Tridiagonal<T>, Error> {
+        let n = d.len();
+        let off = if n == 0 { 0 } else { n - 1 };
+        if dl.len() != off || du.len() != off {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Tridiagonal {
+            dl: dl,
+            d: d,
+            du: du,
+        })
+    }
+
+    pub fn dim(&self) -> c_int { self.d.len() as c_int }
+
+    pub fn sub_diagonal(&self) -> &[T] { &self.dl[..] }
+    pub fn diagonal(&self) -> &[T] { &self.d[..] }
+    pub fn sup_diagonal(&self) -> &[T] { &self.du[..] }
+
+    /// Pointers to `dl`, `d` and `du`, in the order LAPACK takes them.
+    pub fn as_ptrs(&self) -> (*const T, *const T, *const T) {
+        (self.dl.as_ptr(), self.d.as_ptr(), self.du.as_ptr())
+    }
+
+    pub fn as_mut_ptrs(&mut self) -> (*mut T, *mut T, *mut T) {
+        (self.dl.as_mut_ptr(), self.d.as_mut_ptr(), self.du.as_mut_ptr())
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<T>) {
+        (self.dl, self.d, self.du)
+    }
+}
+
+impl<T: Copy + Zero> Tridiagonal<T> {
+    /// The matrix as a dense column major vector.
+    pub fn to_dense(&self) -> Vec<T> {
+        let n = self.d.len();
+        let mut dense = vec![T::zero(); n * n];
+
+        for j in 0..n {
+            dense[j + j * n] = self.d[j];
+            if j + 1 < n {
+                dense[j + 1 + j * n] = self.dl[j];
+                dense[j + (j + 1) * n] = self.du[j];
+            }
+        }
+
+        dense
+    }
+}
+
+/// `LU` factorization of a tridiagonal matrix from `Gttrf`.
+///
+/// `factors` holds the multipliers of `L` and the first two diagonals of
+/// `U`; `du2` holds the second superdiagonal of `U` introduced by pivoting.
+#[derive(Clone, Debug)]
+pub struct TridiagonalLU<T> {
+    factors: Tridiagonal<T>,
+    du2: Vec<T>,
+    ipiv: Vec<c_int>,
+}
+
+impl<T> TridiagonalLU<T> {
+    pub fn dim(&self) -> c_int { self.factors.dim() }
+    pub fn factors(&self) -> &Tridiagonal<T> { &self.factors }
+    pub fn du2(&self) -> &[T] { &self.du2[..] }
+    pub fn pivots(&self) -> &[c_int] { &self.ipiv[..] }
+
+    /// Refines a solution `x` of `A X = B`, where this is the factorization
+    /// of `a`, and bounds its error.
+    pub fn refine<Real>(&self, a: &Tridiagonal<T>, b: &mut Matrix<T>, x: &mut Matrix<T>)
+        -> Result<Refinement<Real>, Error> where T: Gtrfs<Real> {
+
+        T::gtrfs(a, self, b, x)
+    }
+}
+
+/// A symmetric (Hermitian) tridiagonal matrix with real diagonal `d` and
+/// subdiagonal `e`.
+#[derive(Clone, Debug)]
+pub struct SymmetricTridiagonal<T, Real = T> {
+    d: Vec<Real>,
+    e: Vec<T>,
+}
+
+impl<T, Real> SymmetricTridiagonal<T, Real> {
+    /// `e` must hold one element less than `d`.
+    pub fn new(d: Vec<Real>, e: Vec<T>) -> Result<SymmetricTridiagonal<T, Real>, Error> {
+        let n = d.len();
+        if e.len() != if n == 0 { 0 } else { n - 1 } {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(SymmetricTridiagonal {
+            d: d,
+            e: e,
+        })
+    }
+
+    pub fn dim(&self) -> c_int { self.d.len() as c_int }
+    pub fn diagonal(&self) -> &[Real] { &self.d[..] }
+    pub fn sub_diagonal(&self) -> &[T] { &self.e[..] }
+
+    pub fn as_mut_ptrs(&mut self) -> (*mut Real, *mut T) {
+        (self.d.as_mut_ptr(), self.e.as_mut_ptr())
+    }
+
+    pub fn into_parts(self) -> (Vec<Real>, Vec<T>) {
+        (self.d, self.e)
+    }
+}
+
+/// Reduction of a symmetric (Hermitian) matrix to real symmetric
+/// tridiagonal form, `A = Q T Qᴴ`, by `Sytrd` or `Hetrd`.
+#[derive(Clone, Debug)]
+pub struct Tridiagonalization<T, Real = T> {
+    n: c_int,
+    uplo: Symmetry,
+    factors: Vec<T>,
+    d: Vec<Real>,
+    e: Vec<Real>,
+    tau: Vec<T>,
+}
+
+impl<T, Real> Tridiagonalization<T, Real> {
+    pub fn dim(&self) -> c_int { self.n }
+    pub fn symmetry(&self) -> Symmetry { self.uplo }
+
+    /// The diagonal of `T`.
+    pub fn diagonal(&self) -> &[Real] { &self.d[..] }
+    /// The off-diagonal of `T`.
+    pub fn sub_diagonal(&self) -> &[Real] { &self.e[..] }
+
+    /// The raw output of `sytrd`, column major, holding the reflectors
+    /// forming `Q` in the `symmetry()` triangle.
+    pub fn factors(&self) -> &[T] { &self.factors[..] }
+    pub fn tau(&self) -> &[T] { &self.tau[..] }
+}
+
+impl<T, Real: Clone> Tridiagonalization<T, Real> {
+    /// `T`, for the tridiagonal eigensolvers.
+    pub fn tridiagonal(&self) -> SymmetricTridiagonal<Real> {
+        SymmetricTridiagonal { d: self.d.clone(), e: self.e.clone() }
+    }
+}
+
+/// `LU` factorization of a tridiagonal matrix with partial pivoting.
+pub trait Gttrf: Sized {
+    fn gttrf(a: Tridiagonal<Self>) -> Result<TridiagonalLU<Self>, Error>;
+}
+
+/// Solves `A X = B` with the factorization from `Gttrf`, overwriting `b`.
+pub trait Gttrs: Sized {
+    fn gttrs(lu: &TridiagonalLU<Self>, b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Expert tridiagonal solver. Factors `a`, solves `A X = B` into `x`, and
+/// estimates the condition number and error bounds. `a` and `b` are left
+/// unchanged.
+pub trait Gtsvx<Real>: Sized {
+    fn gtsvx(a: &Tridiagonal<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+        -> Result<ExpertSolution<Real>, Error>;
+}
+
+/// `L D Lᴴ` factorization of a symmetric (Hermitian) positive definite
+/// tridiagonal matrix, in place. `d` is overwritten by `D` and `e` by the
+/// subdiagonal of the unit bidiagonal `L`.
+pub trait Pttrf<Real>: Sized {
+    fn pttrf(a: &mut SymmetricTridiagonal<Self, Real>) -> Result<(), Error>;
+}
+
+/// Solves `A X = B` with the factorization from `Pttrf`, overwriting `b`.
+pub trait Pttrs<Real>: Sized {
+    fn pttrs(factor: &SymmetricTridiagonal<Self, Real>, b: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Eigenvalues, in ascending order, and optionally eigenvectors of a real
+/// symmetric tridiagonal matrix. The eigenvectors are written to the
+/// columns of `z`, which must be `n × n`.
+pub trait Stev: Sized {
+    fn stev(a: SymmetricTridiagonal<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Self>, Error>;
+}
+
+/// Selected eigenvalues, in ascending order, and optionally eigenvectors of
+/// a real symmetric tridiagonal matrix using relatively robust
+/// representations. `z` must have `n` rows and a column for every
+/// eigenvalue that may be found.
+pub trait Stevr: Sized {
+    fn stevr(a: SymmetricTridiagonal<Self>, range: EigenRange<Self>, z: Option<&mut Matrix<Self>>)
+        -> Result<Vec<Self>, Error>;
+}
+
+/// Reduces a real symmetric matrix to tridiagonal form. `a` is not changed.
+pub trait Sytrd: Sized {
+    fn sytrd(a: &SymmetricMatrix<Self>) -> Result<Tridiagonalization<Self>, Error>;
+}
+
+/// Reduces a complex Hermitian matrix to real tridiagonal form. `a` is not
+/// changed.
+pub trait Hetrd<Real>: Sized {
+    fn hetrd(a: &SymmetricMatrix<Self>) -> Result<Tridiagonalization<Self, Real>, Error>;
+}
+
+/// The orthogonal `Q` of a tridiagonal reduction, column major.
+pub trait Orgtr: Sized {
+    fn orgtr(t: &Tridiagonalization<Self>) -> Result<Vec<Self>, Error>;
+}
+
+/// The unitary `Q` of a tridiagonal reduction, column major.
+pub trait Ungtr<Real>: Sized {
+    fn ungtr(t: &Tridiagonalization<Self, Real>) -> Result<Vec<Self>, Error>;
+}
+
+/// Overwrites `c` with `op(Q) C` for `Side::Left` or `C op(Q)` for
+/// `Side::Right`, where `Q` is from a tridiagonal reduction.
+pub trait Ormtr: Sized {
+    fn ormtr(side: Side, trans: Transpose, t: &Tridiagonalization<Self>, c: &mut Matrix<Self>)
+        -> Result<(), Error>;
+}
+
+/// The complex counterpart of `Ormtr`.
+pub trait Unmtr<Real>: Sized {
+    fn unmtr(side: Side, trans: Transpose, t: &Tridiagonalization<Self, Real>, c: &mut Matrix<Self>)
+        -> Result<(), Error>;
+}
+
+/// Eigenvalues from `Stebz`, grouped by the blocks the matrix splits into,
+/// as `Stein` expects. They are ascending within each block, and overall
+/// unless the matrix splits.
+#[derive(Clone, Debug)]
+pub struct Bisection<Real> {
+    pub values: Vec<Real>,
+    /// The block each eigenvalue belongs to, counting from one.
+    pub blocks: Vec<c_int>,
+    /// The last row of each block, counting from one.
+    pub splits: Vec<c_int>,
+}
+
+/// Eigenvalues, in ascending order, of a real symmetric tridiagonal matrix
+/// with diagonal `d` and subdiagonal `e`, by the root-free QR algorithm.
+pub trait Sterf: Sized {
+    fn sterf(d: &[Self], e: &[Self]) -> Result<Vec<Self>, Error>;
+}
+
+/// Eigenvalues, in ascending order, and optionally eigenvectors of a
+/// symmetric tridiagonal matrix by the implicit QL or QR algorithm. `z`
+/// must be `n × n` unless `compz` is `VectorUpdate::None`.
+pub trait Steqr<Real>: Sized {
+    fn steqr(compz: VectorUpdate, d: &[Real], e: &[Real], z: Option<&mut Matrix<Self>>)
+        -> Result<Vec<Real>, Error>;
+}
+
+/// `Steqr` by divide and conquer, which is much faster for eigenvectors of
+/// large matrices.
+pub trait Stedc<Real>: Sized {
+    fn stedc(compz: VectorUpdate, d: &[Real], e: &[Real], z: Option<&mut Matrix<Self>>)
+        -> Result<Vec<Real>, Error>;
+}
+
+/// Selected eigenvalues of a real symmetric tridiagonal matrix by
+/// bisection, to within `abstol`. Zero or less uses `ε ‖T‖₁`.
+pub trait Stebz: Sized {
+    fn stebz(range: EigenRange<Self>, abstol: Self, d: &[Self], e: &[Self]) -> Result<Bisection<Self>, Error>;
+}
+
+/// Eigenvectors for eigenvalues from `Stebz` by inverse iteration, written
+/// to the columns of `z`, which must be `n × m` for `m` eigenvalues.
+pub trait Stein<Real>: Sized {
+    fn stein(d: &[Real], e: &[Real], values: &Bisection<Real>, z: &mut Matrix<Self>) -> Result<(), Error>;
+}
+
+/// Selected eigenvalues, in ascending order, and optionally eigenvectors of
+/// a symmetric tridiagonal matrix using multiple relatively robust
+/// representations. `z` must have `n` rows and a column for every
+/// eigenvalue that may be found.
+pub trait Stemr<Real>: Sized {
+    fn stemr(range: EigenRange<Real>, d: &[Real], e: &[Real], z: Option<&mut Matrix<Self>>)
+        -> Result<Vec<Real>, Error>;
+}
+
+/// All eigenvalues, in ascending order, and optionally eigenvectors of a
+/// symmetric tridiagonal matrix with a choice of algorithm. `z` is as for
+/// `Steqr`.
+pub trait TridiagonalEigen<Real>: Sized {
+    fn tridiagonal_eigen(algorithm: TridiagonalAlgorithm, compz: VectorUpdate,
+        d: &[Real], e: &[Real], z: Option<&mut Matrix<Self>>) -> Result<Vec<Real>, Error>;
+}
+
+fn check_b<T>(n: c_int, b: &Matrix<T>) -> Result<c_int, Error> {
+    if b.rows() != n {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(b.cols())
+}
+
+macro_rules! tridiagonal_impl(($($t: ident: $r: ident, $zero: expr, $work: expr, $extra: ty, $extra_zero: expr), +) => ($(
+    impl Gttrf for $t {
+        fn gttrf(mut a: Tridiagonal<Self>) -> Result<TridiagonalLU<Self>, Error> {
+            let mut info: c_int = 0;
+            let n = a.dim();
+            let mut du2: Vec<$t> = vec![$zero; if n > 2 { n as usize - 2 } else { 0 }];
+            let mut ipiv: Vec<c_int> = vec![0; n as usize];
+
+            unsafe {
+                let (dl, d, du) = a.as_mut_ptrs();
+
+                prefix!($t, gttrf_)(n.as_mut(),
+                    dl, d, du, du2.as_mut_ptr(),
+                    ipiv.as_mut_ptr(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(TridiagonalLU { factors: a, du2: du2, ipiv: ipiv }),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl Gttrs for $t {
+        fn gttrs(lu: &TridiagonalLU<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = lu.dim();
+            let nrhs = try!(check_b(n, b));
+
+            let mut info: c_int = 0;
+            let (dl, d, du) = lu.factors.as_ptrs();
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, gttrs_)((b'N' as i8).as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    lu.du2.as_ptr() as *mut _, lu.ipiv.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Gtsvx<$r> for $t {
+        fn gtsvx(a: &Tridiagonal<Self>, b: &mut Matrix<Self>, x: &mut Matrix<Self>)
+            -> Result<ExpertSolution<$r>, Error> {
+
+            let n = a.dim();
+            let nrhs = try!(check_b(n, b));
+            if x.rows() != n || x.cols() != nrhs {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let mut info: c_int = 0;
+            let mut lu = a.clone();
+            let mut du2: Vec<$t> = vec![$zero; n as usize];
+            let mut ipiv: Vec<c_int> = vec![0; n as usize];
+            let mut rcond: $r = 0.0;
+            let mut ferr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut berr: Vec<$r> = vec![0.0; nrhs as usize];
+            let mut work: Vec<$t> = vec![$zero; $work * n as usize];
+            let mut extra: Vec<$extra> = vec![$extra_zero; n as usize];
+
+            let (dl, d, du) = a.as_ptrs();
+            let mut b_mem = ColMem::new(b.order(), b);
+            let mut x_mem = ColMem::new(x.order(), x);
+
+            unsafe {
+                let (dlf, df, duf) = lu.as_mut_ptrs();
+
+                prefix!($t, gtsvx_)((b'N' as i8).as_mut(), (b'N' as i8).as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    dl as *mut _, d as *mut _, du as *mut _,
+                    dlf, df, duf, du2.as_mut_ptr(),
+                    ipiv.as_mut_ptr(),
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    x_mem.as_mut_ptr(), x_mem.lead().as_mut(),
+                    &mut rcond, ferr.as_mut_ptr(), berr.as_mut_ptr(),
+                    work.as_mut_ptr(), extra.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            let solution = ExpertSolution {
+                rcond: rcond,
+                bounds: Refinement { ferr: ferr, berr: berr },
+                equilibration: Equilibration::None,
+            };
+
+            match info {
+                0 => Ok(solution),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x if x > n => Ok(solution),
+                x => Err(Error::DiagonalElementZero(x as usize)),
+            }
+        }
+    }
+
+    impl Pttrf<$r> for $t {
+        fn pttrf(a: &mut SymmetricTridiagonal<Self, $r>) -> Result<(), Error> {
+            let mut info: c_int = 0;
+            let n = a.dim();
+
+            unsafe {
+                let (d, e) = a.as_mut_ptrs();
+
+                prefix!($t, pttrf_)(n.as_mut(), d, e,
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(()),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NotPositiveDefinite(x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! real_tridiagonal_impl(($($t: ident), +) => ($(
+    impl Pttrs<$t> for $t {
+        fn pttrs(factor: &SymmetricTridiagonal<Self>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = factor.dim();
+            let nrhs = try!(check_b(n, b));
+
+            let mut info: c_int = 0;
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, pttrs_)(n.as_mut(), nrhs.as_mut(),
+                    factor.d.as_ptr() as *mut _, factor.e.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Stev for $t {
+        fn stev(mut a: SymmetricTridiagonal<Self>, z: Option<&mut Matrix<Self>>) -> Result<Vec<Self>, Error> {
+            let mut info: c_int = 0;
+            let n = a.dim();
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() != n {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz) = match z_mem {
+                Some(ref mut z) => (b'V', z.as_mut_ptr(), z.lead()),
+                None => (b'N', ptr::null::<$t>() as *mut _, 1),
+            };
+
+            let mut work: Vec<$t> = vec![0.0; if n > 1 { 2 * n as usize - 2 } else { 1 }];
+
+            unsafe {
+                let (d, e) = a.as_mut_ptrs();
+
+                prefix!($t, stev_)((jobz as i8).as_mut(), n.as_mut(),
+                    d, e,
+                    z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(a.d),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+
+    impl Stevr for $t {
+        fn stevr(mut a: SymmetricTridiagonal<Self>, range: EigenRange<Self>, z: Option<&mut Matrix<Self>>)
+            -> Result<Vec<Self>, Error> {
+
+            let mut info: c_int = 0;
+            let n = a.dim();
+
+            let (mut vl, mut vu, mut il, mut iu) = match range {
+                EigenRange::All => (0.0, 0.0, 1, n),
+                EigenRange::Values(l, u) => (l, u, 1, n),
+                EigenRange::Indices(l, u) => (0.0, 0.0, l, u),
+            };
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() < iu - il + 1 {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz) = match z_mem {
+                Some(ref mut z) => (b'V', z.as_mut_ptr(), z.lead()),
+                None => (b'N', ptr::null::<$t>() as *mut _, 1),
+            };
+
+            let len = if n > 0 { n as usize } else { 1 };
+            let mut m: c_int = 0;
+            let mut w: Vec<$t> = vec![0.0; len];
+            let mut isuppz: Vec<c_int> = vec![0; 2 * len];
+            let mut work: Vec<$t> = vec![0.0; 20 * len];
+            let mut iwork: Vec<c_int> = vec![0; 10 * len];
+
+            unsafe {
+                let (d, e) = a.as_mut_ptrs();
+
+                prefix!($t, stevr_)((jobz as i8).as_mut(), range.as_i8().as_mut(),
+                    n.as_mut(), d, e,
+                    &mut vl, &mut vu, &mut il, &mut iu,
+                    (0.0 as $t).as_mut(), &mut m,
+                    w.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    isuppz.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            w.truncate(m as usize);
+
+            match info {
+                0 => Ok(w),
+                x if x < 0 => Err(Error::IllegalParameter(-x as usize)),
+                x => Err(Error::NoConvergence(x as usize)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_tridiagonal_impl(($($t: ident: $r: ident), +) => ($(
+    impl Pttrs<$r> for $t {
+        fn pttrs(factor: &SymmetricTridiagonal<Self, $r>, b: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = factor.dim();
+            let nrhs = try!(check_b(n, b));
+
+            let mut info: c_int = 0;
+            let mut b_mem = ColMem::new(b.order(), b);
+
+            unsafe {
+                prefix!($t, pttrs_)((b'L' as i8).as_mut(),
+                    n.as_mut(), nrhs.as_mut(),
+                    factor.d.as_ptr() as *mut _, factor.e.as_ptr() as *mut _,
+                    b_mem.as_mut_ptr(), b_mem.lead().as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+)+));
+
+tridiagonal_impl!(
+    f32: f32, 0.0, 3, c_int, 0,
+    f64: f64, 0.0, 3, c_int, 0,
+    Complex32: f32, Complex32::new(0.0, 0.0), 2, f32, 0.0,
+    Complex64: f64, Complex64::new(0.0, 0.0), 2, f64, 0.0
+);
+real_tridiagonal_impl!(f32, f64);
+complex_tridiagonal_impl!(Complex32: f32, Complex64: f64);
+
+fn check_de<T>(d: &[T], e: &[T]) -> Result<c_int, Error> {
+    let n = d.len();
+    if e.len() != if n == 0 { 0 } else { n - 1 } {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(n as c_int)
+}
+
+fn st_error(info: c_int) -> Error {
+    match info {
+        x if x < 0 => Error::IllegalParameter(-x as usize),
+        x => Error::NoConvergence(x as usize),
+    }
+}
+
+fn compz_mem<'a, T>(compz: VectorUpdate, n: c_int, z: Option<&'a mut Matrix<T>>)
+    -> Result<Option<ColMem<'a, T>>, Error> {
+
+    match compz {
+        VectorUpdate::None => Ok(None),
+        _ => match try!(square_mem(n, z)) {
+            Some(z) => Ok(Some(z)),
+            None => Err(Error::IllegalParameter(1)),
+        },
+    }
+}
+
+fn compz_ptr<'a, T>(z: &mut Option<ColMem<'a, T>>) -> (*mut T, c_int) {
+    match *z {
+        Some(ref mut z) => (z.as_mut_ptr(), z.lead()),
+        None => (ptr::null_mut(), 1),
+    }
+}
+
+macro_rules! real_st_impl(($($t: ident), +) => ($(
+    impl Sterf for $t {
+        fn sterf(d: &[Self], e: &[Self]) -> Result<Vec<Self>, Error> {
+            let n = try!(check_de(d, e));
+            let mut d = d.to_vec();
+            let mut e = e.to_vec();
+            let mut info: c_int = 0;
+
+            unsafe {
+                prefix!($t, sterf_)(n.as_mut(), d.as_mut_ptr(), e.as_mut_ptr(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(d),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+
+    impl Stedc<$t> for $t {
+        fn stedc(compz: VectorUpdate, d: &[$t], e: &[$t], z: Option<&mut Matrix<Self>>)
+            -> Result<Vec<$t>, Error> {
+
+            let n = try!(check_de(d, e));
+            let mut z_mem = try!(compz_mem(compz, n, z));
+            let (z_ptr, ldz) = compz_ptr(&mut z_mem);
+            let mut d = d.to_vec();
+            let mut e = e.to_vec();
+
+            let mut info: c_int = 0;
+            let mut len: $t = 0.0;
+            let mut ilen: c_int = 0;
+
+            unsafe {
+                prefix!($t, stedc_)(compz.as_i8().as_mut(), n.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut ilen, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            let mut work: Vec<$t> = vec![0.0; cmp::max(len.as_work(), 1)];
+            let mut iwork: Vec<c_int> = vec![0; cmp::max(ilen, 1) as usize];
+
+            unsafe {
+                prefix!($t, stedc_)(compz.as_i8().as_mut(), n.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(d),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+
+    impl Stebz for $t {
+        fn stebz(range: EigenRange<Self>, abstol: Self, d: &[Self], e: &[Self]) -> Result<Bisection<Self>, Error> {
+            let n = try!(check_de(d, e));
+            let len = n as usize;
+
+            let (mut vl, mut vu, mut il, mut iu) = match range {
+                EigenRange::All => (0.0, 0.0, 1, n),
+                EigenRange::Values(l, u) => (l, u, 1, n),
+                EigenRange::Indices(l, u) => (0.0, 0.0, l, u),
+            };
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut nsplit: c_int = 0;
+            let mut abstol = abstol;
+            let mut w: Vec<$t> = vec![0.0; len];
+            let mut iblock: Vec<c_int> = vec![0; len];
+            let mut isplit: Vec<c_int> = vec![0; len];
+            let mut work: Vec<$t> = vec![0.0; 4 * len + 1];
+            let mut iwork: Vec<c_int> = vec![0; 3 * len + 1];
+
+            unsafe {
+                prefix!($t, stebz_)(range.as_i8().as_mut(), (b'B' as i8).as_mut(),
+                    n.as_mut(), &mut vl, &mut vu, &mut il, &mut iu, &mut abstol,
+                    d.as_ptr() as *mut _, e.as_ptr() as *mut _,
+                    &mut m, &mut nsplit,
+                    w.as_mut_ptr(), iblock.as_mut_ptr(), isplit.as_mut_ptr(),
+                    work.as_mut_ptr(), iwork.as_mut_ptr(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            w.truncate(m as usize);
+            iblock.truncate(m as usize);
+            isplit.truncate(nsplit as usize);
+
+            match info {
+                0 => Ok(Bisection { values: w, blocks: iblock, splits: isplit }),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+)+));
+
+macro_rules! complex_st_impl(($($t: ident: $r: ident), +) => ($(
+    impl Stedc<$r> for $t {
+        fn stedc(compz: VectorUpdate, d: &[$r], e: &[$r], z: Option<&mut Matrix<Self>>)
+            -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_de(d, e));
+            let mut z_mem = try!(compz_mem(compz, n, z));
+            let (z_ptr, ldz) = compz_ptr(&mut z_mem);
+            let mut d = d.to_vec();
+            let mut e = e.to_vec();
+
+            let mut info: c_int = 0;
+            let mut len = $t::new(0.0, 0.0);
+            let mut rlen: $r = 0.0;
+            let mut ilen: c_int = 0;
+
+            unsafe {
+                prefix!($t, stedc_)(compz.as_i8().as_mut(), n.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    &mut len, (-1 as c_int).as_mut(),
+                    &mut rlen, (-1 as c_int).as_mut(),
+                    &mut ilen, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            let mut work: Vec<$t> = vec![$t::new(0.0, 0.0); cmp::max(len.as_work(), 1)];
+            let mut rwork: Vec<$r> = vec![0.0; cmp::max(rlen.as_work(), 1)];
+            let mut iwork: Vec<c_int> = vec![0; cmp::max(ilen, 1) as usize];
+
+            unsafe {
+                prefix!($t, stedc_)(compz.as_i8().as_mut(), n.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    rwork.as_mut_ptr(), (rwork.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(d),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+)+));
+
+// `steqr` needs `2 n - 2` work, `stein` `5 n` work and `n` iwork, and
+// `stemr` with eigenvectors `18 n` work and `10 n` iwork.
+macro_rules! st_impl(($($t: ident: $r: ident, $zero: expr), +) => ($(
+    impl Steqr<$r> for $t {
+        fn steqr(compz: VectorUpdate, d: &[$r], e: &[$r], z: Option<&mut Matrix<Self>>)
+            -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_de(d, e));
+            let mut z_mem = try!(compz_mem(compz, n, z));
+            let (z_ptr, ldz) = compz_ptr(&mut z_mem);
+            let mut d = d.to_vec();
+            let mut e = e.to_vec();
+
+            let mut info: c_int = 0;
+            let mut work: Vec<$r> = vec![0.0; cmp::max(2 * n - 2, 1) as usize];
+
+            unsafe {
+                prefix!($t, steqr_)(compz.as_i8().as_mut(), n.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), z_ptr, ldz.as_mut(),
+                    work.as_mut_ptr(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(d),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+
+    impl Stein<$r> for $t {
+        fn stein(d: &[$r], e: &[$r], values: &Bisection<$r>, z: &mut Matrix<Self>) -> Result<(), Error> {
+            let n = try!(check_de(d, e));
+            let m = values.values.len() as c_int;
+            if values.blocks.len() != m as usize || z.rows() != n || z.cols() != m {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let len = n as usize;
+            let mut z_mem = ColMem::new(z.order(), z);
+            let ldz = cmp::max(z_mem.lead(), 1);
+
+            // `isplit` is read for every block up to the last one used.
+            let mut isplit = values.splits.clone();
+            isplit.resize(cmp::max(len, 1), n);
+
+            let mut info: c_int = 0;
+            let mut work: Vec<$r> = vec![0.0; 5 * len];
+            let mut iwork: Vec<c_int> = vec![0; len];
+            let mut ifail: Vec<c_int> = vec![0; m as usize];
+
+            unsafe {
+                prefix!($t, stein_)(n.as_mut(), d.as_ptr() as *mut _, e.as_ptr() as *mut _,
+                    m.as_mut(), values.values.as_ptr() as *mut _,
+                    values.blocks.as_ptr() as *mut _, isplit.as_mut_ptr(),
+                    z_mem.as_mut_ptr(), ldz.as_mut(),
+                    work.as_mut_ptr(), iwork.as_mut_ptr(), ifail.as_mut_ptr(),
+                    &mut info as *mut c_int);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+
+    impl Stemr<$r> for $t {
+        fn stemr(range: EigenRange<$r>, d: &[$r], e: &[$r], z: Option<&mut Matrix<Self>>)
+            -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_de(d, e));
+            let len = cmp::max(n, 1) as usize;
+
+            let (mut vl, mut vu, mut il, mut iu) = match range {
+                EigenRange::All => (0.0, 0.0, 1, n),
+                EigenRange::Values(l, u) => (l, u, 1, n),
+                EigenRange::Indices(l, u) => (0.0, 0.0, l, u),
+            };
+
+            let mut z_mem = match z {
+                Some(z) => {
+                    if z.rows() != n || z.cols() < iu - il + 1 {
+                        return Err(Error::DimensionMismatch);
+                    }
+                    Some(ColMem::new(z.order(), z))
+                },
+                None => None,
+            };
+
+            let (jobz, z_ptr, ldz, mut nzc) = match z_mem {
+                Some(ref mut z) => (b'V', z.as_mut_ptr(), cmp::max(z.lead(), 1), iu - il + 1),
+                None => (b'N', ptr::null_mut(), 1, 0),
+            };
+
+            // `e` is used as workspace and needs `n` elements.
+            let mut d = d.to_vec();
+            let mut e = e.to_vec();
+            e.push(0.0);
+
+            let mut info: c_int = 0;
+            let mut m: c_int = 0;
+            let mut tryrac: c_int = 1;
+            let mut w: Vec<$r> = vec![0.0; len];
+            let mut isuppz: Vec<c_int> = vec![0; 2 * len];
+            let mut work: Vec<$r> = vec![0.0; 18 * len];
+            let mut iwork: Vec<c_int> = vec![0; 10 * len];
+
+            unsafe {
+                prefix!($t, stemr_)((jobz as i8).as_mut(), range.as_i8().as_mut(),
+                    n.as_mut(), d.as_mut_ptr(), e.as_mut_ptr(),
+                    &mut vl, &mut vu, &mut il, &mut iu,
+                    &mut m, w.as_mut_ptr(),
+                    z_ptr, ldz.as_mut(), &mut nzc,
+                    isuppz.as_mut_ptr(), &mut tryrac,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    iwork.as_mut_ptr(), (iwork.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1);
+            }
+
+            w.truncate(m as usize);
+
+            match info {
+                0 => Ok(w),
+                x => Err(st_error(x)),
+            }
+        }
+    }
+
+    impl TridiagonalEigen<$r> for $t {
+        fn tridiagonal_eigen(algorithm: TridiagonalAlgorithm, compz: VectorUpdate,
+            d: &[$r], e: &[$r], z: Option<&mut Matrix<Self>>) -> Result<Vec<$r>, Error> {
+
+            let n = try!(check_de(d, e));
+            let len = n as usize;
+
+            match (algorithm, compz) {
+                (TridiagonalAlgorithm::Qr, VectorUpdate::None) => return Sterf::sterf(d, e),
+                (TridiagonalAlgorithm::Qr, _) => return Steqr::steqr(compz, d, e, z),
+                (TridiagonalAlgorithm::DivideAndConquer, _) => return Stedc::stedc(compz, d, e, z),
+                _ => {},
+            }
+
+            let z_mem = try!(compz_mem(compz, n, z));
+
+            // Eigenvectors of the tridiagonal matrix itself, ascending.
+            let mut v: Vec<$t> = vec![$zero; if compz == VectorUpdate::None { 0 } else { len * len }];
+            let values = {
+                let mut v_mat = ColSlice(n, n, &mut v[..]);
+                let v_mat = match compz {
+                    VectorUpdate::None => None,
+                    _ => Some(&mut v_mat as &mut Matrix<$t>),
+                };
+
+                match algorithm {
+                    TridiagonalAlgorithm::RelativelyRobust => try!(Stemr::stemr(EigenRange::All, d, e, v_mat)),
+                    _ => {
+                        let found = try!(Stebz::stebz(EigenRange::All, 0.0, d, e));
+                        if let Some(v_mat) = v_mat {
+                            try!(Stein::stein(d, e, &found, v_mat));
+                        }
+                        found.values
+                    },
+                }
+            };
+
+            // Blocks of a split matrix are not sorted together by `stebz`.
+            let mut order: Vec<usize> = (0..values.len()).collect();
+            order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap_or(cmp::Ordering::Equal));
+            let sorted = order.iter().map(|&k| values[k]).collect();
+            if compz == VectorUpdate::None {
+                return Ok(sorted);
+            }
+
+            let mut sorted_v = Vec::with_capacity(len * len);
+            for &k in &order {
+                sorted_v.extend_from_slice(&v[k * len..(k + 1) * len]);
+            }
+
+            let mut z_mem = z_mem.unwrap();
+            let z = unsafe { slice::from_raw_parts_mut(z_mem.as_mut_ptr(), len * len) };
+            match compz {
+                VectorUpdate::Update => {
+                    let qv = multiply(len, len, len, z, &sorted_v);
+                    z.copy_from_slice(&qv);
+                },
+                _ => z.copy_from_slice(&sorted_v),
+            }
+
+            Ok(sorted)
+        }
+    }
+)+));
+
+real_st_impl!(f32, f64);
+complex_st_impl!(Complex32: f32, Complex64: f64);
+st_impl!(
+    f32: f32, 0.0,
+    f64: f64, 0.0,
+    Complex32: f32, Complex32::new(0.0, 0.0),
+    Complex64: f64, Complex64::new(0.0, 0.0)
+);
+
+macro_rules! tridiagonalization_impl(($($t: ident: $r: ident, $zero: expr, $conj: expr,
+    $trd: ident, $trd_fn: ident, $trd_ll: ident, [$($trd_real: ident)*],
+    $gtr: ident, $gtr_fn: ident, $gtr_ll: ident, [$($gtr_real: ident)*],
+    $mtr: ident, $mtr_fn: ident, $mtr_ll: ident, [$($mtr_real: ident)*]), +) => ($(
+    impl $trd$(<$trd_real>)* for $t {
+        fn $trd_fn(a: &SymmetricMatrix<Self>) -> Result<Tridiagonalization<Self, $r>, Error> {
+            let n = a.rows();
+            if n != a.cols() {
+                return Err(Error::DimensionMismatch);
+            }
+
+            let uplo = a.symmetry();
+            let len = n as usize;
+            let lda = cmp::max(n, 1);
+            let mut factors = col_vec(a);
+            let mut d: Vec<$r> = vec![0.0; len];
+            let mut e: Vec<$r> = vec![0.0; cmp::max(n - 1, 0) as usize];
+            let mut tau: Vec<$t> = vec![$zero; cmp::max(n - 1, 0) as usize];
+
+            let mut info: c_int = 0;
+            let mut work_len = $zero;
+
+            unsafe {
+                prefix!($t, $trd_ll)(uplo.as_i8().as_mut(), n.as_mut(),
+                    factors.as_mut_ptr(), lda.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), tau.as_mut_ptr(),
+                    &mut work_len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            let mut work: Vec<$t> = vec![$zero; cmp::max(work_len.as_work(), 1)];
+
+            unsafe {
+                prefix!($t, $trd_ll)(uplo.as_i8().as_mut(), n.as_mut(),
+                    factors.as_mut_ptr(), lda.as_mut(),
+                    d.as_mut_ptr(), e.as_mut_ptr(), tau.as_mut_ptr(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(Tridiagonalization {
+                    n: n,
+                    uplo: uplo,
+                    factors: factors,
+                    d: d,
+                    e: e,
+                    tau: tau,
+                }),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl $gtr$(<$gtr_real>)* for $t {
+        fn $gtr_fn(t: &Tridiagonalization<Self, $r>) -> Result<Vec<Self>, Error> {
+            let n = t.n;
+            let lda = cmp::max(n, 1);
+            let mut q = t.factors.clone();
+
+            let mut info: c_int = 0;
+            let mut work_len = $zero;
+
+            unsafe {
+                prefix!($t, $gtr_ll)(t.uplo.as_i8().as_mut(), n.as_mut(),
+                    q.as_mut_ptr(), lda.as_mut(), t.tau.as_ptr() as *mut _,
+                    &mut work_len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            let mut work: Vec<$t> = vec![$zero; cmp::max(work_len.as_work(), 1)];
+
+            unsafe {
+                prefix!($t, $gtr_ll)(t.uplo.as_i8().as_mut(), n.as_mut(),
+                    q.as_mut_ptr(), lda.as_mut(), t.tau.as_ptr() as *mut _,
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1);
+            }
+
+            match info {
+                0 => Ok(q),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl $mtr$(<$mtr_real>)* for $t {
+        fn $mtr_fn(side: Side, trans: Transpose, t: &Tridiagonalization<Self, $r>, c: &mut Matrix<Self>)
+            -> Result<(), Error> {
+
+            let n = t.n;
+            let m = c.rows();
+            let cols = c.cols();
+            match side {
+                Side::Left if m == n => {},
+                Side::Right if cols == n => {},
+                Side::Both => return Err(Error::IllegalParameter(1)),
+                _ => return Err(Error::DimensionMismatch),
+            }
+
+            let lda = cmp::max(n, 1);
+            let trans = rfp_trans(trans, $conj);
+            let mut c_mem = ColMem::new(c.order(), c);
+            let ldc = cmp::max(c_mem.lead(), 1);
+
+            let mut info: c_int = 0;
+            let mut work_len = $zero;
+
+            unsafe {
+                prefix!($t, $mtr_ll)(side.as_i8().as_mut(), t.uplo.as_i8().as_mut(), trans.as_mut(),
+                    m.as_mut(), cols.as_mut(),
+                    t.factors.as_ptr() as *mut _, lda.as_mut(), t.tau.as_ptr() as *mut _,
+                    c_mem.as_mut_ptr(), ldc.as_mut(),
+                    &mut work_len, (-1 as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            let mut work: Vec<$t> = vec![$zero; cmp::max(work_len.as_work(), 1)];
+
+            unsafe {
+                prefix!($t, $mtr_ll)(side.as_i8().as_mut(), t.uplo.as_i8().as_mut(), trans.as_mut(),
+                    m.as_mut(), cols.as_mut(),
+                    t.factors.as_ptr() as *mut _, lda.as_mut(), t.tau.as_ptr() as *mut _,
+                    c_mem.as_mut_ptr(), ldc.as_mut(),
+                    work.as_mut_ptr(), (work.len() as c_int).as_mut(),
+                    &mut info as *mut c_int, 1, 1, 1);
+            }
+
+            match info {
+                0 => Ok(()),
+                x => Err(Error::IllegalParameter(-x as usize)),
+            }
+        }
+    }
+
+    impl Tridiagonalization<$t, $r> {
+        /// Turns eigenvectors of `T` in the columns of `v`, such as from
+        /// `Stedc`, into eigenvectors of `A` by multiplying them by `Q`.
+        pub fn back_transform(&self, v: &mut Matrix<$t>) -> Result<(), Error> {
+            $mtr::$mtr_fn(Side::Left, Transpose::None, self, v)
+        }
+    }
+)+));
+
+tridiagonalization_impl!(
+    f32: f32, 0.0, b'T', Sytrd, sytrd, sytrd_, [], Orgtr, orgtr, orgtr_, [], Ormtr, ormtr, ormtr_, [],
+    f64: f64, 0.0, b'T', Sytrd, sytrd, sytrd_, [], Orgtr, orgtr, orgtr_, [], Ormtr, ormtr, ormtr_, [],
+    Complex32: f32, Complex32::new(0.0, 0.0), b'C',
+        Hetrd, hetrd, hetrd_, [f32], Ungtr, ungtr, ungtr_, [f32], Unmtr, unmtr, unmtr_, [f32],
+    Complex64: f64, Complex64::new(0.0, 0.0), b'C',
+        Hetrd, hetrd, hetrd_, [f64], Ungtr, ungtr, ungtr_, [f64], Unmtr, unmtr, unmtr_, [f64]
+);
+
+#[cfg(test)]
+mod tridiagonal_tests {
+    use tridiagonal::{
+        Gtsvx,
+        Gttrf,
+        Gttrs,
+        Pttrf,
+        Pttrs,
+        Stev,
+        Stevr,
+        SymmetricTridiagonal,
+        Tridiagonal,
+    };
+    use matrix::tests::M;
+    use types::EigenRange;
+    use types::Order::*;
+
+    fn tridiagonal() -> Tridiagonal<f64> {
+        Tridiagonal::new(vec![-1.0, -1.0], vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]).unwrap()
+    }
+
+    fn symmetric() -> SymmetricTridiagonal<f64> {
+        SymmetricTridiagonal::new(vec![2.0, 2.0, 2.0], vec![-1.0, -1.0]).unwrap()
+    }
+
+    #[test]
+    fn dimensions() {
+        assert!(Tridiagonal::new(vec![1.0f64], vec![1.0, 2.0], vec![]).is_err());
+        assert_eq!(tridiagonal().to_dense(), vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    }
+
+    #[test]
+    fn factor_solve() {
+        let lu = Gttrf::gttrf(tridiagonal()).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+
+        Gttrs::gttrs(&lu, &mut b).unwrap();
+
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn refine() {
+        let lu = Gttrf::gttrf(tridiagonal()).unwrap();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+        let mut x = M(ColMajor, 3i32, 1i32, vec![1.0f64 + 1e-6, 1.0, 1.0]);
+
+        let r = lu.refine::<f64>(&tridiagonal(), &mut b, &mut x).unwrap();
+
+        assert_eq!(r.ferr.len(), 1);
+        for x in x.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn expert() {
+        let a = tridiagonal();
+        let mut b = M(RowMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+        let mut x = M(RowMajor, 3i32, 1i32, vec![0.0f64; 3]);
+
+        let s = Gtsvx::<f64>::gtsvx(&a, &mut b, &mut x).unwrap();
+
+        assert!(s.rcond > 0.0 && s.rcond <= 1.0);
+        for x in x.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn positive_definite() {
+        let mut a = symmetric();
+        let mut b = M(ColMajor, 3i32, 1i32, vec![1.0f64, 0.0, 1.0]);
+
+        Pttrf::pttrf(&mut a).unwrap();
+        Pttrs::pttrs(&a, &mut b).unwrap();
+
+        for x in b.3 {
+            assert!((x - 1.0).abs() < 1e-12);
+        }
+
+        let mut indefinite = SymmetricTridiagonal::new(vec![1.0f64, 1.0], vec![2.0]).unwrap();
+        assert!(Pttrf::pttrf(&mut indefinite).is_err());
+    }
+
+    #[test]
+    fn eigenvalues() {
+        let s = 2.0f64.sqrt();
+        let expected = vec![2.0 - s, 2.0, 2.0 + s];
+
+        let mut z = M(ColMajor, 3i32, 3i32, vec![0.0f64; 9]);
+        let w = Stev::stev(symmetric(), Some(&mut z)).unwrap();
+        for (w, e) in w.iter().zip(expected.iter()) {
+            assert!((w - e).abs() < 1e-12);
+        }
+        assert!((z.3[1].abs() - 1.0 / s).abs() < 1e-12);
+
+        let w = Stevr::stevr(symmetric(), EigenRange::Indices(2, 3), None).unwrap();
+        assert_eq!(w.len(), 2);
+        assert!((w[0] - 2.0).abs() < 1e-12);
+
+        let w = Stevr::stevr(symmetric(), EigenRange::Values(1.0, 3.0), None).unwrap();
+        assert_eq!(w.len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod tridiagonal_eigen_tests {
+    use std::f64::consts::SQRT_2;
+    use num::complex::Complex64;
+    use matrix::tests::M;
+    use tridiagonal::{Stebz, Stein, Stemr, Steqr, Sterf, TridiagonalEigen};
+    use types::{EigenRange, TridiagonalAlgorithm, VectorUpdate};
+    use types::Order::*;
+
+    const D: [f64; 3] = [2.0, 2.0, 2.0];
+    const E: [f64; 2] = [-1.0, -1.0];
+
+    fn expected() -> [f64; 3] {
+        [2.0 - SQRT_2, 2.0, 2.0 + SQRT_2]
+    }
+
+    // The dense column major matrix with diagonal `D` and off-diagonals `E`.
+    fn dense() -> Vec<f64> {
+        vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]
+    }
+
+    fn check_vectors(a: &[f64], values: &[f64], z: &[f64]) {
+        for (j, &l) in values.iter().enumerate() {
+            for i in 0..3 {
+                let az: f64 = (0..3).map(|k| a[i + k * 3] * z[k + j * 3]).sum();
+                assert!((az - l * z[i + j * 3]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn values() {
+        let w = Sterf::sterf(&D, &E).unwrap();
+        for (x, y) in w.iter().zip(expected().iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        assert!(Sterf::sterf(&D, &E[..1]).is_err());
+    }
+
+    #[test]
+    fn vectors() {
+        let mut z = M(ColMajor, 3i32, 3i32, vec![0.0f64; 9]);
+        let w = Steqr::steqr(VectorUpdate::Initialize, &D, &E, Some(&mut z)).unwrap();
+        check_vectors(&dense(), &w, &z.3);
+
+        assert!(<f64 as Steqr<f64>>::steqr(VectorUpdate::Initialize, &D, &E, None).is_err());
+    }
+
+    #[test]
+    fn algorithms() {
+        // Q swaps the first two rows, so Q T Qᵀ has eigenvectors Q V.
+        let t = dense();
+        let a: Vec<f64> = (0..9).map(|k| t[[1, 0, 2][k % 3] + [1, 0, 2][k / 3] * 3]).collect();
+
+        for &algorithm in &[TridiagonalAlgorithm::Qr, TridiagonalAlgorithm::DivideAndConquer,
+                TridiagonalAlgorithm::RelativelyRobust, TridiagonalAlgorithm::Bisection] {
+            let w = <f64 as TridiagonalEigen<f64>>::tridiagonal_eigen(algorithm, VectorUpdate::None,
+                &D, &E, None).unwrap();
+            for (x, y) in w.iter().zip(expected().iter()) {
+                assert!((x - y).abs() < 1e-12);
+            }
+
+            let mut z = M(RowMajor, 3i32, 3i32, vec![0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+            let w = TridiagonalEigen::tridiagonal_eigen(algorithm, VectorUpdate::Update, &D, &E,
+                Some(&mut z)).unwrap();
+            // Row major, so read `z` back transposed.
+            let z: Vec<f64> = (0..9).map(|k| z.3[k / 3 + k % 3 * 3]).collect();
+            check_vectors(&a, &w, &z);
+        }
+    }
+
+    #[test]
+    fn bisection() {
+        let found = Stebz::stebz(EigenRange::Indices(1, 2), 0.0, &D, &E).unwrap();
+        assert_eq!(found.values.len(), 2);
+        assert!((found.values[1] - 2.0).abs() < 1e-12);
+
+        let mut z = M(ColMajor, 3i32, 2i32, vec![0.0f64; 6]);
+        Stein::stein(&D, &E, &found, &mut z).unwrap();
+        check_vectors(&dense(), &found.values, &z.3);
+    }
+
+    #[test]
+    fn relatively_robust() {
+        let w = <f64 as Stemr<f64>>::stemr(EigenRange::Values(1.0, 3.0), &D, &E, None).unwrap();
+        assert_eq!(w.len(), 1);
+        assert!((w[0] - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn complex_vectors() {
+        let mut z = M(ColMajor, 3i32, 3i32, vec![Complex64::new(0.0, 0.0); 9]);
+        let w = Steqr::steqr(VectorUpdate::Initialize, &D, &E, Some(&mut z)).unwrap();
+
+        let a = dense();
+        for j in 0..3 {
+            for i in 0..3 {
+                let az = (0..3).fold(Complex64::new(0.0, 0.0), |s, k| s + z.3[k + j * 3] * a[i + k * 3]);
+                assert!((az - z.3[i + j * 3] * w[j]).norm() < 1e-12);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tridiagonalization_tests {
+    use num::complex::Complex64;
+    use matrix::tests::{M, S};
+    use tridiagonal::{Hetrd, Orgtr, Ormtr, Steqr, Sytrd, Tridiagonalization, Ungtr};
+    use types::{Side, Symmetry, Transpose, VectorUpdate};
+    use types::Order::*;
+
+    fn symmetric() -> Vec<f64> {
+        vec![
+            4.0, 1.0, -2.0,
+            1.0, 2.0, 0.0,
+            -2.0, 0.0, 3.0,
+        ]
+    }
+
+    // Q T Qᵀ, column major.
+    fn rebuild(t: &Tridiagonalization<f64>, q: &[f64]) -> Vec<f64> {
+        let (d, e) = (t.diagonal(), t.sub_diagonal());
+        let mut tq = vec![0.0; 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut x = d[i] * q[j + i * 3];
+                if i > 0 { x += e[i - 1] * q[j + (i - 1) * 3]; }
+                if i < 2 { x += e[i] * q[j + (i + 1) * 3]; }
+                tq[i + j * 3] = x;
+            }
+        }
+        let mut a = vec![0.0; 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                a[i + j * 3] = (0..3).map(|k| q[i + k * 3] * tq[k + j * 3]).sum();
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn reduce() {
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            for &order in &[ColMajor, RowMajor] {
+                let a = S(uplo, M(order, 3i32, 3i32, symmetric()));
+                let t = Sytrd::sytrd(&a).unwrap();
+                assert_eq!(t.dim(), 3);
+
+                let q = Orgtr::orgtr(&t).unwrap();
+                let rebuilt = rebuild(&t, &q);
+                for (x, y) in rebuilt.iter().zip(symmetric().iter()) {
+                    assert!((x - y).abs() < 1e-12);
+                }
+
+                // Qᵀ Q = I through ormtr.
+                let mut c = M(order, 3i32, 3i32, match order {
+                    ColMajor => q.clone(),
+                    RowMajor => (0..9).map(|k| q[k / 3 + (k % 3) * 3]).collect(),
+                });
+                Ormtr::ormtr(Side::Left, Transpose::Conjugate, &t, &mut c).unwrap();
+                for (k, x) in c.3.iter().enumerate() {
+                    let i = if k / 3 == k % 3 { 1.0 } else { 0.0 };
+                    assert!((x - i).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn back_transform() {
+        let a = S(Symmetry::Lower, M(ColMajor, 3i32, 3i32, symmetric()));
+        let t = Sytrd::sytrd(&a).unwrap();
+
+        let mut z = M(ColMajor, 3i32, 3i32, vec![0.0; 9]);
+        let w = <f64 as Steqr<f64>>::steqr(VectorUpdate::Initialize,
+            t.diagonal(), t.sub_diagonal(), Some(&mut z)).unwrap();
+        t.back_transform(&mut z).unwrap();
+
+        let a = symmetric();
+        for (j, &l) in w.iter().enumerate() {
+            for i in 0..3 {
+                let av: f64 = (0..3).map(|k| a[i + k * 3] * z.3[k + j * 3]).sum();
+                assert!((av - l * z.3[i + j * 3]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        let a = S(Symmetry::Upper, M(ColMajor, 3i32, 3i32, symmetric()));
+        let t = Sytrd::sytrd(&a).unwrap();
+        let mut c = M(ColMajor, 2i32, 3i32, vec![0.0; 6]);
+
+        assert!(Ormtr::ormtr(Side::Left, Transpose::None, &t, &mut c).is_err());
+        assert!(Ormtr::ormtr(Side::Right, Transpose::None, &t, &mut c).is_ok());
+        assert!(Ormtr::ormtr(Side::Both, Transpose::None, &t, &mut c).is_err());
+    }
+
+    #[test]
+    fn hermitian() {
+        let c = |re, im| Complex64::new(re, im);
+        let h = vec![
+            c(2.0, 0.0), c(1.0, 1.0), c(0.0, -1.0),
+            c(1.0, -1.0), c(3.0, 0.0), c(2.0, 0.0),
+            c(0.0, 1.0), c(2.0, 0.0), c(1.0, 0.0),
+        ];
+
+        for &uplo in &[Symmetry::Upper, Symmetry::Lower] {
+            let a = S(uplo, M(ColMajor, 3i32, 3i32, h.clone()));
+            let t = Hetrd::hetrd(&a).unwrap();
+            let q = Ungtr::ungtr(&t).unwrap();
+            let (d, e) = (t.diagonal(), t.sub_diagonal());
+
+            for i in 0..3 {
+                for j in 0..3 {
+                    // (Q T Qᴴ)ᵢⱼ = Σₖₗ qᵢₖ tₖₗ conj(qⱼₗ)
+                    let mut x = c(0.0, 0.0);
+                    for k in 0..3 {
+                        for l in 0..3 {
+                            let tkl = if k == l { d[k] }
+                                else if k == l + 1 { e[l] }
+                                else if l == k + 1 { e[k] }
+                                else { 0.0 };
+                            x = x + q[i + k * 3] * q[j + l * 3].conj() * tkl;
+                        }
+                    }
+                    assert!((x - h[i + j * 3]).norm() < 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright 2014 Michael Yang. All rights reserved.
 // Use of this source code is governed by a MIT-style
 // license that can be found in the LICENSE file.
+use libc::c_int;
 
 #[cfg(feature = "default")]
 pub use rblas::attribute::Order;
@@ -26,6 +27,7 @@ impl Compute {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Symmetry {
     Upper,
     Lower,
@@ -40,6 +42,7 @@ impl Symmetry {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Transpose {
     None,
     Conjugate,
@@ -53,3 +56,264 @@ impl Transpose {
         }
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diagonal {
+    NonUnit,
+    Unit,
+}
+
+impl Diagonal {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Diagonal::NonUnit => 78,
+            Diagonal::Unit => 85,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Norm {
+    One,
+    Infinity,
+    Frobenius,
+    MaxAbs,
+}
+
+impl Norm {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Norm::One => 79,
+            Norm::Infinity => 73,
+            Norm::Frobenius => 70,
+            Norm::MaxAbs => 77,
+        }
+    }
+}
+
+/// How a matrix has been scaled. `Row` and `Column` are relative to the
+/// matrix as the caller stores it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Equilibration {
+    None,
+    Row,
+    Column,
+    Both,
+}
+
+impl Equilibration {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Equilibration::None => 78,
+            Equilibration::Row => 82,
+            Equilibration::Column => 67,
+            Equilibration::Both => 66,
+        }
+    }
+
+    /// Reads an `equed` flag. Symmetric routines report `Y`, which scales
+    /// both sides.
+    pub fn from_i8(equed: i8) -> Equilibration {
+        match equed {
+            82 => Equilibration::Row,
+            67 => Equilibration::Column,
+            66 | 89 => Equilibration::Both,
+            _ => Equilibration::None,
+        }
+    }
+}
+
+/// Which eigenvalues to compute.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EigenRange<T> {
+    All,
+    /// Eigenvalues in the half-open interval `(lower, upper]`.
+    Values(T, T),
+    /// The `il`-th through `iu`-th smallest eigenvalues, counting from one.
+    Indices(i32, i32),
+}
+
+impl<T> EigenRange<T> {
+    pub fn as_i8(&self) -> i8 {
+        match self {
+            &EigenRange::All => 65,
+            &EigenRange::Values(..) => 86,
+            &EigenRange::Indices(..) => 73,
+        }
+    }
+}
+
+/// The form of a generalized symmetric-definite eigenproblem, with `B`
+/// positive definite.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GeneralizedForm {
+    /// `A x = λ B x`
+    Ax,
+    /// `A B x = λ x`
+    ABx,
+    /// `B A x = λ x`
+    BAx,
+}
+
+impl GeneralizedForm {
+    pub fn as_c_int(self) -> c_int {
+        match self {
+            GeneralizedForm::Ax => 1,
+            GeneralizedForm::ABx => 2,
+            GeneralizedForm::BAx => 3,
+        }
+    }
+}
+
+/// How a matrix is balanced before computing its eigenvalues.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Balance {
+    None,
+    /// Permute to isolate eigenvalues where possible.
+    Permute,
+    /// Scale rows and columns to make their norms closer.
+    Scale,
+    Both,
+}
+
+impl Balance {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Balance::None => 78,
+            Balance::Permute => 80,
+            Balance::Scale => 83,
+            Balance::Both => 66,
+        }
+    }
+}
+
+/// Which reciprocal condition numbers an expert eigenvalue driver computes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sense {
+    None,
+    Eigenvalues,
+    Eigenvectors,
+    Both,
+}
+
+impl Sense {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Sense::None => 78,
+            Sense::Eigenvalues => 69,
+            Sense::Eigenvectors => 86,
+            Sense::Both => 66,
+        }
+    }
+
+    /// How many eigenvalue and eigenvector condition numbers, in that order,
+    /// this requests for `n` eigenvalues.
+    pub fn condition_lens(self, n: usize) -> (usize, usize) {
+        match self {
+            Sense::None => (0, 0),
+            Sense::Eigenvalues => (n, 0),
+            Sense::Eigenvectors => (0, n),
+            Sense::Both => (n, n),
+        }
+    }
+}
+
+/// The sign `±` in a Sylvester equation `op(A) X ± X op(B) = C`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Plus,
+    Minus,
+}
+
+impl Sign {
+    pub fn as_c_int(self) -> c_int {
+        match self {
+            Sign::Plus => 1,
+            Sign::Minus => -1,
+        }
+    }
+}
+
+/// The form of a Lyapunov equation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LyapunovForm {
+    /// `A X + X Aᴴ = -Q`
+    Continuous,
+    /// `A X Aᴴ - X = -Q`
+    Discrete,
+}
+
+/// Which eigenvectors of a matrix are computed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+    Both,
+}
+
+impl Side {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            Side::Left => 76,
+            Side::Right => 82,
+            Side::Both => 66,
+        }
+    }
+}
+
+/// What the Hessenberg QR iteration computes besides the eigenvalues.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SchurJob {
+    /// Only the eigenvalues.
+    Eigenvalues,
+    /// The Schur form `T` too.
+    Schur,
+}
+
+impl SchurJob {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            SchurJob::Eigenvalues => 69,
+            SchurJob::Schur => 83,
+        }
+    }
+}
+
+/// How a routine working on a reduced matrix, such as the Hessenberg `H`
+/// of `Hseqr` or the tridiagonal `T` of `Steqr`, returns vectors in `Z`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VectorUpdate {
+    None,
+    /// `Z` is set to the Schur vectors or eigenvectors of the reduced
+    /// matrix.
+    Initialize,
+    /// `Z` holds the `Q` of the reduction on entry and is multiplied by the
+    /// vectors of the reduced matrix, so on exit it holds those of the
+    /// original matrix.
+    Update,
+}
+
+impl VectorUpdate {
+    pub fn as_i8(self) -> i8 {
+        match self {
+            VectorUpdate::None => 78,
+            VectorUpdate::Initialize => 73,
+            VectorUpdate::Update => 86,
+        }
+    }
+}
+
+/// An algorithm for the eigenvalues and eigenvectors of a symmetric
+/// tridiagonal matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TridiagonalAlgorithm {
+    /// The implicit QL or QR algorithm, `steqr`, or its root-free variant
+    /// `sterf` for eigenvalues only.
+    Qr,
+    /// Divide and conquer, `stedc`.
+    DivideAndConquer,
+    /// Multiple relatively robust representations, `stemr`.
+    RelativelyRobust,
+    /// Bisection and inverse iteration, `stebz` and `stein`.
+    Bisection,
+}
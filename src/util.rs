@@ -1,11 +1,21 @@
 // Copyright 2015 Michael Yang. All rights reserved.
 // Use of this source code is governed by a MIT-style
 // license that can be found in the LICENSE file.
+use std::any::Any;
+use std::cell::Cell;
 use std::cmp;
 use std::ops::Drop;
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
-use matrix::Matrix;
-use types::Order;
+use std::slice;
+use libc::{c_int, c_void};
+use num::{Num, Zero};
+use num::complex::Complex;
+use error::Error;
+use general_eigenvalues::GeneralizedEigenvalue;
+use ll::__CLPK_L_fp;
+use matrix::{Matrix, SymmetricMatrix};
+use types::{Norm, Order, Symmetry, Transpose};
 use types::Order::*;
 
 pub struct ColMem<'a, T: 'a> {
@@ -79,6 +89,90 @@ impl<'a, T> Drop for ColMem<'a, T> {
     }
 }
 
+/// The dimension of `a`, or `DimensionMismatch` if it is not square.
+pub fn check_square<T>(a: &Matrix<T>) -> Result<c_int, Error> {
+    match a.rows() == a.cols() {
+        true => Ok(a.rows()),
+        false => Err(Error::DimensionMismatch),
+    }
+}
+
+/// A column major view of an optional `n × n` matrix, such as one to
+/// receive eigenvectors.
+pub fn square_mem<'a, T>(n: c_int, v: Option<&'a mut Matrix<T>>) -> Result<Option<ColMem<'a, T>>, Error> {
+    match v {
+        Some(v) => {
+            if v.rows() != n || v.cols() != n {
+                return Err(Error::DimensionMismatch);
+            }
+            Ok(Some(ColMem::new(v.order(), v)))
+        },
+        None => Ok(None),
+    }
+}
+
+/// The `'V'` or `'N'` job flag, pointer and leading dimension for an
+/// optional output matrix.
+pub fn mem_job<'a, T>(v: &mut Option<ColMem<'a, T>>) -> (i8, *mut T, c_int) {
+    match *v {
+        Some(ref mut v) => (b'V' as i8, v.as_mut_ptr(), v.lead()),
+        None => (b'N' as i8, ptr::null_mut(), 1),
+    }
+}
+
+/// A `rows × cols` column major matrix borrowed from a slice, for passing
+/// owned results back through the `Matrix` based traits.
+pub struct ColSlice<'a, T: 'a>(pub c_int, pub c_int, pub &'a mut [T]);
+
+impl<'a, T> Matrix<T> for ColSlice<'a, T> {
+    fn rows(&self) -> c_int { self.0 }
+    fn cols(&self) -> c_int { self.1 }
+    fn order(&self) -> Order { Order::ColMajor }
+    fn as_ptr(&self) -> *const T { self.2.as_ptr() }
+    fn as_mut_ptr(&mut self) -> *mut T { self.2.as_mut_ptr() }
+}
+
+/// A column major symmetric matrix borrowed from a slice, for passing stored
+/// factors through the `SymmetricMatrix` based traits.
+pub struct ColSymmetric<'a, T: 'a>(pub Symmetry, pub ColSlice<'a, T>);
+
+impl<'a, T> Matrix<T> for ColSymmetric<'a, T> {
+    fn rows(&self) -> c_int { self.1.rows() }
+    fn cols(&self) -> c_int { self.1.cols() }
+    fn order(&self) -> Order { Order::ColMajor }
+    fn as_ptr(&self) -> *const T { self.1.as_ptr() }
+    fn as_mut_ptr(&mut self) -> *mut T { self.1.as_mut_ptr() }
+}
+
+impl<'a, T> SymmetricMatrix<T> for ColSymmetric<'a, T> {
+    fn symmetry(&self) -> Symmetry { self.0 }
+}
+
+/// The product of the column major `m × k` matrix `a` and `k × n` matrix `b`.
+pub fn multiply<T: Copy + Num>(m: usize, k: usize, n: usize, a: &[T], b: &[T]) -> Vec<T> {
+    let mut c = vec![T::zero(); m * n];
+    for j in 0..n {
+        for l in 0..k {
+            let x = b[l + j * k];
+            for i in 0..m {
+                c[i + j * m] = c[i + j * m] + a[i + l * m] * x;
+            }
+        }
+    }
+    c
+}
+
+/// The conjugate transpose of the column major `m × n` matrix `a`.
+pub fn adjoint<T: Copy, F: Fn(T) -> T>(m: usize, n: usize, a: &[T], conj: F) -> Vec<T> {
+    let mut t = Vec::with_capacity(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            t.push(conj(a[i + j * m]));
+        }
+    }
+    t
+}
+
 pub unsafe fn transpose_data<T>(initial_layout: Order, m: isize, n: isize, input: *const T, ld_input: isize, output: *mut T, ld_output: isize) {
     let (x, y) = match initial_layout {
         ColMajor => (n, m),
@@ -95,6 +189,153 @@ pub unsafe fn transpose_data<T>(initial_layout: Order, m: isize, n: isize, input
     }
 }
 
+/// The norm to request from LAPACK for a matrix stored in `order`. A row
+/// major matrix is seen as its transpose, which swaps the 1- and
+/// infinity-norms.
+pub fn col_norm(order: Order, norm: Norm) -> Norm {
+    match (order, norm) {
+        (Order::RowMajor, Norm::One) => Norm::Infinity,
+        (Order::RowMajor, Norm::Infinity) => Norm::One,
+        (_, norm) => norm,
+    }
+}
+
+/// The triangle LAPACK sees for a matrix stored in `order`.
+pub fn col_uplo(order: Order, uplo: Symmetry) -> Symmetry {
+    match (order, uplo) {
+        (Order::RowMajor, Symmetry::Upper) => Symmetry::Lower,
+        (Order::RowMajor, Symmetry::Lower) => Symmetry::Upper,
+        (_, uplo) => uplo,
+    }
+}
+
+/// The `trans` flag that undoes the transpose LAPACK sees for a matrix
+/// stored in `order`.
+pub fn col_trans(order: Order) -> i8 {
+    match order {
+        Order::ColMajor => b'N' as i8,
+        Order::RowMajor => b'T' as i8,
+    }
+}
+
+/// Rows, columns and leading dimension of the column major view of `a`.
+pub fn col_dims<T>(a: &Matrix<T>) -> (c_int, c_int, c_int) {
+    match a.order() {
+        Order::ColMajor => (a.rows(), a.cols(), a.rows()),
+        Order::RowMajor => (a.cols(), a.rows(), a.cols()),
+    }
+}
+
+/// A column major copy of a row major `a`, or `None` if `a` is already
+/// column major. Either way the leading dimension is `a.rows()`.
+pub fn col_copy<T: Copy + Zero>(a: &Matrix<T>) -> Option<Vec<T>> {
+    match a.order() {
+        Order::ColMajor => None,
+        Order::RowMajor => {
+            let m = a.rows();
+            let n = a.cols();
+            let mut copy = vec![T::zero(); (m * n) as usize];
+
+            unsafe {
+                transpose_data(Order::RowMajor, m as isize, n as isize,
+                    a.as_ptr(), n as isize, copy.as_mut_ptr(), m as isize);
+            }
+
+            Some(copy)
+        },
+    }
+}
+
+/// The `transr` (or `trans`) flag for a routine that names the transposed
+/// form `conj`: `T` for real and `C` for complex matrices.
+pub fn rfp_trans(trans: Transpose, conj: u8) -> i8 {
+    match trans {
+        Transpose::None => b'N' as i8,
+        Transpose::Conjugate => conj as i8,
+    }
+}
+
+/// A column major copy of `a` with leading dimension `a.rows()`.
+pub fn col_vec<T: Copy + Zero>(a: &Matrix<T>) -> Vec<T> {
+    match col_copy(a) {
+        Some(copy) => copy,
+        None => unsafe {
+            slice::from_raw_parts(a.as_ptr(), (a.rows() * a.cols()) as usize).to_vec()
+        },
+    }
+}
+
+struct Selector<'a, V: 'a> {
+    select: &'a mut FnMut(V) -> bool,
+    panic: Option<Box<Any + Send>>,
+}
+
+// LAPACK's selection callbacks take no context argument, so the active
+// selector is passed to the trampolines through a thread local.
+thread_local!(static SELECTOR: Cell<*mut c_void> = Cell::new(ptr::null_mut()));
+
+pub fn call_selector<V>(value: V) -> c_int {
+    SELECTOR.with(|s| {
+        let selector = unsafe { &mut *(s.get() as *mut Selector<V>) };
+        if selector.panic.is_some() {
+            return 0;
+        }
+
+        match panic::catch_unwind(AssertUnwindSafe(|| (selector.select)(value))) {
+            Ok(true) => 1,
+            Ok(false) => 0,
+            Err(e) => {
+                selector.panic = Some(e);
+                0
+            },
+        }
+    })
+}
+
+/// Calls `f` with the `sort` flag and selection function for LAPACK, with
+/// `select` installed for `trampoline` to pass to `call_selector`.
+pub fn with_selector<V, R, F>(select: Option<&mut FnMut(V) -> bool>, trampoline: __CLPK_L_fp, f: F) -> R
+    where F: FnOnce(i8, __CLPK_L_fp) -> R {
+
+    let select = match select {
+        Some(select) => select,
+        None => return f(b'N' as i8, None),
+    };
+
+    let mut selector = Selector { select: select, panic: None };
+    let previous = SELECTOR.with(|s| {
+        let previous = s.get();
+        s.set(&mut selector as *mut Selector<V> as *mut c_void);
+        previous
+    });
+
+    let result = f(b'S' as i8, trampoline);
+
+    SELECTOR.with(|s| s.set(previous));
+    if let Some(e) = selector.panic {
+        panic::resume_unwind(e);
+    }
+
+    result
+}
+
+/// Generalized eigenvalues from the `alphar`, `alphai` and `beta` of a real
+/// LAPACK routine.
+pub fn real_values<T: Copy + Num>(alphar: Vec<T>, alphai: Vec<T>, beta: Vec<T>) -> Vec<GeneralizedEigenvalue<T>> {
+    alphar.into_iter().zip(alphai.into_iter()).zip(beta.into_iter())
+        .map(|((r, i), b)| GeneralizedEigenvalue {
+            alpha: Complex::new(r, i),
+            beta: Complex::new(b, T::zero()),
+        })
+        .collect()
+}
+
+pub fn complex_values<T>(alpha: Vec<Complex<T>>, beta: Vec<Complex<T>>) -> Vec<GeneralizedEigenvalue<T>> {
+    alpha.into_iter().zip(beta.into_iter())
+        .map(|(a, b)| GeneralizedEigenvalue { alpha: a, beta: b })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::mem;